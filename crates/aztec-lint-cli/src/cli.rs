@@ -1,12 +1,17 @@
 use std::error::Error;
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::process::ExitCode;
 
-use aztec_lint_core::config::{ConfigError, RuleOverrides};
+use aztec_lint_core::config::{ConfigError, RuleOverrides, UnknownKeyPolicy};
 use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
 
-use crate::commands::{aztec_scan, check, explain, fix, rules, update};
+use crate::commands::check::LintRunOptions;
+use crate::commands::{
+    aztec_scan, cache_clean, check, config, explain, fix, init, lsp, rules, update,
+};
 use crate::exit_codes;
 
 #[derive(Debug)]
@@ -41,6 +46,38 @@ pub enum OutputFormat {
     Text,
     Json,
     Sarif,
+    Junit,
+    Gitlab,
+    Github,
+    Html,
+    Markdown,
+    Rdjson,
+    Rdjsonl,
+    Ndjson,
+    Checkstyle,
+    Sonarqube,
+}
+
+/// A report written to a file in addition to the `--format` output on stdout.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutputTarget {
+    pub format: OutputFormat,
+    pub path: PathBuf,
+}
+
+/// Parses a `--output <format>=<path>` value.
+pub fn parse_output_target(value: &str) -> Result<OutputTarget, String> {
+    let Some((format, path)) = value.split_once('=') else {
+        return Err(format!("expected <format>=<path>, got '{value}'"));
+    };
+    let format = OutputFormat::from_str(format.trim(), true)?;
+    if path.is_empty() {
+        return Err(format!("missing output path for '{value}'"));
+    }
+    Ok(OutputTarget {
+        format,
+        path: PathBuf::from(path),
+    })
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
@@ -56,22 +93,76 @@ pub enum MinConfidence {
     Low,
 }
 
+/// `--config-unknown-keys` mode for keys `aztec-lint.toml` does not recognize.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum ConfigUnknownKeys {
+    Warn,
+    Error,
+}
+
+impl ConfigUnknownKeys {
+    pub fn policy(self) -> UnknownKeyPolicy {
+        match self {
+            Self::Warn => UnknownKeyPolicy::Warn,
+            Self::Error => UnknownKeyPolicy::Error,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Args)]
 pub struct CommonLintFlags {
     #[arg(long, default_value = "text", value_enum)]
     pub format: OutputFormat,
     #[arg(long, default_value = "warning", value_enum)]
     pub severity_threshold: SeverityThreshold,
+    #[command(flatten)]
+    pub overrides: RuleOverrideFlags,
+    #[arg(long, default_value = "low", value_enum)]
+    pub min_confidence: MinConfidence,
+    #[arg(long)]
+    pub show_suppressed: bool,
+    #[arg(long)]
+    pub no_cache: bool,
+    #[arg(long, short = 'j', value_name = "N")]
+    pub jobs: Option<NonZeroUsize>,
+    #[arg(long)]
+    pub timings: bool,
+    #[arg(long, default_value = "error", value_enum, value_name = "MODE")]
+    pub config_unknown_keys: ConfigUnknownKeys,
+    #[arg(long = "exclude", value_name = "GLOB", action = ArgAction::Append)]
+    pub exclude: Vec<String>,
+}
+
+/// `--deny`/`--warn`/`--allow` rule level overrides.
+#[derive(Clone, Debug, Args, Default)]
+pub struct RuleOverrideFlags {
     #[arg(long = "deny", value_name = "RULE_ID", action = ArgAction::Append)]
     pub deny: Vec<String>,
     #[arg(long = "warn", value_name = "RULE_ID", action = ArgAction::Append)]
     pub warn: Vec<String>,
     #[arg(long = "allow", value_name = "RULE_ID", action = ArgAction::Append)]
     pub allow: Vec<String>,
-    #[arg(long, default_value = "low", value_enum)]
-    pub min_confidence: MinConfidence,
-    #[arg(long)]
-    pub show_suppressed: bool,
+}
+
+#[derive(Clone, Debug, Args, Default)]
+pub struct BaselineFlags {
+    #[arg(long, value_name = "FILE")]
+    pub baseline: Option<PathBuf>,
+    /// Rejected with `--changed-only`, which would record a partial baseline.
+    #[arg(long, value_name = "FILE", conflicts_with = "changed_only")]
+    pub write_baseline: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, Args, Default)]
+pub struct OutputFlags {
+    /// Also write a report to a file; repeatable, e.g. `--output sarif=out.sarif`.
+    #[arg(
+        long = "output",
+        value_name = "FORMAT=PATH",
+        value_parser = parse_output_target,
+        action = ArgAction::Append
+    )]
+    pub outputs: Vec<OutputTarget>,
 }
 
 #[derive(Clone, Debug, Args, Default)]
@@ -127,7 +218,7 @@ impl TargetSelectionFlags {
     }
 }
 
-impl CommonLintFlags {
+impl RuleOverrideFlags {
     pub fn rule_overrides(&self) -> RuleOverrides {
         RuleOverrides {
             deny: self.deny.clone(),
@@ -137,6 +228,22 @@ impl CommonLintFlags {
     }
 }
 
+impl CommonLintFlags {
+    pub fn rule_overrides(&self) -> RuleOverrides {
+        self.overrides.rule_overrides()
+    }
+
+    pub(crate) fn run_options(&self) -> LintRunOptions {
+        LintRunOptions {
+            no_cache: self.no_cache,
+            jobs: self.jobs.map(NonZeroUsize::get),
+            timings: self.timings,
+            unknown_keys: self.config_unknown_keys.policy(),
+            exclude: self.exclude.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Parser)]
 #[command(name = "aztec-lint", version, about = "Aztec/Noir linting CLI")]
 struct SubcommandCli {
@@ -148,7 +255,7 @@ struct SubcommandCli {
 #[command(name = "aztec-lint", version, about = "Aztec/Noir linting CLI")]
 struct DefaultCli {
     #[arg(default_value = ".")]
-    path: std::path::PathBuf,
+    path: PathBuf,
     #[arg(long, default_value = "aztec")]
     profile: String,
     #[arg(long)]
@@ -161,6 +268,10 @@ struct DefaultCli {
     targets: TargetSelectionFlags,
     #[command(flatten)]
     lint: CommonLintFlags,
+    #[command(flatten)]
+    baseline: BaselineFlags,
+    #[command(flatten)]
+    output: OutputFlags,
 }
 
 #[derive(Debug, Subcommand)]
@@ -170,7 +281,11 @@ enum Command {
     Rules(rules::RulesArgs),
     Explain(explain::ExplainArgs),
     Update(update::UpdateArgs),
+    Lsp(lsp::LspArgs),
     Aztec(AztecArgs),
+    Cache(CacheArgs),
+    Init(init::InitArgs),
+    Config(ConfigArgs),
 }
 
 #[derive(Debug, Args)]
@@ -184,6 +299,29 @@ enum AztecSubcommand {
     Scan(aztec_scan::AztecScanArgs),
 }
 
+#[derive(Debug, Args)]
+struct CacheArgs {
+    #[command(subcommand)]
+    command: CacheSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum CacheSubcommand {
+    Clean(cache_clean::CacheCleanArgs),
+}
+
+#[derive(Debug, Args)]
+struct ConfigArgs {
+    #[command(subcommand)]
+    command: ConfigSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum ConfigSubcommand {
+    Show(config::ConfigShowArgs),
+    Validate(config::ConfigValidateArgs),
+}
+
 pub fn run() -> ExitCode {
     let args = std::env::args_os().collect::<Vec<_>>();
     let result = if starts_with_subcommand(&args) {
@@ -219,7 +357,16 @@ fn starts_with_subcommand(args: &[OsString]) -> bool {
     args.get(1).and_then(|arg| arg.to_str()).is_some_and(|arg| {
         matches!(
             arg,
-            "check" | "fix" | "rules" | "explain" | "update" | "aztec"
+            "check"
+                | "fix"
+                | "rules"
+                | "explain"
+                | "update"
+                | "lsp"
+                | "aztec"
+                | "cache"
+                | "init"
+                | "config"
         )
     })
 }
@@ -239,14 +386,33 @@ fn dispatch_subcommand(cli: SubcommandCli) -> Result<ExitCode, CliError> {
         Command::Rules(args) => rules::run(args),
         Command::Explain(args) => explain::run(args),
         Command::Update(args) => update::run(args),
+        Command::Lsp(args) => lsp::run(args),
         Command::Aztec(args) => match args.command {
             AztecSubcommand::Scan(scan_args) => aztec_scan::run(scan_args),
         },
+        Command::Cache(args) => match args.command {
+            CacheSubcommand::Clean(clean_args) => cache_clean::run(clean_args),
+        },
+        Command::Init(args) => init::run(args),
+        Command::Config(args) => match args.command {
+            ConfigSubcommand::Show(show_args) => config::show(show_args),
+            ConfigSubcommand::Validate(validate_args) => config::validate(validate_args),
+        },
     }
 }
 
 fn dispatch_default(cli: DefaultCli) -> Result<ExitCode, CliError> {
     if cli.fix {
+        if cli.baseline.baseline.is_some() || cli.baseline.write_baseline.is_some() {
+            return Err(CliError::Runtime(
+                "`--baseline` and `--write-baseline` are not valid with `--fix`".to_string(),
+            ));
+        }
+        if !cli.output.outputs.is_empty() {
+            return Err(CliError::Runtime(
+                "`--output` is not valid with `--fix`".to_string(),
+            ));
+        }
         fix::run(fix::FixArgs {
             path: cli.path,
             profile: cli.profile,
@@ -267,13 +433,21 @@ fn dispatch_default(cli: DefaultCli) -> Result<ExitCode, CliError> {
             changed_only: cli.changed_only,
             targets: cli.targets,
             lint: cli.lint,
+            baseline: cli.baseline,
+            output: cli.output,
+            watch: false,
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{ResolvedTargetSelection, TargetSelectionFlags};
+    use std::path::PathBuf;
+
+    use super::{
+        OutputFormat, OutputTarget, ResolvedTargetSelection, TargetSelectionFlags,
+        parse_output_target,
+    };
 
     #[test]
     fn target_selection_defaults_to_all_targets() {
@@ -302,4 +476,18 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn output_target_parses_format_and_path() {
+        assert_eq!(
+            parse_output_target("SARIF=reports/out.sarif"),
+            Ok(OutputTarget {
+                format: OutputFormat::Sarif,
+                path: PathBuf::from("reports/out.sarif"),
+            })
+        );
+        assert!(parse_output_target("sarif").is_err());
+        assert!(parse_output_target("sarif=").is_err());
+        assert!(parse_output_target("yaml=out.yaml").is_err());
+    }
 }
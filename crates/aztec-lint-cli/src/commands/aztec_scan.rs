@@ -3,7 +3,7 @@ use std::process::ExitCode;
 
 use clap::Args;
 
-use crate::cli::{CliError, CommonLintFlags, TargetSelectionFlags};
+use crate::cli::{BaselineFlags, CliError, CommonLintFlags, OutputFlags, TargetSelectionFlags};
 use crate::commands::check::{CheckArgs, run as run_check};
 
 #[derive(Clone, Debug, Args)]
@@ -16,6 +16,10 @@ pub struct AztecScanArgs {
     pub targets: TargetSelectionFlags,
     #[command(flatten)]
     pub lint: CommonLintFlags,
+    #[command(flatten)]
+    pub baseline: BaselineFlags,
+    #[command(flatten)]
+    pub output: OutputFlags,
 }
 
 pub fn run(args: AztecScanArgs) -> Result<ExitCode, CliError> {
@@ -25,5 +29,8 @@ pub fn run(args: AztecScanArgs) -> Result<ExitCode, CliError> {
         changed_only: args.changed_only,
         targets: args.targets,
         lint: args.lint,
+        baseline: args.baseline,
+        output: args.output,
+        watch: false,
     })
 }
@@ -0,0 +1,44 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aztec_lint_core::cache::ContentCache;
+use clap::Args;
+
+use crate::cli::CliError;
+use crate::commands::check::nearest_project_root;
+
+#[derive(Clone, Debug, Args)]
+pub struct CacheCleanArgs {
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+}
+
+pub fn run(args: CacheCleanArgs) -> Result<ExitCode, CliError> {
+    let path = args.path.canonicalize().map_err(|source| {
+        CliError::Runtime(format!(
+            "failed to resolve cache target '{}': {source}",
+            args.path.display()
+        ))
+    })?;
+    let root = if path.is_file() {
+        path.parent()
+            .and_then(nearest_project_root)
+            .unwrap_or_else(|| path.parent().unwrap_or(&path).to_path_buf())
+    } else {
+        path
+    };
+
+    let cache = ContentCache::for_target_root(root.as_path());
+    let removed = cache.clean().map_err(|source| {
+        CliError::Runtime(format!(
+            "failed to remove cache '{}': {source}",
+            cache.root().display()
+        ))
+    })?;
+    if removed {
+        println!("removed cache '{}'", cache.root().display());
+    } else {
+        println!("no cache found at '{}'", cache.root().display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
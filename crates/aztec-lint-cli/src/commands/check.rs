@@ -1,28 +1,49 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::env;
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
 use aztec_lint_aztec::{SourceUnit, build_aztec_model_with_semantic, should_activate_aztec};
-use aztec_lint_core::config::{RuleOverrides, load_from_dir};
+use aztec_lint_core::cache::{CacheKeyBuilder, ContentCache};
+use aztec_lint_core::config::{
+    Config, ConfigSource, LoadedConfig, PathFilter, ResolvedPathOverride, RuleOverrides,
+    UnknownKeyPolicy, load_from_dir_with_policy,
+};
 use aztec_lint_core::diagnostics::{
-    Confidence, Diagnostic, Severity, normalize_file_path, sort_diagnostics,
+    Baseline, Confidence, Diagnostic, Severity, normalize_file_path, sort_diagnostics,
 };
-use aztec_lint_core::noir::build_project_semantic_bundle_with_options;
+use aztec_lint_core::model::{AztecModel, ProjectModel};
+use aztec_lint_core::noir::build_project_semantic_bundle_with_timings;
+use aztec_lint_core::output::ansi::{Colorizer, Stream};
+use aztec_lint_core::output::checkstyle as checkstyle_output;
+use aztec_lint_core::output::github as github_output;
+use aztec_lint_core::output::gitlab as gitlab_output;
+use aztec_lint_core::output::html::{HtmlReport, render_html_report};
 use aztec_lint_core::output::json as json_output;
+use aztec_lint_core::output::junit as junit_output;
+use aztec_lint_core::output::markdown::{MarkdownReport, render_markdown_report};
+use aztec_lint_core::output::ndjson::{self as ndjson_output, NdjsonSummary};
+use aztec_lint_core::output::rdjson as rdjson_output;
 use aztec_lint_core::output::sarif as sarif_output;
-use aztec_lint_core::output::text::{CheckTextReport, render_check_report};
+use aztec_lint_core::output::sonarqube as sonarqube_output;
+use aztec_lint_core::output::text::{
+    CheckTextReport, render_check_report_with_colors, render_timings_table,
+};
+use aztec_lint_core::parallel::{default_jobs, map_ordered};
+use aztec_lint_core::timings::{AZTEC_MODEL_PHASE, Timings};
 use aztec_lint_core::vcs::changed_files_from_git;
-use aztec_lint_rules::RuleEngine;
 use aztec_lint_rules::engine::context::RuleContext;
+use aztec_lint_rules::{RuleEngine, RuleRunSettings};
 use clap::Args;
 use toml::Value as TomlValue;
 
 use crate::cli::{
-    CliError, CommonLintFlags, MinConfidence, OutputFormat, ResolvedTargetSelection,
-    SeverityThreshold, TargetSelectionFlags,
+    BaselineFlags, CliError, CommonLintFlags, MinConfidence, OutputFlags, OutputFormat,
+    ResolvedTargetSelection, SeverityThreshold, TargetSelectionFlags,
 };
+use crate::commands::watch;
 use crate::exit_codes;
 
 #[derive(Clone, Debug, Args)]
@@ -37,12 +58,18 @@ pub struct CheckArgs {
     pub targets: TargetSelectionFlags,
     #[command(flatten)]
     pub lint: CommonLintFlags,
+    #[command(flatten)]
+    pub baseline: BaselineFlags,
+    #[command(flatten)]
+    pub output: OutputFlags,
+    #[arg(long, conflicts_with_all = ["write_baseline", "timings"])]
+    pub watch: bool,
 }
 
-#[derive(Clone, Debug)]
-struct NoirProject {
-    root: PathBuf,
-    entry: PathBuf,
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct NoirProject {
+    pub root: PathBuf,
+    pub entry: PathBuf,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -54,96 +81,364 @@ enum NoirTargetKind {
     Test,
 }
 
+const MODEL_CACHE_NAMESPACE: &str = "models";
+const DIAGNOSTICS_CACHE_NAMESPACE: &str = "diagnostics";
+
 #[derive(Clone, Debug)]
 pub(crate) struct LintRun {
     pub effective_rules: usize,
     pub diagnostics: Vec<Diagnostic>,
     pub report_root: PathBuf,
+    pub config_source: ConfigSource,
+    /// Phase and rule wall times, present when `--timings` was requested.
+    pub timings: Option<Timings>,
+    /// Files diagnostics were kept for under `--changed-only`, relative to
+    /// `report_root`; `None` when every file was analyzed.
+    pub changed_files: Option<BTreeSet<String>>,
+}
+
+/// Diagnostics and timings produced by linting a single project.
+pub(crate) struct ProjectLint {
+    pub diagnostics: Vec<Diagnostic>,
+    pub timings: Timings,
 }
 
 pub fn run(args: CheckArgs) -> Result<ExitCode, CliError> {
+    if args.watch {
+        return watch::run(&args);
+    }
+
     let lint_run = collect_lint_run(
         args.path.as_path(),
         &args.profile,
         args.changed_only,
         args.targets.resolve(),
         args.lint.rule_overrides(),
+        args.lint.run_options(),
     )?;
+    report_lint_run(&args, lint_run)
+}
 
-    let include_suppressed = suppression_visible(args.lint.format, args.lint.show_suppressed);
-    let diagnostics = diagnostics_for_output(
+pub(crate) fn report_lint_run(
+    args: &CheckArgs,
+    mut lint_run: LintRun,
+) -> Result<ExitCode, CliError> {
+    apply_baseline(&mut lint_run, &args.baseline)?;
+
+    let blocking = has_blocking_diagnostics(
         &lint_run.diagnostics,
         args.lint.min_confidence,
         args.lint.severity_threshold,
-        include_suppressed,
     );
+    let exit_code = exit_codes::diagnostics_found_status(blocking);
+    let render = |format: OutputFormat, colors: Colorizer, writer: &mut dyn Write| {
+        let include_suppressed = suppression_visible(format, args.lint.show_suppressed);
+        let diagnostics = diagnostics_for_output(
+            &lint_run.diagnostics,
+            args.lint.min_confidence,
+            args.lint.severity_threshold,
+            include_suppressed,
+        );
+        write_report(
+            writer,
+            CheckRenderContext {
+                format,
+                colors,
+                path: args.path.as_path(),
+                show_run_header: true,
+                profile: &args.profile,
+                changed_only: args.changed_only,
+                effective_rules: lint_run.effective_rules,
+                diagnostics: &diagnostics,
+                sarif_root: lint_run.report_root.as_path(),
+                config_source: &lint_run.config_source,
+                exit_code,
+                timings: lint_run.timings.as_ref(),
+            },
+        )
+    };
+
+    for output in &args.output.outputs {
+        write_output_file(&output.path, |writer| {
+            render(output.format, Colorizer::disabled(), writer)
+        })?;
+    }
 
-    render_result(
+    let stdout = std::io::stdout();
+    let mut writer = std::io::BufWriter::new(stdout.lock());
+    render(
         args.lint.format,
-        args.path.as_path(),
-        &args.profile,
-        args.changed_only,
-        lint_run.effective_rules,
-        &diagnostics,
-        lint_run.report_root.as_path(),
+        Colorizer::for_stream(Stream::Stdout),
+        &mut writer,
     )?;
+    writer
+        .flush()
+        .map_err(|source| CliError::Runtime(format!("failed to write report: {source}")))?;
+    print_timings_to_stderr(args.lint.format, lint_run.timings.as_ref());
 
-    let blocking = has_blocking_diagnostics(
-        &lint_run.diagnostics,
-        args.lint.min_confidence,
-        args.lint.severity_threshold,
-    );
     Ok(exit_codes::diagnostics_found(blocking))
 }
 
+/// Prints the timings table on stderr for formats whose report does not
+/// carry timings itself, so machine-readable stdout stays parseable.
+pub(crate) fn print_timings_to_stderr(format: OutputFormat, timings: Option<&Timings>) {
+    if let Some(timings) = timings
+        && !matches!(format, OutputFormat::Text | OutputFormat::Json)
+    {
+        eprint!("{}", render_timings_table(timings));
+    }
+}
+
 pub(crate) fn collect_lint_run(
     path: &Path,
     profile: &str,
     changed_only: bool,
     targets: ResolvedTargetSelection,
     rule_overrides: RuleOverrides,
+    options: LintRunOptions,
 ) -> Result<LintRun, CliError> {
-    let loaded = load_from_dir(config_root_for_target(path))?;
-    let effective_rules = loaded
-        .config
-        .effective_rule_levels(profile, &rule_overrides)?;
+    let report_timings = options.timings;
+    let session = LintSession::prepare(path, profile, targets, rule_overrides, options)?;
+    let projects = session.projects.iter().collect::<Vec<_>>();
+    let mut diagnostics = Vec::<Diagnostic>::new();
+    let mut timings = Timings::default();
+    for project_lint in session.lint_projects(&projects) {
+        let project_lint = project_lint?;
+        diagnostics.extend(project_lint.diagnostics);
+        timings.merge(&project_lint.timings);
+    }
+    let mut lint_run = session.finish(diagnostics, changed_only)?;
+    lint_run.timings = report_timings.then_some(timings);
+    Ok(lint_run)
+}
 
-    let discovered_projects = discover_noir_projects(path).map_err(|source| {
-        CliError::Runtime(format!(
-            "failed to discover Noir projects under '{}': {source}",
-            path.display()
-        ))
-    })?;
-    if discovered_projects.is_empty() {
-        return Err(CliError::Runtime(format!(
-            "no Noir project found under '{}'",
-            path.display()
-        )));
+/// Configuration, rule levels, and discovered projects for one lint target.
+/// Projects can be linted independently and merged with [`LintSession::finish`].
+pub(crate) struct LintSession {
+    path: PathBuf,
+    profile: String,
+    targets: ResolvedTargetSelection,
+    config: Config,
+    config_source: ConfigSource,
+    /// The loaded config file and the files it extends.
+    pub(crate) config_files: Vec<PathBuf>,
+    rule_settings: RuleRunSettings,
+    path_overrides: Vec<ResolvedPathOverride>,
+    jobs: usize,
+    selection_root: PathBuf,
+    pub(crate) source_filter: SourceFilter,
+    pub projects: Vec<NoirProject>,
+    pub report_root: PathBuf,
+    engine: RuleEngine,
+    cache: Option<ContentCache>,
+}
+
+struct ProjectCacheKeys {
+    models: String,
+    diagnostics: String,
+}
+
+/// Options shared by every command that runs the lint pipeline.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct LintRunOptions {
+    pub no_cache: bool,
+    /// Worker thread limit; defaults to the available parallelism.
+    pub jobs: Option<usize>,
+    /// Report phase and rule wall times. Bypasses the cache so every phase runs.
+    pub timings: bool,
+    pub unknown_keys: UnknownKeyPolicy,
+    /// `--exclude` globs, added to the config's `exclude` list.
+    pub exclude: Vec<String>,
+}
+
+impl LintSession {
+    pub(crate) fn prepare(
+        path: &Path,
+        profile: &str,
+        targets: ResolvedTargetSelection,
+        rule_overrides: RuleOverrides,
+        options: LintRunOptions,
+    ) -> Result<Self, CliError> {
+        let loaded = load_config(path, options.unknown_keys)?;
+        let effective_rules = loaded
+            .config
+            .effective_rule_levels(profile, &rule_overrides)?;
+        let path_overrides = loaded.config.resolve_path_overrides()?;
+        let source_filter = SourceFilter::new(
+            config_root_for_target(path),
+            loaded.config.path_filter(&options.exclude),
+        );
+
+        let selection_root = config_root_for_target(path)
+            .canonicalize()
+            .unwrap_or_else(|_| config_root_for_target(path).to_path_buf());
+        let projects =
+            discover_target_projects(path, &source_filter, targets, selection_root.as_path())?;
+        let report_root = report_root_for_target(path, &projects);
+
+        // Projects are linted concurrently first; threads left over are
+        // shared out to run rules concurrently within each project.
+        let jobs = options.jobs.unwrap_or_else(default_jobs).max(1);
+        let rule_jobs = jobs / jobs.min(projects.len());
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            profile: profile.to_string(),
+            targets,
+            config: loaded.config,
+            config_source: loaded.source,
+            config_files: loaded.files,
+            rule_settings: RuleRunSettings {
+                effective_levels: effective_rules,
+                jobs: rule_jobs,
+            },
+            path_overrides,
+            jobs,
+            selection_root,
+            source_filter,
+            projects,
+            cache: (!options.no_cache && !options.timings)
+                .then(|| ContentCache::for_target_root(report_root.as_path())),
+            report_root,
+            engine: RuleEngine::new(),
+        })
     }
 
-    let selection_root = config_root_for_target(path)
-        .canonicalize()
-        .unwrap_or_else(|_| config_root_for_target(path).to_path_buf());
-    let projects =
-        filter_projects_by_target(discovered_projects, targets, selection_root.as_path());
-    if projects.is_empty() {
-        let selected = selected_target_labels(targets);
-        return Err(CliError::Runtime(format!(
-            "no Noir project target matched under '{}' for selected targets: {selected}",
-            path.display(),
-        )));
+    /// Re-runs project discovery with this session's filters and target
+    /// selection, picking up projects created since the session was prepared.
+    pub(crate) fn discover_projects(&self) -> Result<Vec<NoirProject>, CliError> {
+        discover_target_projects(
+            self.path.as_path(),
+            &self.source_filter,
+            self.targets,
+            self.selection_root.as_path(),
+        )
     }
-    let report_root = report_root_for_target(path, &projects);
 
-    let engine = RuleEngine::new();
-    let mut diagnostics = Vec::<Diagnostic>::new();
+    /// Lints `projects` on up to `jobs` worker threads. Results are returned in
+    /// input order.
+    pub(crate) fn lint_projects(
+        &self,
+        projects: &[&NoirProject],
+    ) -> Vec<Result<ProjectLint, CliError>> {
+        map_ordered(projects, self.jobs, |project| self.lint_project(project))
+    }
+
+    fn lint_project(&self, project: &NoirProject) -> Result<ProjectLint, CliError> {
+        let project_kind = classify_target_kind(project, self.selection_root.as_path());
+        let mut timings = Timings::default();
+        let mut project_diagnostics = self.project_diagnostics(project, &mut timings)?;
+        rebase_diagnostic_paths(
+            &mut project_diagnostics,
+            project.root.as_path(),
+            self.report_root.as_path(),
+        );
+        retain_diagnostics_for_selected_targets(
+            &mut project_diagnostics,
+            self.targets,
+            project_kind,
+        );
+        Ok(ProjectLint {
+            diagnostics: project_diagnostics,
+            timings,
+        })
+    }
+
+    /// Runs the rule engine for one project, answering from the content cache
+    /// when sources, configuration, profile and tool version are unchanged.
+    /// Returned diagnostics are relative to the project root.
+    fn project_diagnostics(
+        &self,
+        project: &NoirProject,
+        timings: &mut Timings,
+    ) -> Result<Vec<Diagnostic>, CliError> {
+        let cached = self
+            .cache
+            .as_ref()
+            .and_then(|cache| Some((cache, self.project_cache_keys(project)?)));
+
+        if let Some((cache, keys)) = &cached
+            && let Some(rules) = cache.load::<BTreeMap<String, Vec<Diagnostic>>>(
+                DIAGNOSTICS_CACHE_NAMESPACE,
+                &keys.diagnostics,
+            )
+        {
+            let mut diagnostics = rules.into_values().flatten().collect::<Vec<_>>();
+            sort_diagnostics(&mut diagnostics);
+            return Ok(diagnostics);
+        }
+
+        let models = cached.as_ref().and_then(|(cache, keys)| {
+            cache.load::<(ProjectModel, Option<AztecModel>)>(MODEL_CACHE_NAMESPACE, &keys.models)
+        });
+        let (project_model, aztec_model) = match models {
+            Some(models) => models,
+            None => {
+                let models = self.build_models(project, timings)?;
+                if let Some((cache, keys)) = &cached {
+                    let _ = cache.store(MODEL_CACHE_NAMESPACE, &keys.models, &models);
+                }
+                models
+            }
+        };
+
+        let mut context = RuleContext::from_project_root(&project.root, &project_model)
+            .map_err(|source| read_sources_error(project, source))?;
+        context.set_aztec_config(self.config.aztec.clone());
+        context.set_lint_options(self.config.lints.clone());
+        context.set_path_overrides(
+            self.path_overrides.clone(),
+            config_relative_project_root(project, self.selection_root.as_path()),
+        );
+        if !self.source_filter.is_empty() {
+            context.retain_files(|file| self.source_filter.includes_file(&project.root.join(file)));
+        }
+        if let Some(aztec_model) = aztec_model {
+            context.set_aztec_model(aztec_model);
+        }
+
+        let mut diagnostics = self
+            .engine
+            .run_with_settings(&context, &self.rule_settings)
+            .map_err(|source| {
+                CliError::Runtime(format!(
+                    "diagnostic validation failed while linting '{}': {source}",
+                    project.root.display()
+                ))
+            })?;
+        timings.merge(&context.timings());
+        // The semantic model still describes excluded modules, so findings
+        // located in them are dropped as well.
+        if !self.source_filter.is_empty() {
+            diagnostics.retain(|diagnostic| {
+                self.source_filter
+                    .includes_file(&project.root.join(&diagnostic.primary_span.file))
+            });
+        }
+
+        if let Some((cache, keys)) = &cached {
+            let mut rules = BTreeMap::<String, Vec<Diagnostic>>::new();
+            for diagnostic in &diagnostics {
+                rules
+                    .entry(diagnostic.rule_id.clone())
+                    .or_default()
+                    .push(diagnostic.clone());
+            }
+            let _ = cache.store(DIAGNOSTICS_CACHE_NAMESPACE, &keys.diagnostics, &rules);
+        }
+        Ok(diagnostics)
+    }
 
-    for project in projects {
-        let project_kind = classify_target_kind(&project, selection_root.as_path());
-        let bundle = build_project_semantic_bundle_with_options(
+    fn build_models(
+        &self,
+        project: &NoirProject,
+        timings: &mut Timings,
+    ) -> Result<(ProjectModel, Option<AztecModel>), CliError> {
+        let bundle = build_project_semantic_bundle_with_timings(
             &project.root,
             &project.entry,
-            loaded.config.deprecated_path,
+            self.config.deprecated_path,
+            timings,
         )
         .map_err(|source| {
             CliError::Runtime(format!(
@@ -152,62 +447,265 @@ pub(crate) fn collect_lint_run(
                 project.entry.display()
             ))
         })?;
-        let mut context = RuleContext::from_project_root(&project.root, bundle.project_model())
-            .map_err(|source| {
-                CliError::Runtime(format!(
-                    "failed to read Noir sources for '{}': {source}",
-                    project.root.display()
-                ))
-            })?;
-        context.set_aztec_config(loaded.config.aztec.clone());
+        let context = RuleContext::from_project_root(&project.root, bundle.project_model())
+            .map_err(|source| read_sources_error(project, source))?;
 
         let sources = context
             .files()
             .iter()
             .map(|file| SourceUnit::new(file.path().to_string(), file.text().to_string()))
             .collect::<Vec<_>>();
-        if should_activate_aztec(profile, &sources, &loaded.config.aztec) {
-            let aztec_model = build_aztec_model_with_semantic(
-                &sources,
-                &loaded.config.aztec,
-                Some(context.semantic_model()),
+        let aztec_model =
+            should_activate_aztec(&self.profile, &sources, &self.config.aztec).then(|| {
+                timings.time(AZTEC_MODEL_PHASE, || {
+                    build_aztec_model_with_semantic(
+                        &sources,
+                        &self.config.aztec,
+                        Some(context.semantic_model()),
+                    )
+                })
+            });
+        Ok((bundle.into_project_model(), aztec_model))
+    }
+
+    fn project_cache_keys(&self, project: &NoirProject) -> Option<ProjectCacheKeys> {
+        let inputs = project_source_inputs(project.root.as_path()).ok()?;
+        let entry = project
+            .entry
+            .strip_prefix(&project.root)
+            .unwrap_or(&project.entry);
+
+        let mut models = CacheKeyBuilder::new(MODEL_CACHE_NAMESPACE);
+        models
+            .field("entry", entry.to_string_lossy().as_bytes())
+            .field("profile", self.profile.as_bytes())
+            .field(
+                "deprecated_path",
+                &serde_json::to_vec(&self.config.deprecated_path).ok()?,
+            )
+            .field("aztec", &serde_json::to_vec(&self.config.aztec).ok()?);
+        for (path, contents) in &inputs {
+            models.field(path, contents);
+        }
+        let models = models.finish();
+
+        let mut diagnostics = CacheKeyBuilder::new(DIAGNOSTICS_CACHE_NAMESPACE);
+        diagnostics
+            .field("models", models.as_bytes())
+            .field("config", &serde_json::to_vec(&self.config).ok()?)
+            // `[[overrides]]` and the path filter match config-relative paths,
+            // so identical projects at different locations lint differently.
+            .field(
+                "project_root",
+                config_relative_project_root(project, self.selection_root.as_path()).as_bytes(),
+            )
+            .field(
+                "path_filter",
+                &serde_json::to_vec(self.source_filter.path_filter()).ok()?,
+            )
+            .field(
+                "rules",
+                &serde_json::to_vec(&self.rule_settings.effective_levels).ok()?,
+            );
+
+        Some(ProjectCacheKeys {
+            models,
+            diagnostics: diagnostics.finish(),
+        })
+    }
+
+    pub(crate) fn finish(
+        &self,
+        mut diagnostics: Vec<Diagnostic>,
+        changed_only: bool,
+    ) -> Result<LintRun, CliError> {
+        sort_diagnostics(&mut diagnostics);
+
+        let changed_files = if changed_only {
+            let changed = changed_files_from_git(&self.path).map_err(|source| {
+                CliError::Runtime(format!(
+                    "failed to compute changed files for '{}': {source}",
+                    self.path.display()
+                ))
+            })?;
+            let changed_files = changed
+                .files_for_root(self.report_root.as_path())
+                .iter()
+                .map(|file| normalize_file_path(file))
+                .collect::<BTreeSet<_>>();
+            retain_changed_only(&mut diagnostics, &changed_files);
+            Some(changed_files)
+        } else {
+            None
+        };
+
+        Ok(LintRun {
+            effective_rules: self.rule_settings.effective_levels.len(),
+            diagnostics,
+            report_root: self.report_root.clone(),
+            config_source: self.config_source.clone(),
+            timings: None,
+            changed_files,
+        })
+    }
+}
+
+fn apply_baseline(lint_run: &mut LintRun, flags: &BaselineFlags) -> Result<(), CliError> {
+    let report_root = lint_run.report_root.as_path();
+    let recorded = flags
+        .write_baseline
+        .as_ref()
+        .map(|_| Baseline::from_diagnostics(report_root, &lint_run.diagnostics));
+
+    if let Some(path) = &flags.baseline {
+        let baseline =
+            Baseline::load(path).map_err(|source| CliError::Runtime(source.to_string()))?;
+        let mut matched = baseline.filter(report_root, std::mem::take(&mut lint_run.diagnostics));
+        lint_run.diagnostics = matched.new_diagnostics;
+        // Entries in files `--changed-only` did not analyze are not fixed,
+        // only unreported.
+        if let Some(changed_files) = &lint_run.changed_files {
+            matched
+                .fixed
+                .retain(|entry| changed_files.contains(&entry.file));
+        }
+        eprintln!(
+            "baseline: {} known diagnostic(s) filtered, {} fixed since baseline",
+            matched.known,
+            matched.fixed.len()
+        );
+        for entry in &matched.fixed {
+            eprintln!(
+                "  fixed since baseline: {} {}:{} {}",
+                entry.rule_id, entry.file, entry.line, entry.message
             );
-            context.set_aztec_model(aztec_model);
         }
+    }
 
-        let mut project_diagnostics = engine.run(&context, &effective_rules).map_err(|source| {
-            CliError::Runtime(format!(
-                "diagnostic validation failed while linting '{}': {source}",
-                project.root.display()
-            ))
-        })?;
-        rebase_diagnostic_paths(
-            &mut project_diagnostics,
-            project.root.as_path(),
-            report_root.as_path(),
+    if let (Some(path), Some(baseline)) = (&flags.write_baseline, recorded) {
+        baseline
+            .write(path)
+            .map_err(|source| CliError::Runtime(source.to_string()))?;
+        eprintln!(
+            "baseline: wrote {} entry(ies) to '{}'",
+            baseline.entries.len(),
+            path.display()
         );
-        retain_diagnostics_for_selected_targets(&mut project_diagnostics, targets, project_kind);
-        diagnostics.extend(project_diagnostics);
+        let matched = baseline.filter(report_root, std::mem::take(&mut lint_run.diagnostics));
+        lint_run.diagnostics = matched.new_diagnostics;
     }
 
-    sort_diagnostics(&mut diagnostics);
+    Ok(())
+}
 
-    if changed_only {
-        let changed = changed_files_from_git(path).map_err(|source| {
-            CliError::Runtime(format!(
-                "failed to compute changed files for '{}': {source}",
-                path.display()
-            ))
-        })?;
-        let changed_files = changed.files_for_root(report_root.as_path());
-        retain_changed_only(&mut diagnostics, &changed_files);
+fn read_sources_error(project: &NoirProject, source: std::io::Error) -> CliError {
+    CliError::Runtime(format!(
+        "failed to read Noir sources for '{}': {source}",
+        project.root.display()
+    ))
+}
+
+/// Contents of every file that can influence analysis of the package at
+/// `root`, keyed by path relative to `root`.
+fn project_source_inputs(root: &Path) -> std::io::Result<BTreeMap<String, Vec<u8>>> {
+    let mut inputs = BTreeMap::<String, Vec<u8>>::new();
+    for file in project_input_files(root)? {
+        let Ok(contents) = fs::read(&file) else {
+            continue;
+        };
+        let label = file.strip_prefix(root).unwrap_or(&file);
+        inputs.insert(normalize_file_path(&label.to_string_lossy()), contents);
     }
+    Ok(inputs)
+}
 
-    Ok(LintRun {
-        effective_rules: effective_rules.len(),
-        diagnostics,
-        report_root,
-    })
+/// Every file that can influence analysis of the package at `root`: its
+/// manifest and sources plus those of local path dependencies. Files that
+/// do not exist (such as a missing manifest) are still listed.
+pub(crate) fn project_input_files(root: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::<PathBuf>::new();
+    let mut visited = BTreeSet::<PathBuf>::new();
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(package_root) = pending.pop() {
+        if !visited.insert(package_root.clone()) {
+            continue;
+        }
+
+        let manifest_path = package_root.join("Nargo.toml");
+        files.push(manifest_path.clone());
+        // Excluded sources are still compiled, so they stay part of the inputs.
+        collect_noir_sources(
+            &package_root.join("src"),
+            &SourceFilter::default(),
+            &mut files,
+        )?;
+
+        let Some(manifest) = fs::read_to_string(&manifest_path)
+            .ok()
+            .and_then(|manifest| toml::from_str::<TomlValue>(&manifest).ok())
+        else {
+            continue;
+        };
+        let dependencies = manifest
+            .get("dependencies")
+            .and_then(TomlValue::as_table)
+            .into_iter()
+            .flat_map(|table| table.values());
+        for dependency in dependencies {
+            if let Some(path) = dependency.get("path").and_then(TomlValue::as_str) {
+                let dependency_root = package_root.join(path);
+                pending.push(dependency_root.canonicalize().unwrap_or(dependency_root));
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Loads the config that applies to `target`, printing keys ignored under
+/// [`UnknownKeyPolicy::Warn`] to stderr.
+pub(crate) fn load_config(
+    target: &Path,
+    unknown_keys: UnknownKeyPolicy,
+) -> Result<LoadedConfig, CliError> {
+    let loaded = load_from_dir_with_policy(config_root_for_target(target), unknown_keys)?;
+    for key in &loaded.warnings {
+        eprintln!("warning: {key}");
+    }
+    Ok(loaded)
+}
+
+/// Discovers the Noir projects under `path` and keeps those of the selected
+/// target kinds, failing when none remain.
+fn discover_target_projects(
+    path: &Path,
+    source_filter: &SourceFilter,
+    targets: ResolvedTargetSelection,
+    selection_root: &Path,
+) -> Result<Vec<NoirProject>, CliError> {
+    let discovered_projects = discover_noir_projects(path, source_filter).map_err(|source| {
+        CliError::Runtime(format!(
+            "failed to discover Noir projects under '{}': {source}",
+            path.display()
+        ))
+    })?;
+    if discovered_projects.is_empty() {
+        return Err(CliError::Runtime(format!(
+            "no Noir project found under '{}'",
+            path.display()
+        )));
+    }
+
+    let projects = filter_projects_by_target(discovered_projects, targets, selection_root);
+    if projects.is_empty() {
+        let selected = selected_target_labels(targets);
+        return Err(CliError::Runtime(format!(
+            "no Noir project target matched under '{}' for selected targets: {selected}",
+            path.display(),
+        )));
+    }
+    Ok(projects)
 }
 
 pub(crate) fn config_root_for_target(path: &Path) -> &Path {
@@ -217,39 +715,71 @@ pub(crate) fn config_root_for_target(path: &Path) -> &Path {
     path
 }
 
-fn render_result(
-    format: OutputFormat,
-    path: &Path,
-    profile: &str,
-    changed_only: bool,
-    effective_rules: usize,
-    diagnostics: &[&Diagnostic],
-    sarif_root: &Path,
+pub(crate) struct CheckRenderContext<'a> {
+    pub format: OutputFormat,
+    pub colors: Colorizer,
+    pub path: &'a Path,
+    /// Whether the `text` report opens with its `checked=...` line; `fix`
+    /// prints its own header instead.
+    pub show_run_header: bool,
+    pub profile: &'a str,
+    pub changed_only: bool,
+    pub effective_rules: usize,
+    pub diagnostics: &'a [&'a Diagnostic],
+    pub sarif_root: &'a Path,
+    pub config_source: &'a ConfigSource,
+    pub exit_code: u8,
+    pub timings: Option<&'a Timings>,
+}
+
+/// Writes one report in `context.format`. Timings are part of the `text` and
+/// `json` reports; other formats leave them to the caller.
+pub(crate) fn write_report(
+    writer: &mut dyn Write,
+    context: CheckRenderContext<'_>,
 ) -> Result<(), CliError> {
-    match format {
+    let CheckRenderContext {
+        format,
+        colors,
+        path,
+        show_run_header,
+        profile,
+        changed_only,
+        effective_rules,
+        diagnostics,
+        sarif_root,
+        config_source,
+        exit_code,
+        timings,
+    } = context;
+    let rendered = match format {
         OutputFormat::Text => {
             let display_root = text_display_root(path, sarif_root);
             let diagnostics =
                 diagnostics_for_text_display(diagnostics, sarif_root, display_root.as_path());
             let diagnostic_refs = diagnostics.iter().collect::<Vec<_>>();
-            let rendered = render_check_report(CheckTextReport {
-                path,
-                source_root: display_root.as_path(),
-                show_run_header: true,
-                profile,
-                changed_only,
-                active_rules: effective_rules,
-                diagnostics: &diagnostic_refs,
-            });
-            print!("{rendered}");
-            Ok(())
+            let mut rendered = render_check_report_with_colors(
+                CheckTextReport {
+                    path,
+                    source_root: display_root.as_path(),
+                    show_run_header,
+                    profile,
+                    changed_only,
+                    active_rules: effective_rules,
+                    diagnostics: &diagnostic_refs,
+                },
+                colors,
+            );
+            if let Some(timings) = timings {
+                rendered.push_str(&render_timings_table(timings));
+            }
+            rendered
         }
         OutputFormat::Json => {
-            let rendered = json_output::render_diagnostics(diagnostics).map_err(|source| {
+            let rendered = render_json(diagnostics, timings).map_err(|source| {
                 CliError::Runtime(format!("failed to serialize diagnostics as JSON: {source}"))
             })?;
-            println!("{rendered}");
-            Ok(())
+            format!("{rendered}\n")
         }
         OutputFormat::Sarif => {
             let rendered =
@@ -258,12 +788,130 @@ fn render_result(
                         "failed to serialize diagnostics as SARIF: {source}"
                     ))
                 })?;
-            println!("{rendered}");
-            Ok(())
+            format!("{rendered}\n")
         }
+        OutputFormat::Gitlab => {
+            let rendered =
+                gitlab_output::render_diagnostics(sarif_root, diagnostics).map_err(|source| {
+                    CliError::Runtime(format!(
+                        "failed to serialize diagnostics as GitLab Code Quality JSON: {source}"
+                    ))
+                })?;
+            format!("{rendered}\n")
+        }
+        OutputFormat::Github => render_github(diagnostics, sarif_root),
+        OutputFormat::Html => render_html_report(HtmlReport {
+            path,
+            source_root: sarif_root,
+            profile,
+            diagnostics,
+        }),
+        OutputFormat::Markdown => render_markdown_report(MarkdownReport {
+            path,
+            source_root: sarif_root,
+            profile,
+            changed_only,
+            active_rules: effective_rules,
+            diagnostics,
+        }),
+        OutputFormat::Rdjson => {
+            let rendered =
+                rdjson_output::render_diagnostics(sarif_root, diagnostics).map_err(|source| {
+                    CliError::Runtime(format!(
+                        "failed to serialize diagnostics as reviewdog JSON: {source}"
+                    ))
+                })?;
+            format!("{rendered}\n")
+        }
+        OutputFormat::Rdjsonl => rdjson_output::render_diagnostics_lines(sarif_root, diagnostics)
+            .map_err(|source| {
+            CliError::Runtime(format!(
+                "failed to serialize diagnostics as reviewdog JSON: {source}"
+            ))
+        })?,
+        OutputFormat::Ndjson => {
+            // Streamed straight to the writer rather than buffered.
+            return ndjson_output::write_diagnostics(
+                writer,
+                diagnostics,
+                NdjsonSummary {
+                    exit_code,
+                    config_source,
+                },
+            )
+            .map_err(|source| {
+                CliError::Runtime(format!("failed to write diagnostics as NDJSON: {source}"))
+            });
+        }
+        OutputFormat::Checkstyle => checkstyle_output::render_diagnostics(sarif_root, diagnostics),
+        OutputFormat::Sonarqube => {
+            let rendered = sonarqube_output::render_diagnostics(sarif_root, diagnostics).map_err(
+                |source| {
+                    CliError::Runtime(format!(
+                        "failed to serialize diagnostics as SonarQube JSON: {source}"
+                    ))
+                },
+            )?;
+            format!("{rendered}\n")
+        }
+        OutputFormat::Junit => junit_output::render_diagnostics(sarif_root, diagnostics),
+    };
+    writer
+        .write_all(rendered.as_bytes())
+        .map_err(|source| CliError::Runtime(format!("failed to write report: {source}")))
+}
+
+/// Creates `path` (and missing parent directories) and lets `render` write
+/// the report into it.
+fn write_output_file(
+    path: &Path,
+    render: impl FnOnce(&mut dyn Write) -> Result<(), CliError>,
+) -> Result<(), CliError> {
+    let io_error = |source: std::io::Error| {
+        CliError::Runtime(format!(
+            "failed to write report to '{}': {source}",
+            path.display()
+        ))
+    };
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    let mut writer = std::io::BufWriter::new(fs::File::create(path).map_err(io_error)?);
+    render(&mut writer)?;
+    writer.flush().map_err(io_error)
+}
+
+/// JSON diagnostics, wrapped with a `timings` section when timings were requested.
+fn render_json(
+    diagnostics: &[&Diagnostic],
+    timings: Option<&Timings>,
+) -> Result<String, serde_json::Error> {
+    match timings {
+        Some(timings) => json_output::render_diagnostics_with_timings(diagnostics, timings),
+        None => json_output::render_diagnostics(diagnostics),
     }
 }
 
+/// GitHub resolves annotation paths against the workflow checkout, so paths
+/// are rebased onto `GITHUB_WORKSPACE`, or the current directory outside of
+/// Actions, before rendering.
+fn render_github(diagnostics: &[&Diagnostic], report_root: &Path) -> String {
+    let annotation_root = env::var_os("GITHUB_WORKSPACE")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| env::current_dir().ok())
+        .map(|root| root.canonicalize().unwrap_or(root))
+        .unwrap_or_else(|| report_root.to_path_buf());
+    let mut rebased = diagnostics
+        .iter()
+        .map(|diagnostic| (*diagnostic).clone())
+        .collect::<Vec<_>>();
+    rebase_diagnostic_paths_between_roots(&mut rebased, report_root, &annotation_root);
+    github_output::render_diagnostics(&annotation_root, &rebased.iter().collect::<Vec<_>>())
+}
+
 pub(crate) fn text_display_root(path_arg: &Path, default_root: &Path) -> PathBuf {
     if path_arg.is_absolute() {
         return default_root.to_path_buf();
@@ -376,6 +1024,16 @@ fn path_contains_component(path: &Path, target_component: &str) -> bool {
     })
 }
 
+/// `project`'s root relative to the config directory, which `[[overrides]]`
+/// globs are written against.
+fn config_relative_project_root(project: &NoirProject, config_root: &Path) -> String {
+    project
+        .root
+        .strip_prefix(config_root)
+        .map(|relative| relative.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
 fn path_contains_component_relative(path: &Path, base: &Path, target_component: &str) -> bool {
     let relative = path.strip_prefix(base).unwrap_or(path);
     relative.components().any(|component| {
@@ -485,14 +1143,10 @@ fn rebase_file_path_between_roots(file: &str, source_root: &Path, target_root: &
     normalize_file_path(&rebased.to_string_lossy())
 }
 
+/// Keeps diagnostics located in `changed_files`, which must be normalized.
 fn retain_changed_only(diagnostics: &mut Vec<Diagnostic>, changed_files: &BTreeSet<String>) {
-    let normalized = changed_files
-        .iter()
-        .map(|file| normalize_file_path(file))
-        .collect::<BTreeSet<_>>();
-
     diagnostics.retain(|diagnostic| {
-        normalized.contains(&normalize_file_path(&diagnostic.primary_span.file))
+        changed_files.contains(&normalize_file_path(&diagnostic.primary_span.file))
     });
 }
 
@@ -534,8 +1188,18 @@ pub(crate) fn passes_thresholds(
 
 pub(crate) fn suppression_visible(format: OutputFormat, show_suppressed: bool) -> bool {
     match format {
-        OutputFormat::Text => show_suppressed,
-        OutputFormat::Json | OutputFormat::Sarif => true,
+        OutputFormat::Text | OutputFormat::Markdown => show_suppressed,
+        OutputFormat::Json
+        | OutputFormat::Sarif
+        | OutputFormat::Junit
+        | OutputFormat::Gitlab
+        | OutputFormat::Github
+        | OutputFormat::Html
+        | OutputFormat::Rdjson
+        | OutputFormat::Rdjsonl
+        | OutputFormat::Ndjson
+        | OutputFormat::Checkstyle
+        | OutputFormat::Sonarqube => true,
     }
 }
 
@@ -569,7 +1233,66 @@ fn severity_threshold_rank(threshold: SeverityThreshold) -> u8 {
     }
 }
 
-fn discover_noir_projects(target: &Path) -> std::io::Result<Vec<NoirProject>> {
+/// [`PathFilter`] globs anchored at the config directory, applied to
+/// filesystem paths while discovering projects and collecting sources.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SourceFilter {
+    root: PathBuf,
+    filter: PathFilter,
+}
+
+impl SourceFilter {
+    pub(crate) fn new(root: &Path, filter: PathFilter) -> Self {
+        Self {
+            root: root.canonicalize().unwrap_or_else(|_| root.to_path_buf()),
+            filter,
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.filter.is_empty()
+    }
+
+    pub(crate) fn path_filter(&self) -> &PathFilter {
+        &self.filter
+    }
+
+    /// Whether `path` is excluded; excluded directories are not walked.
+    pub(crate) fn excludes(&self, path: &Path) -> bool {
+        !self.filter.exclude.is_empty()
+            && self
+                .relative(path)
+                .is_some_and(|relative| self.filter.excludes(&relative))
+    }
+
+    /// Whether the source file at `path` is linted.
+    pub(crate) fn includes_file(&self, path: &Path) -> bool {
+        self.filter.is_empty()
+            || self
+                .relative(path)
+                .is_none_or(|relative| self.filter.includes_file(&relative))
+    }
+
+    /// `path` relative to the config directory. Paths outside it are never
+    /// filtered.
+    fn relative(&self, path: &Path) -> Option<String> {
+        let relative = match path.strip_prefix(&self.root) {
+            Ok(relative) => relative.to_path_buf(),
+            Err(_) => path
+                .canonicalize()
+                .ok()?
+                .strip_prefix(&self.root)
+                .ok()?
+                .to_path_buf(),
+        };
+        Some(normalize_file_path(&relative.to_string_lossy()))
+    }
+}
+
+pub(crate) fn discover_noir_projects(
+    target: &Path,
+    filter: &SourceFilter,
+) -> std::io::Result<Vec<NoirProject>> {
     let mut roots = Vec::<PathBuf>::new();
 
     if target.is_file() {
@@ -591,7 +1314,7 @@ fn discover_noir_projects(target: &Path) -> std::io::Result<Vec<NoirProject>> {
     } else if let Some(root) = nearest_project_root(target) {
         append_expanded_project_roots(&root, &mut roots)?;
     } else {
-        collect_project_roots(target, &mut roots)?;
+        collect_project_roots(target, filter, &mut roots)?;
     }
 
     roots.sort();
@@ -600,6 +1323,7 @@ fn discover_noir_projects(target: &Path) -> std::io::Result<Vec<NoirProject>> {
     let canonical_roots = roots
         .into_iter()
         .filter_map(|root| root.canonicalize().ok())
+        .filter(|root| !filter.excludes(root) && has_included_sources(root, filter))
         .collect::<Vec<_>>();
 
     Ok(canonical_roots
@@ -628,7 +1352,7 @@ fn append_expanded_project_roots(root: &Path, out: &mut Vec<PathBuf>) -> std::io
     Ok(())
 }
 
-fn nearest_project_root(start: &Path) -> Option<PathBuf> {
+pub(crate) fn nearest_project_root(start: &Path) -> Option<PathBuf> {
     let mut current = Some(start);
     while let Some(path) = current {
         if path.join("Nargo.toml").is_file() {
@@ -639,7 +1363,20 @@ fn nearest_project_root(start: &Path) -> Option<PathBuf> {
     None
 }
 
-fn collect_project_roots(dir: &Path, out: &mut Vec<PathBuf>) -> std::io::Result<()> {
+/// Whether any source under `root/src` survives an `include` list.
+fn has_included_sources(root: &Path, filter: &SourceFilter) -> bool {
+    if filter.filter.include.is_empty() {
+        return true;
+    }
+    let mut sources = Vec::<PathBuf>::new();
+    collect_noir_sources(&root.join("src"), filter, &mut sources).is_ok() && !sources.is_empty()
+}
+
+fn collect_project_roots(
+    dir: &Path,
+    filter: &SourceFilter,
+    out: &mut Vec<PathBuf>,
+) -> std::io::Result<()> {
     if dir.join("Nargo.toml").is_file() {
         append_expanded_project_roots(dir, out)?;
         return Ok(());
@@ -650,15 +1387,15 @@ fn collect_project_roots(dir: &Path, out: &mut Vec<PathBuf>) -> std::io::Result<
 
     for entry in entries {
         let path = entry.path();
-        if path.is_dir() {
-            collect_project_roots(&path, out)?;
+        if path.is_dir() && !filter.excludes(&path) {
+            collect_project_roots(&path, filter, out)?;
         }
     }
 
     Ok(())
 }
 
-fn workspace_members(root: &Path) -> std::io::Result<Vec<PathBuf>> {
+pub(crate) fn workspace_members(root: &Path) -> std::io::Result<Vec<PathBuf>> {
     let manifest_path = root.join("Nargo.toml");
     if !manifest_path.is_file() {
         return Ok(Vec::new());
@@ -703,13 +1440,19 @@ fn select_entry_file(root: &Path) -> Option<PathBuf> {
     }
 
     let mut candidates = Vec::<PathBuf>::new();
-    collect_noir_sources(&root.join("src"), &mut candidates).ok()?;
+    collect_noir_sources(&root.join("src"), &SourceFilter::default(), &mut candidates).ok()?;
     candidates.sort();
     candidates.into_iter().next()
 }
 
-fn collect_noir_sources(dir: &Path, out: &mut Vec<PathBuf>) -> std::io::Result<()> {
-    if !dir.exists() || !dir.is_dir() {
+/// Appends the `.nr` files under `dir` that `filter` lets through, skipping
+/// excluded directories without reading them.
+pub(crate) fn collect_noir_sources(
+    dir: &Path,
+    filter: &SourceFilter,
+    out: &mut Vec<PathBuf>,
+) -> std::io::Result<()> {
+    if !dir.exists() || !dir.is_dir() || filter.excludes(dir) {
         return Ok(());
     }
 
@@ -719,13 +1462,14 @@ fn collect_noir_sources(dir: &Path, out: &mut Vec<PathBuf>) -> std::io::Result<(
     for entry in entries {
         let path = entry.path();
         if path.is_dir() {
-            collect_noir_sources(&path, out)?;
+            collect_noir_sources(&path, filter, out)?;
             continue;
         }
         if path
             .extension()
             .and_then(|ext| ext.to_str())
             .is_some_and(|ext| ext.eq_ignore_ascii_case("nr"))
+            && filter.includes_file(&path)
         {
             out.push(path);
         }
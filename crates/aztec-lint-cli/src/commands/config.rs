@@ -0,0 +1,90 @@
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aztec_lint_core::config::{ConfigSource, RuleOverrides};
+use clap::Args;
+
+use crate::cli::{CliError, ConfigUnknownKeys, RuleOverrideFlags};
+use crate::commands::check::load_config;
+use crate::exit_codes;
+
+#[derive(Clone, Debug, Args)]
+pub struct ConfigShowArgs {
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+    #[arg(long, default_value = "aztec")]
+    pub profile: String,
+    #[command(flatten)]
+    pub overrides: RuleOverrideFlags,
+    #[arg(long, default_value = "error", value_enum, value_name = "MODE")]
+    pub config_unknown_keys: ConfigUnknownKeys,
+}
+
+#[derive(Clone, Debug, Args)]
+pub struct ConfigValidateArgs {
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+    #[arg(long, default_value = "error", value_enum, value_name = "MODE")]
+    pub config_unknown_keys: ConfigUnknownKeys,
+}
+
+/// Prints the merged configuration, the profile resolution order, and the
+/// effective level of every enabled rule together with what set it.
+pub fn show(args: ConfigShowArgs) -> Result<ExitCode, CliError> {
+    let loaded = load_config(&args.path, args.config_unknown_keys.policy())?;
+    let config = &loaded.config;
+    let profile_order = config.resolve_profile_order(&args.profile)?;
+    let resolved = config.resolve_profile(&args.profile)?;
+    let trace = config.rule_level_trace(&args.profile, &args.overrides.rule_overrides())?;
+    let merged = toml::to_string(config).map_err(|source| {
+        CliError::Runtime(format!("failed to serialize configuration: {source}"))
+    })?;
+
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "config_source={} profile={}",
+        config_source_label(&loaded.source),
+        resolved.name
+    );
+    let _ = writeln!(output, "profile_order={}", profile_order.join(" -> "));
+    let _ = writeln!(output, "rulesets={}", resolved.rulesets.join(", "));
+    let _ = writeln!(output);
+    let _ = writeln!(output, "--- merged config ---");
+    let _ = write!(output, "{merged}");
+    let _ = writeln!(output);
+    let _ = writeln!(output, "--- effective rule levels ---");
+    let _ = writeln!(output, "RULE_ID\tLEVEL\tSOURCE");
+    for (rule_id, steps) in &trace {
+        if let Some(step) = steps.last() {
+            let _ = writeln!(output, "{rule_id}\t{}\t{}", step.level, step.source);
+        }
+    }
+    print!("{output}");
+    Ok(exit_codes::success())
+}
+
+/// Loads the configuration and resolves every profile without running any
+/// analysis, so errors surface as a non-zero exit.
+pub fn validate(args: ConfigValidateArgs) -> Result<ExitCode, CliError> {
+    let loaded = load_config(&args.path, args.config_unknown_keys.policy())?;
+    for profile in loaded.config.profile.keys() {
+        loaded
+            .config
+            .effective_rule_levels(profile, &RuleOverrides::default())?;
+    }
+    println!(
+        "config ok: {} ({} profile(s))",
+        config_source_label(&loaded.source),
+        loaded.config.profile.len()
+    );
+    Ok(exit_codes::success())
+}
+
+fn config_source_label(source: &ConfigSource) -> String {
+    match source {
+        ConfigSource::File(path) => path.display().to_string(),
+        ConfigSource::Default => "built-in defaults".to_string(),
+    }
+}
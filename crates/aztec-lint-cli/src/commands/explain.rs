@@ -1,10 +1,11 @@
+use std::fmt::Write as _;
 use std::process::ExitCode;
 
 use aztec_lint_core::lints::LintLifecycleState;
 use clap::Args;
 
 use crate::cli::CliError;
-use crate::commands::catalog::{confidence_label, find_rule};
+use crate::commands::catalog::{RuleDoc, confidence_label, find_rule};
 use crate::exit_codes;
 
 #[derive(Debug, Args)]
@@ -16,60 +17,80 @@ pub fn run(args: ExplainArgs) -> Result<ExitCode, CliError> {
     let rule_id = args.rule_id.trim().to_ascii_uppercase();
     let rule = find_rule(&rule_id).ok_or(CliError::UnknownRule { rule_id })?;
 
-    println!("Rule: {}", rule.id);
-    println!("Pack: {}", rule.pack);
-    println!("Category: {}", rule.category.as_str());
-    println!("Maturity: {}", rule.maturity.as_str());
-    println!("Policy: {}", rule.policy);
-    println!("Default Level: {}", rule.default_level);
-    println!("Confidence: {}", confidence_label(rule.confidence));
-    println!("Introduced In: {}", rule.introduced_in);
-    println!("Lifecycle: {}", lifecycle_label(rule.lifecycle));
+    print!("{}", render_explanation(rule));
+    Ok(exit_codes::success())
+}
+
+pub(crate) fn render_explanation(rule: &RuleDoc) -> String {
+    let mut output = String::new();
+    let _ = writeln!(output, "Rule: {}", rule.id);
+    let _ = writeln!(output, "Pack: {}", rule.pack);
+    let _ = writeln!(output, "Category: {}", rule.category.as_str());
+    let _ = writeln!(output, "Maturity: {}", rule.maturity.as_str());
+    let _ = writeln!(output, "Policy: {}", rule.policy);
+    let _ = writeln!(output, "Default Level: {}", rule.default_level);
+    let _ = writeln!(output, "Confidence: {}", confidence_label(rule.confidence));
+    let _ = writeln!(output, "Introduced In: {}", rule.introduced_in);
+    let _ = writeln!(output, "Lifecycle: {}", lifecycle_label(rule.lifecycle));
 
     match rule.lifecycle {
         LintLifecycleState::Deprecated {
             replacement, note, ..
         } => {
             if let Some(replacement) = replacement {
-                println!("Replacement: {replacement}");
+                let _ = writeln!(output, "Replacement: {replacement}");
             }
-            println!("Lifecycle Note: {note}");
+            let _ = writeln!(output, "Lifecycle Note: {note}");
         }
         LintLifecycleState::Renamed { to, .. } => {
-            println!("Replacement: {to}");
+            let _ = writeln!(output, "Replacement: {to}");
         }
         LintLifecycleState::Removed { note, .. } => {
-            println!("Lifecycle Note: {note}");
+            let _ = writeln!(output, "Lifecycle Note: {note}");
         }
         LintLifecycleState::Active => {}
     }
 
-    println!();
-    println!("Summary:");
-    println!("{}", rule.docs.summary);
-    println!();
-    println!("What It Does:");
-    println!("{}", rule.docs.what_it_does);
-    println!();
-    println!("Why This Matters:");
-    println!("{}", rule.docs.why_this_matters);
-    println!();
-    println!("Known Limitations:");
-    println!("{}", rule.docs.known_limitations);
-    println!();
-    println!("How To Fix:");
-    println!("{}", rule.docs.how_to_fix);
-    println!();
-    println!("Examples:");
+    let _ = writeln!(output);
+    let _ = writeln!(output, "Summary:");
+    let _ = writeln!(output, "{}", rule.docs.summary);
+    let _ = writeln!(output);
+    let _ = writeln!(output, "What It Does:");
+    let _ = writeln!(output, "{}", rule.docs.what_it_does);
+    let _ = writeln!(output);
+    let _ = writeln!(output, "Why This Matters:");
+    let _ = writeln!(output, "{}", rule.docs.why_this_matters);
+    let _ = writeln!(output);
+    let _ = writeln!(output, "Known Limitations:");
+    let _ = writeln!(output, "{}", rule.docs.known_limitations);
+    let _ = writeln!(output);
+    let _ = writeln!(output, "How To Fix:");
+    let _ = writeln!(output, "{}", rule.docs.how_to_fix);
+    let _ = writeln!(output);
+    let _ = writeln!(output, "Examples:");
     for example in rule.docs.examples {
-        println!("- {example}");
+        let _ = writeln!(output, "- {example}");
     }
-    println!();
-    println!("References:");
+    let _ = writeln!(output);
+    let _ = writeln!(output, "References:");
     for reference in rule.docs.references {
-        println!("- {reference}");
+        let _ = writeln!(output, "- {reference}");
     }
-    Ok(exit_codes::success())
+    if !rule.options.is_empty() {
+        let _ = writeln!(output);
+        let _ = writeln!(output, "Options ([lints.{}]):", rule.id);
+        for option in rule.options {
+            let _ = writeln!(
+                output,
+                "- {} ({}, default {}): {}",
+                option.name,
+                option.default.type_name(),
+                option.default.value(),
+                option.description
+            );
+        }
+    }
+    output
 }
 
 fn lifecycle_label(lifecycle: LintLifecycleState) -> String {
@@ -1,19 +1,20 @@
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
+use aztec_lint_core::config::ConfigSource;
 use aztec_lint_core::diagnostics::Diagnostic;
 use aztec_lint_core::fix::{
     FixApplicationMode, FixApplicationReport, FixSource, SkippedFixReason, apply_fixes,
 };
-use aztec_lint_core::output::json as json_output;
-use aztec_lint_core::output::sarif as sarif_output;
-use aztec_lint_core::output::text::{CheckTextReport, render_check_report};
+use aztec_lint_core::output::ansi::{Colorizer, Stream};
+use aztec_lint_core::timings::Timings;
 use clap::Args;
 
 use crate::cli::{CliError, CommonLintFlags, OutputFormat, TargetSelectionFlags};
 use crate::commands::check::{
-    collect_lint_run, diagnostics_for_text_display, has_blocking_diagnostics, passes_thresholds,
-    suppression_visible, text_display_root,
+    CheckRenderContext, collect_lint_run, has_blocking_diagnostics, passes_thresholds,
+    print_timings_to_stderr, suppression_visible, write_report,
 };
 use crate::exit_codes;
 
@@ -40,6 +41,7 @@ pub fn run(args: FixArgs) -> Result<ExitCode, CliError> {
         args.changed_only,
         args.targets.resolve(),
         args.lint.rule_overrides(),
+        args.lint.run_options(),
     )?;
 
     let fix_mode = if args.dry_run {
@@ -65,6 +67,7 @@ pub fn run(args: FixArgs) -> Result<ExitCode, CliError> {
             args.changed_only,
             args.targets.resolve(),
             args.lint.rule_overrides(),
+            args.lint.run_options(),
         )?
     };
 
@@ -75,6 +78,11 @@ pub fn run(args: FixArgs) -> Result<ExitCode, CliError> {
         args.lint.severity_threshold,
         include_suppressed,
     );
+    let blocking = has_blocking_diagnostics(
+        &final_run.diagnostics,
+        args.lint.min_confidence,
+        args.lint.severity_threshold,
+    );
 
     render_fix_result(FixRenderContext {
         format: args.lint.format,
@@ -86,13 +94,11 @@ pub fn run(args: FixArgs) -> Result<ExitCode, CliError> {
         diagnostics: &diagnostics,
         sarif_root: final_run.report_root.as_path(),
         fix_report: &fix_report,
+        config_source: &final_run.config_source,
+        exit_code: exit_codes::diagnostics_found_status(blocking),
+        timings: final_run.timings.as_ref(),
     })?;
 
-    let blocking = has_blocking_diagnostics(
-        &final_run.diagnostics,
-        args.lint.min_confidence,
-        args.lint.severity_threshold,
-    );
     Ok(exit_codes::diagnostics_found(blocking))
 }
 
@@ -138,124 +144,119 @@ struct FixRenderContext<'a> {
     diagnostics: &'a [&'a Diagnostic],
     sarif_root: &'a Path,
     fix_report: &'a FixApplicationReport,
+    config_source: &'a ConfigSource,
+    exit_code: u8,
+    timings: Option<&'a Timings>,
 }
 
+/// Renders the remaining diagnostics like `check` does; the `text` format is
+/// prefixed with the fix summary in place of the usual run header.
 fn render_fix_result(context: FixRenderContext<'_>) -> Result<(), CliError> {
-    match context.format {
-        OutputFormat::Text => {
-            let mode_label = if context.dry_run { "dry-run" } else { "apply" };
-            println!(
-                "fix path={} profile={} changed_only={} mode={} active_rules={effective_rules}",
-                context.path.display(),
-                context.profile,
-                context.changed_only,
-                mode_label,
-                effective_rules = context.effective_rules
-            );
-            println!(
-                "fixes_total={} fixes_selected={} fixes_skipped={} files_changed={}",
-                context.fix_report.total_candidates,
-                context.fix_report.selected.len(),
-                context.fix_report.skipped.len(),
-                context.fix_report.files_changed,
-            );
-            let (selected_explicit, selected_structured) =
-                source_breakdown_selected(context.fix_report);
-            let (skipped_explicit, skipped_structured) =
-                source_breakdown_skipped(context.fix_report);
-            println!(
-                "fixes_selected_explicit={} fixes_selected_structured={} fixes_skipped_explicit={} fixes_skipped_structured={}",
-                selected_explicit, selected_structured, skipped_explicit, skipped_structured,
-            );
-            let (
-                skipped_suppressed,
-                skipped_unsafe,
-                skipped_mixed_file,
-                skipped_overlap,
-                skipped_invalid_span,
-                skipped_noop,
-            ) = skipped_reason_breakdown(context.fix_report);
-            println!(
-                "fixes_skipped_suppressed={} fixes_skipped_unsafe={} fixes_skipped_mixed_file={} fixes_skipped_overlap={} fixes_skipped_invalid_span={} fixes_skipped_noop={}",
-                skipped_suppressed,
-                skipped_unsafe,
-                skipped_mixed_file,
-                skipped_overlap,
-                skipped_invalid_span,
-                skipped_noop,
-            );
+    let stdout = std::io::stdout();
+    let mut writer = std::io::BufWriter::new(stdout.lock());
+    let write_error =
+        |source: std::io::Error| CliError::Runtime(format!("failed to write report: {source}"));
+    if context.format == OutputFormat::Text {
+        write_fix_summary(&mut writer, &context).map_err(write_error)?;
+    }
+    write_report(
+        &mut writer,
+        CheckRenderContext {
+            format: context.format,
+            colors: Colorizer::for_stream(Stream::Stdout),
+            path: context.path,
+            show_run_header: false,
+            profile: context.profile,
+            changed_only: context.changed_only,
+            effective_rules: context.effective_rules,
+            diagnostics: context.diagnostics,
+            sarif_root: context.sarif_root,
+            config_source: context.config_source,
+            exit_code: context.exit_code,
+            timings: context.timings,
+        },
+    )?;
+    writer.flush().map_err(write_error)?;
+    print_timings_to_stderr(context.format, context.timings);
+    Ok(())
+}
 
-            for selected in &context.fix_report.selected {
-                println!(
-                    "fix_selected rule={} source={} group={} edits={} file={} span={}..{} provenance={}",
-                    selected.rule_id,
-                    source_label(selected.source),
-                    selected.group_id,
-                    selected.edit_count,
-                    selected.file,
-                    selected.start,
-                    selected.end,
-                    selected.provenance.as_deref().unwrap_or("-"),
-                );
-            }
+fn write_fix_summary(writer: &mut dyn Write, context: &FixRenderContext<'_>) -> io::Result<()> {
+    let mode_label = if context.dry_run { "dry-run" } else { "apply" };
+    writeln!(
+        writer,
+        "fix path={} profile={} changed_only={} mode={} active_rules={effective_rules}",
+        context.path.display(),
+        context.profile,
+        context.changed_only,
+        mode_label,
+        effective_rules = context.effective_rules
+    )?;
+    writeln!(
+        writer,
+        "fixes_total={} fixes_selected={} fixes_skipped={} files_changed={}",
+        context.fix_report.total_candidates,
+        context.fix_report.selected.len(),
+        context.fix_report.skipped.len(),
+        context.fix_report.files_changed,
+    )?;
+    let (selected_explicit, selected_structured) = source_breakdown_selected(context.fix_report);
+    let (skipped_explicit, skipped_structured) = source_breakdown_skipped(context.fix_report);
+    writeln!(
+        writer,
+        "fixes_selected_explicit={} fixes_selected_structured={} fixes_skipped_explicit={} fixes_skipped_structured={}",
+        selected_explicit, selected_structured, skipped_explicit, skipped_structured,
+    )?;
+    let (
+        skipped_suppressed,
+        skipped_unsafe,
+        skipped_mixed_file,
+        skipped_overlap,
+        skipped_invalid_span,
+        skipped_noop,
+    ) = skipped_reason_breakdown(context.fix_report);
+    writeln!(
+        writer,
+        "fixes_skipped_suppressed={} fixes_skipped_unsafe={} fixes_skipped_mixed_file={} fixes_skipped_overlap={} fixes_skipped_invalid_span={} fixes_skipped_noop={}",
+        skipped_suppressed,
+        skipped_unsafe,
+        skipped_mixed_file,
+        skipped_overlap,
+        skipped_invalid_span,
+        skipped_noop,
+    )?;
 
-            for skipped in &context.fix_report.skipped {
-                println!(
-                    "fix_skipped rule={} source={} group={} edits={} file={} span={}..{} reason={} provenance={}",
-                    skipped.rule_id,
-                    source_label(skipped.source),
-                    skipped.group_id,
-                    skipped.edit_count,
-                    skipped.file,
-                    skipped.start,
-                    skipped.end,
-                    skipped_reason_label(skipped.reason),
-                    skipped.provenance.as_deref().unwrap_or("-"),
-                );
-            }
+    for selected in &context.fix_report.selected {
+        writeln!(
+            writer,
+            "fix_selected rule={} source={} group={} edits={} file={} span={}..{} provenance={}",
+            selected.rule_id,
+            source_label(selected.source),
+            selected.group_id,
+            selected.edit_count,
+            selected.file,
+            selected.start,
+            selected.end,
+            selected.provenance.as_deref().unwrap_or("-"),
+        )?;
+    }
 
-            let display_root = text_display_root(context.path, context.sarif_root);
-            let diagnostics = diagnostics_for_text_display(
-                context.diagnostics,
-                context.sarif_root,
-                display_root.as_path(),
-            );
-            let diagnostic_refs = diagnostics.iter().collect::<Vec<_>>();
-            let rendered = render_check_report(CheckTextReport {
-                path: context.path,
-                source_root: display_root.as_path(),
-                show_run_header: false,
-                profile: context.profile,
-                changed_only: context.changed_only,
-                active_rules: context.effective_rules,
-                diagnostics: &diagnostic_refs,
-            });
-            print!("{rendered}");
-            Ok(())
-        }
-        OutputFormat::Json => {
-            let rendered =
-                json_output::render_diagnostics(context.diagnostics).map_err(|source| {
-                    CliError::Runtime(format!(
-                        "failed to serialize fix diagnostics as JSON: {source}"
-                    ))
-                })?;
-            println!("{rendered}");
-            Ok(())
-        }
-        OutputFormat::Sarif => {
-            let rendered =
-                sarif_output::render_diagnostics(context.sarif_root, context.diagnostics).map_err(
-                    |source| {
-                        CliError::Runtime(format!(
-                            "failed to serialize fix diagnostics as SARIF: {source}"
-                        ))
-                    },
-                )?;
-            println!("{rendered}");
-            Ok(())
-        }
+    for skipped in &context.fix_report.skipped {
+        writeln!(
+            writer,
+            "fix_skipped rule={} source={} group={} edits={} file={} span={}..{} reason={} provenance={}",
+            skipped.rule_id,
+            source_label(skipped.source),
+            skipped.group_id,
+            skipped.edit_count,
+            skipped.file,
+            skipped.start,
+            skipped.end,
+            skipped_reason_label(skipped.reason),
+            skipped.provenance.as_deref().unwrap_or("-"),
+        )?;
     }
+    Ok(())
 }
 
 fn source_breakdown_selected(report: &FixApplicationReport) -> (usize, usize) {
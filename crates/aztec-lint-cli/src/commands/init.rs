@@ -0,0 +1,397 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aztec_lint_aztec::{SourceUnit, should_activate_aztec};
+use aztec_lint_core::config::{AztecConfig, CONFIG_FILE_PRIMARY, RuleOverrides};
+use clap::Args;
+use toml::Value as TomlValue;
+
+use crate::cli::{CliError, ResolvedTargetSelection};
+use crate::commands::check::{
+    LintRunOptions, SourceFilter, collect_lint_run, collect_noir_sources, config_root_for_target,
+    discover_noir_projects, workspace_members,
+};
+use crate::exit_codes;
+
+const CONFIG_DOCS_URL: &str =
+    "https://github.com/NethermindEth/aztec-lint/blob/main/docs/configuration.md";
+
+#[derive(Clone, Debug, Args)]
+pub struct InitArgs {
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+    /// Overwrite an existing `aztec-lint.toml`.
+    #[arg(long)]
+    pub force: bool,
+    /// Allow every rule that currently reports findings, so the first run starts clean.
+    #[arg(long)]
+    pub from_current_findings: bool,
+}
+
+/// Everything `init` learned about the target that ends up in the generated file.
+struct InitPlan {
+    profile: &'static str,
+    aztec: Option<AztecConfig>,
+    members: Vec<String>,
+    findings: BTreeMap<String, usize>,
+}
+
+pub fn run(args: InitArgs) -> Result<ExitCode, CliError> {
+    let root = config_root_for_target(&args.path).to_path_buf();
+    let config_path = root.join(CONFIG_FILE_PRIMARY);
+    if config_path.exists() && !args.force {
+        return Err(CliError::Runtime(format!(
+            "'{}' already exists (pass --force to overwrite it)",
+            config_path.display()
+        )));
+    }
+
+    let projects =
+        discover_noir_projects(&args.path, &SourceFilter::default()).map_err(|source| {
+            CliError::Runtime(format!(
+                "failed to discover Noir projects under '{}': {source}",
+                args.path.display()
+            ))
+        })?;
+    if projects.is_empty() {
+        return Err(CliError::Runtime(format!(
+            "no Noir projects (Nargo.toml) found under '{}'",
+            args.path.display()
+        )));
+    }
+
+    let mut sources = Vec::<SourceUnit>::new();
+    for project in &projects {
+        let mut files = Vec::<PathBuf>::new();
+        collect_noir_sources(
+            &project.root.join("src"),
+            &SourceFilter::default(),
+            &mut files,
+        )
+        .and_then(|()| {
+            for file in files {
+                let text = fs::read_to_string(&file)?;
+                sources.push(SourceUnit::new(file.display().to_string(), text));
+            }
+            Ok(())
+        })
+        .map_err(|source| {
+            CliError::Runtime(format!(
+                "failed to read Noir sources for '{}': {source}",
+                project.root.display()
+            ))
+        })?;
+    }
+
+    let detected = detect_aztec_config(&sources);
+    let is_aztec = should_activate_aztec("default", &sources, &detected);
+    let profile = if is_aztec { "aztec" } else { "noir" };
+
+    let canonical_root = root.canonicalize().unwrap_or_else(|_| root.clone());
+    let member_roots = workspace_members(&canonical_root).unwrap_or_default();
+    let member_roots = if member_roots.is_empty() {
+        projects
+            .iter()
+            .map(|project| project.root.clone())
+            .collect()
+    } else {
+        member_roots
+    };
+    let members = member_roots
+        .iter()
+        .map(|member| {
+            let member = member.canonicalize().unwrap_or_else(|_| member.clone());
+            match member.strip_prefix(&canonical_root) {
+                Ok(relative) if relative.as_os_str().is_empty() => ".".to_string(),
+                Ok(relative) => relative.display().to_string(),
+                Err(_) => member.display().to_string(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let findings = if args.from_current_findings {
+        current_findings(&args.path, profile)?
+    } else {
+        BTreeMap::new()
+    };
+
+    let rendered = render_config(&InitPlan {
+        profile,
+        aztec: is_aztec.then_some(detected),
+        members,
+        findings,
+    });
+    fs::write(&config_path, rendered).map_err(|source| {
+        CliError::Runtime(format!(
+            "failed to write '{}': {source}",
+            config_path.display()
+        ))
+    })?;
+
+    println!(
+        "wrote '{}' (profile `{profile}`, {} project(s))",
+        config_path.display(),
+        projects.len()
+    );
+    if !is_aztec {
+        println!("no Aztec sources detected; lint with `aztec-lint check --profile noir`");
+    }
+    Ok(exit_codes::success())
+}
+
+/// Unsuppressed finding counts per rule for a lint run with the chosen profile.
+fn current_findings(path: &Path, profile: &str) -> Result<BTreeMap<String, usize>, CliError> {
+    let lint_run = collect_lint_run(
+        path,
+        profile,
+        false,
+        ResolvedTargetSelection::all_enabled(),
+        RuleOverrides::default(),
+        LintRunOptions::default(),
+    )?;
+    let mut findings = BTreeMap::<String, usize>::new();
+    for diagnostic in lint_run
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| !diagnostic.suppressed)
+    {
+        *findings.entry(diagnostic.rule_id.clone()).or_default() += 1;
+    }
+    Ok(findings)
+}
+
+/// Starts from the defaults and adopts the contract attribute, external
+/// entrypoint kinds, and Aztec import roots that the sources actually use.
+fn detect_aztec_config(sources: &[SourceUnit]) -> AztecConfig {
+    let mut config = AztecConfig::default();
+
+    if let Some(attribute) = sources
+        .iter()
+        .find_map(|source| contract_attribute(&source.text))
+    {
+        config.contract_attribute = attribute;
+    }
+
+    let external_prefix = format!("#[{}(", config.external_attribute);
+    for line in sources.iter().flat_map(|source| source.text.lines()) {
+        let Some(arguments) = line.trim_start().strip_prefix(&external_prefix) else {
+            continue;
+        };
+        let kind = arguments
+            .split(')')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .trim_matches('"')
+            .to_string();
+        if !kind.is_empty() && !config.external_kinds.contains(&kind) {
+            config.external_kinds.push(kind);
+        }
+    }
+
+    for line in sources.iter().flat_map(|source| source.text.lines()) {
+        if let Some(prefix) = aztec_import_prefix(line)
+            && !config.imports_prefixes.contains(&prefix)
+        {
+            config.imports_prefixes.push(prefix);
+        }
+    }
+
+    config
+}
+
+/// Name of the attribute directly above the first `contract` declaration.
+fn contract_attribute(text: &str) -> Option<String> {
+    let mut previous_attribute = None::<String>;
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with("//") {
+            continue;
+        }
+        if line.starts_with("contract ") || line.starts_with("pub contract ") {
+            return previous_attribute;
+        }
+        previous_attribute = line
+            .strip_prefix("#[")
+            .and_then(|attribute| attribute.strip_suffix(']'))
+            .filter(|attribute| {
+                attribute
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+            })
+            .map(str::to_string);
+    }
+    None
+}
+
+/// Import path up to and including its `aztec` segment, e.g. `dep::aztec`.
+fn aztec_import_prefix(line: &str) -> Option<String> {
+    let trimmed = line.trim_start();
+    let clause = trimmed
+        .strip_prefix("use ")
+        .or_else(|| trimmed.strip_prefix("pub use "))?
+        .trim_start();
+    let (leading, path) = match clause.strip_prefix("::") {
+        Some(path) => ("::", path),
+        None => ("", clause),
+    };
+    let segments = path.split("::").collect::<Vec<_>>();
+    let index = segments.iter().position(|segment| *segment == "aztec")?;
+    Some(format!("{leading}{}", segments[..=index].join("::")))
+}
+
+fn render_config(plan: &InitPlan) -> String {
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "# aztec-lint configuration generated by `aztec-lint init`."
+    );
+    let _ = writeln!(output, "# Reference: {CONFIG_DOCS_URL}");
+    let _ = writeln!(output, "#");
+    let _ = writeln!(output, "# Noir projects:");
+    for member in &plan.members {
+        let _ = writeln!(output, "#   - {member}");
+    }
+    if plan.aztec.is_some() {
+        let _ = writeln!(
+            output,
+            "# Aztec sources detected; `aztec-lint check` uses the `aztec` profile by default."
+        );
+    } else {
+        let _ = writeln!(
+            output,
+            "# No Aztec sources detected; lint with `aztec-lint check --profile noir`."
+        );
+    }
+    let _ = writeln!(output);
+
+    let _ = writeln!(
+        output,
+        "# Shared Noir rules, inherited by every other profile."
+    );
+    let _ = writeln!(output, "[profile.default]");
+    let _ = writeln!(output, "ruleset = [\"noir_core\"]");
+    let _ = writeln!(output);
+    let _ = writeln!(output, "[profile.{}]", plan.profile);
+    let _ = writeln!(output, "extends = [\"default\"]");
+    if plan.aztec.is_some() {
+        let _ = writeln!(output, "ruleset = [\"aztec_pack\"]");
+    }
+    if plan.findings.is_empty() {
+        let _ = writeln!(
+            output,
+            "# Per-rule levels, e.g. deny = [\"NOIR001\"], warn = [], allow = []."
+        );
+    } else {
+        let _ = writeln!(
+            output,
+            "# Rules with findings when this file was generated; remove entries as they are fixed."
+        );
+        let _ = writeln!(output, "allow = [");
+        for (rule_id, count) in &plan.findings {
+            let _ = writeln!(output, "    {}, # {count} finding(s)", toml_string(rule_id));
+        }
+        let _ = writeln!(output, "]");
+    }
+
+    if let Some(aztec) = &plan.aztec {
+        let _ = writeln!(output);
+        let _ = writeln!(
+            output,
+            "# Names used to recognize Aztec contracts, entrypoints, and imports."
+        );
+        let _ = writeln!(output, "[aztec]");
+        for (key, value) in [
+            ("contract_attribute", &aztec.contract_attribute),
+            ("external_attribute", &aztec.external_attribute),
+        ] {
+            let _ = writeln!(output, "{key} = {}", toml_string(value));
+        }
+        let _ = writeln!(
+            output,
+            "external_kinds = {}",
+            toml_array(&aztec.external_kinds)
+        );
+        for (key, value) in [
+            ("only_self_attribute", &aztec.only_self_attribute),
+            ("initializer_attribute", &aztec.initializer_attribute),
+            ("storage_attribute", &aztec.storage_attribute),
+        ] {
+            let _ = writeln!(output, "{key} = {}", toml_string(value));
+        }
+        let _ = writeln!(
+            output,
+            "imports_prefixes = {}",
+            toml_array(&aztec.imports_prefixes)
+        );
+    }
+    output
+}
+
+fn toml_string(value: &str) -> String {
+    TomlValue::String(value.to_string()).to_string()
+}
+
+fn toml_array(values: &[String]) -> String {
+    let items = values
+        .iter()
+        .map(|value| toml_string(value))
+        .collect::<Vec<_>>();
+    format!("[{}]", items.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fs;
+
+    use aztec_lint_aztec::SourceUnit;
+    use aztec_lint_core::config::{AztecConfig, RuleLevel, RuleOverrides, load_from_dir};
+    use tempfile::tempdir;
+
+    use super::{InitPlan, aztec_import_prefix, detect_aztec_config, render_config};
+
+    #[test]
+    fn detects_contract_attribute_external_kinds_and_import_roots() {
+        let sources = vec![SourceUnit::new(
+            "src/main.nr",
+            "use dep::aztec::macros::aztec;\n\n#[aztec]\npub contract Token {\n    #[external(\"utility\")]\n    fn balance() {}\n}\n",
+        )];
+
+        let config = detect_aztec_config(&sources);
+
+        assert_eq!(config.contract_attribute, "aztec");
+        assert_eq!(config.external_kinds, ["public", "private", "utility"]);
+        assert_eq!(config.imports_prefixes, ["aztec", "::aztec", "dep::aztec"]);
+        assert_eq!(aztec_import_prefix("use std::hash;"), None);
+        assert_eq!(
+            aztec_import_prefix("pub use ::aztec::note;"),
+            Some("::aztec".to_string())
+        );
+    }
+
+    #[test]
+    fn generated_config_loads_and_allows_current_findings() {
+        let rendered = render_config(&InitPlan {
+            profile: "aztec",
+            aztec: Some(AztecConfig::default()),
+            members: vec!["contracts/token".to_string()],
+            findings: BTreeMap::from([("NOIR100".to_string(), 3)]),
+        });
+        assert!(rendered.contains("#   - contracts/token"));
+        assert!(rendered.contains("    \"NOIR100\", # 3 finding(s)"));
+
+        let tmp = tempdir().expect("temp dir should be created");
+        fs::write(tmp.path().join("aztec-lint.toml"), rendered).expect("config should be written");
+        let loaded = load_from_dir(tmp.path()).expect("generated config should load");
+        assert_eq!(loaded.config.aztec, AztecConfig::default());
+        let levels = loaded
+            .config
+            .effective_rule_levels("aztec", &RuleOverrides::default())
+            .expect("levels should resolve");
+        assert_eq!(levels.get("NOIR100"), Some(&RuleLevel::Allow));
+        assert!(levels.contains_key("AZTEC001"));
+    }
+}
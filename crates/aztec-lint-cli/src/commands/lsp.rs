@@ -0,0 +1,880 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aztec_lint_core::config::RuleOverrides;
+use aztec_lint_core::diagnostics::{Applicability, Diagnostic, Severity};
+use aztec_lint_core::model::Span;
+use clap::Args;
+use serde_json::{Value, json};
+
+use crate::cli::{CliError, ResolvedTargetSelection};
+use crate::commands::catalog::find_rule;
+use crate::commands::check::{LintRunOptions, collect_lint_run, nearest_project_root};
+use crate::commands::explain::render_explanation;
+use crate::exit_codes;
+
+const SERVER_NAME: &str = "aztec-lint";
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const SERVER_NOT_INITIALIZED: i64 = -32002;
+
+const LSP_SEVERITY_ERROR: u8 = 1;
+const LSP_SEVERITY_WARNING: u8 = 2;
+const LSP_MESSAGE_ERROR: u8 = 1;
+
+#[derive(Clone, Debug, Args)]
+pub struct LspArgs {
+    #[arg(long, default_value = "aztec")]
+    pub profile: String,
+}
+
+pub fn run(args: LspArgs) -> Result<ExitCode, CliError> {
+    let stdin = io::stdin();
+    let mut reader = stdin.lock();
+    let stdout = io::stdout();
+    let mut writer = stdout.lock();
+    let mut server = LspServer::new(args.profile);
+
+    loop {
+        let Some(body) = read_message(&mut reader)
+            .map_err(|source| CliError::Runtime(format!("failed to read LSP message: {source}")))?
+        else {
+            // The client closed stdin without sending `exit`.
+            return Ok(exit_codes::internal_error());
+        };
+
+        let mut outgoing = Vec::<Value>::new();
+        let exit = match serde_json::from_slice::<Value>(&body) {
+            Ok(message) => server.handle(&message, &mut outgoing),
+            Err(source) => {
+                outgoing.push(error_response(
+                    Value::Null,
+                    PARSE_ERROR,
+                    &format!("invalid JSON-RPC message: {source}"),
+                ));
+                None
+            }
+        };
+
+        for message in &outgoing {
+            write_message(&mut writer, message).map_err(|source| {
+                CliError::Runtime(format!("failed to write LSP message: {source}"))
+            })?;
+        }
+        if let Some(code) = exit {
+            return Ok(code);
+        }
+    }
+}
+
+struct LspServer {
+    profile: String,
+    initialized: bool,
+    shutdown_requested: bool,
+    /// Client URIs of opened documents, keyed by canonical path.
+    client_uris: BTreeMap<PathBuf, String>,
+    /// Files that received diagnostics in the last run of each project root.
+    published: BTreeMap<PathBuf, BTreeSet<PathBuf>>,
+    documents: BTreeMap<PathBuf, Vec<PublishedDiagnostic>>,
+}
+
+#[derive(Clone, Debug)]
+struct PublishedDiagnostic {
+    rule_id: String,
+    range: LspRange,
+    lsp: Value,
+    actions: Vec<Value>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct LspPosition {
+    line: u32,
+    character: u32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct LspRange {
+    start: LspPosition,
+    end: LspPosition,
+}
+
+impl LspPosition {
+    fn from_value(value: &Value) -> Option<Self> {
+        Some(Self {
+            line: u32::try_from(value.get("line")?.as_u64()?).ok()?,
+            character: u32::try_from(value.get("character")?.as_u64()?).ok()?,
+        })
+    }
+
+    fn to_value(self) -> Value {
+        json!({ "line": self.line, "character": self.character })
+    }
+}
+
+impl LspRange {
+    fn from_value(value: &Value) -> Option<Self> {
+        Some(Self {
+            start: LspPosition::from_value(value.get("start")?)?,
+            end: LspPosition::from_value(value.get("end")?)?,
+        })
+    }
+
+    fn to_value(self) -> Value {
+        json!({ "start": self.start.to_value(), "end": self.end.to_value() })
+    }
+
+    fn contains(self, position: LspPosition) -> bool {
+        self.start <= position && position <= self.end
+    }
+
+    fn overlaps(self, other: LspRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+impl LspServer {
+    fn new(profile: String) -> Self {
+        Self {
+            profile,
+            initialized: false,
+            shutdown_requested: false,
+            client_uris: BTreeMap::new(),
+            published: BTreeMap::new(),
+            documents: BTreeMap::new(),
+        }
+    }
+
+    fn handle(&mut self, message: &Value, outgoing: &mut Vec<Value>) -> Option<ExitCode> {
+        let method = message.get("method").and_then(Value::as_str);
+        let id = message.get("id").cloned();
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        let Some(method) = method else {
+            // Responses to server-initiated requests are not used.
+            return None;
+        };
+
+        if !self.initialized && !matches!(method, "initialize" | "exit") {
+            if let Some(id) = id {
+                outgoing.push(error_response(
+                    id,
+                    SERVER_NOT_INITIALIZED,
+                    "server has not been initialized",
+                ));
+            }
+            return None;
+        }
+
+        match method {
+            "initialize" => {
+                self.initialized = true;
+                if let Some(id) = id {
+                    outgoing.push(response(id, initialize_result()));
+                }
+            }
+            "initialized" => {}
+            "shutdown" => {
+                self.shutdown_requested = true;
+                if let Some(id) = id {
+                    outgoing.push(response(id, Value::Null));
+                }
+            }
+            "exit" => {
+                return Some(if self.shutdown_requested {
+                    exit_codes::success()
+                } else {
+                    exit_codes::internal_error()
+                });
+            }
+            "textDocument/didOpen" | "textDocument/didSave" => {
+                if let Some(uri) = text_document_uri(&params) {
+                    self.analyze(uri, outgoing);
+                }
+            }
+            "textDocument/codeAction" => {
+                if let Some(id) = id {
+                    outgoing.push(response(id, self.code_actions(&params)));
+                }
+            }
+            "textDocument/hover" => {
+                if let Some(id) = id {
+                    outgoing.push(response(id, self.hover(&params)));
+                }
+            }
+            _ => {
+                if let Some(id) = id {
+                    outgoing.push(error_response(
+                        id,
+                        METHOD_NOT_FOUND,
+                        &format!("unsupported method '{method}'"),
+                    ));
+                }
+            }
+        }
+        None
+    }
+
+    fn analyze(&mut self, uri: &str, outgoing: &mut Vec<Value>) {
+        let Some(path) = uri_to_path(uri) else {
+            outgoing.push(log_message(&format!("unsupported document URI '{uri}'")));
+            return;
+        };
+        let path = path.canonicalize().unwrap_or(path);
+        self.client_uris.insert(path.clone(), uri.to_string());
+
+        let Some(project_root) = path.parent().and_then(nearest_project_root) else {
+            outgoing.push(log_message(&format!(
+                "no Noir project found for '{}'",
+                path.display()
+            )));
+            return;
+        };
+
+        let lint_run = match collect_lint_run(
+            project_root.as_path(),
+            &self.profile,
+            false,
+            ResolvedTargetSelection::all_enabled(),
+            RuleOverrides::default(),
+            LintRunOptions::default(),
+        ) {
+            Ok(lint_run) => lint_run,
+            Err(err) => {
+                outgoing.push(log_message(&err.to_string()));
+                return;
+            }
+        };
+
+        let mut sources = BTreeMap::<PathBuf, Option<String>>::new();
+        let mut by_file = BTreeMap::<PathBuf, Vec<PublishedDiagnostic>>::new();
+        for diagnostic in lint_run
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| !diagnostic.suppressed)
+        {
+            let file = lint_run.report_root.join(&diagnostic.primary_span.file);
+            let published = self.publishable_diagnostic(
+                diagnostic,
+                lint_run.report_root.as_path(),
+                &mut sources,
+            );
+            by_file.entry(file).or_default().push(published);
+        }
+
+        let current = by_file.keys().cloned().collect::<BTreeSet<_>>();
+        let previous = self
+            .published
+            .insert(lint_run.report_root.clone(), current.clone())
+            .unwrap_or_default();
+        for stale in previous.difference(&current) {
+            self.documents.remove(stale);
+            outgoing.push(publish_diagnostics(&self.uri_for_path(stale), Vec::new()));
+        }
+        if !by_file.contains_key(&path) && path.starts_with(&lint_run.report_root) {
+            self.documents.remove(&path);
+            outgoing.push(publish_diagnostics(uri, Vec::new()));
+        }
+        for (file, diagnostics) in by_file {
+            let payload = diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.lsp.clone())
+                .collect();
+            outgoing.push(publish_diagnostics(&self.uri_for_path(&file), payload));
+            self.documents.insert(file, diagnostics);
+        }
+    }
+
+    fn publishable_diagnostic(
+        &self,
+        diagnostic: &Diagnostic,
+        report_root: &Path,
+        sources: &mut BTreeMap<PathBuf, Option<String>>,
+    ) -> PublishedDiagnostic {
+        let mut location = |span: &Span| {
+            let file = report_root.join(&span.file);
+            let range = span_range(span, source_text(sources, &file));
+            (self.uri_for_path(&file), range)
+        };
+
+        let (_, range) = location(&diagnostic.primary_span);
+        let mut message = diagnostic.message.clone();
+        let mut related = Vec::<Value>::new();
+        for span in &diagnostic.secondary_spans {
+            let (uri, range) = location(span);
+            related.push(related_information(&uri, range, "related location"));
+        }
+        for (prefix, entries) in [("note", &diagnostic.notes), ("help", &diagnostic.helps)] {
+            for entry in entries {
+                let text = format!("{prefix}: {}", entry.message);
+                if let Some(span) = &entry.span {
+                    let (uri, range) = location(span);
+                    related.push(related_information(&uri, range, &text));
+                } else {
+                    message.push('\n');
+                    message.push_str(&text);
+                }
+            }
+        }
+
+        let mut lsp = json!({
+            "range": range.to_value(),
+            "severity": lsp_severity(diagnostic.severity),
+            "code": diagnostic.rule_id,
+            "source": SERVER_NAME,
+            "message": message,
+        });
+        if !related.is_empty() {
+            lsp["relatedInformation"] = Value::Array(related);
+        }
+
+        let actions = diagnostic
+            .suggestion_groups
+            .iter()
+            .filter(|group| group.applicability == Applicability::MachineApplicable)
+            .map(|group| {
+                let mut changes = BTreeMap::<String, Vec<Value>>::new();
+                for edit in &group.edits {
+                    let (uri, range) = location(&edit.span);
+                    changes.entry(uri).or_default().push(json!({
+                        "range": range.to_value(),
+                        "newText": edit.replacement,
+                    }));
+                }
+                json!({
+                    "title": group.message,
+                    "kind": "quickfix",
+                    "diagnostics": [lsp.clone()],
+                    "edit": { "changes": changes },
+                })
+            })
+            .collect();
+
+        PublishedDiagnostic {
+            rule_id: diagnostic.rule_id.clone(),
+            range,
+            lsp,
+            actions,
+        }
+    }
+
+    fn code_actions(&self, params: &Value) -> Value {
+        let Some(path) = text_document_uri(params).and_then(canonical_path_for_uri) else {
+            return Value::Array(Vec::new());
+        };
+        let Some(requested) = params.get("range").and_then(LspRange::from_value) else {
+            return Value::Array(Vec::new());
+        };
+
+        let actions = self
+            .documents
+            .get(&path)
+            .into_iter()
+            .flatten()
+            .filter(|diagnostic| diagnostic.range.overlaps(requested))
+            .flat_map(|diagnostic| diagnostic.actions.iter().cloned())
+            .collect();
+        Value::Array(actions)
+    }
+
+    fn hover(&self, params: &Value) -> Value {
+        let Some(path) = text_document_uri(params).and_then(canonical_path_for_uri) else {
+            return Value::Null;
+        };
+        let Some(position) = params.get("position").and_then(LspPosition::from_value) else {
+            return Value::Null;
+        };
+
+        if let Some(diagnostic) = self
+            .documents
+            .get(&path)
+            .into_iter()
+            .flatten()
+            .find(|diagnostic| diagnostic.range.contains(position))
+            && let Some(rule) = find_rule(&diagnostic.rule_id)
+        {
+            return hover_result(&render_explanation(rule), Some(diagnostic.range));
+        }
+
+        let Ok(text) = fs::read_to_string(&path) else {
+            return Value::Null;
+        };
+        let Some((word, range)) = word_at_position(&text, position) else {
+            return Value::Null;
+        };
+        match find_rule(&word.to_ascii_uppercase()) {
+            Some(rule) => hover_result(&render_explanation(rule), Some(range)),
+            None => Value::Null,
+        }
+    }
+
+    fn uri_for_path(&self, path: &Path) -> String {
+        self.client_uris
+            .get(path)
+            .cloned()
+            .unwrap_or_else(|| path_to_uri(path))
+    }
+}
+
+fn initialize_result() -> Value {
+    json!({
+        "capabilities": {
+            "textDocumentSync": {
+                "openClose": true,
+                "change": 0,
+                "save": { "includeText": false },
+            },
+            "hoverProvider": true,
+            "codeActionProvider": { "codeActionKinds": ["quickfix"] },
+        },
+        "serverInfo": {
+            "name": SERVER_NAME,
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+    })
+}
+
+fn response(id: Value, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Value>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "diagnostics": diagnostics },
+    })
+}
+
+fn log_message(message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "window/logMessage",
+        "params": { "type": LSP_MESSAGE_ERROR, "message": message },
+    })
+}
+
+fn hover_result(contents: &str, range: Option<LspRange>) -> Value {
+    let mut hover = json!({ "contents": { "kind": "plaintext", "value": contents } });
+    if let Some(range) = range {
+        hover["range"] = range.to_value();
+    }
+    hover
+}
+
+fn related_information(uri: &str, range: LspRange, message: &str) -> Value {
+    json!({
+        "location": { "uri": uri, "range": range.to_value() },
+        "message": message,
+    })
+}
+
+fn lsp_severity(severity: Severity) -> u8 {
+    match severity {
+        Severity::Error => LSP_SEVERITY_ERROR,
+        Severity::Warning => LSP_SEVERITY_WARNING,
+    }
+}
+
+fn text_document_uri(params: &Value) -> Option<&str> {
+    params.get("textDocument")?.get("uri")?.as_str()
+}
+
+fn canonical_path_for_uri(uri: &str) -> Option<PathBuf> {
+    let path = uri_to_path(uri)?;
+    Some(path.canonicalize().unwrap_or(path))
+}
+
+fn source_text<'a>(
+    sources: &'a mut BTreeMap<PathBuf, Option<String>>,
+    path: &Path,
+) -> Option<&'a str> {
+    sources
+        .entry(path.to_path_buf())
+        .or_insert_with(|| fs::read_to_string(path).ok())
+        .as_deref()
+}
+
+fn span_range(span: &Span, text: Option<&str>) -> LspRange {
+    match text {
+        Some(text) => LspRange {
+            start: position_for_offset(text, span.start as usize),
+            end: position_for_offset(text, span.end as usize),
+        },
+        None => {
+            let start = LspPosition {
+                line: span.line.saturating_sub(1),
+                character: span.col.saturating_sub(1),
+            };
+            LspRange { start, end: start }
+        }
+    }
+}
+
+fn position_for_offset(text: &str, offset: usize) -> LspPosition {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let line_start = text[..offset].rfind('\n').map_or(0, |index| index + 1);
+    let line = text[..line_start].matches('\n').count();
+    let character = text[line_start..offset].encode_utf16().count();
+    LspPosition {
+        line: u32::try_from(line).unwrap_or(u32::MAX),
+        character: u32::try_from(character).unwrap_or(u32::MAX),
+    }
+}
+
+fn word_at_position(text: &str, position: LspPosition) -> Option<(String, LspRange)> {
+    let line = text.lines().nth(position.line as usize)?;
+    let mut cursor = None;
+    let mut utf16 = 0u32;
+    for (index, ch) in line.char_indices() {
+        if utf16 >= position.character {
+            cursor = Some(index);
+            break;
+        }
+        utf16 += ch.len_utf16() as u32;
+    }
+    let cursor = cursor.unwrap_or(line.len());
+
+    let is_word = |ch: char| ch.is_ascii_alphanumeric() || ch == '_';
+    let start = line[..cursor]
+        .rfind(|ch: char| !is_word(ch))
+        .map_or(0, |index| index + 1);
+    let end = line[cursor..]
+        .find(|ch: char| !is_word(ch))
+        .map_or(line.len(), |index| cursor + index);
+    if start >= end {
+        return None;
+    }
+
+    let column = |byte: usize| u32::try_from(line[..byte].encode_utf16().count()).unwrap_or(0);
+    Some((
+        line[start..end].to_string(),
+        LspRange {
+            start: LspPosition {
+                line: position.line,
+                character: column(start),
+            },
+            end: LspPosition {
+                line: position.line,
+                character: column(end),
+            },
+        },
+    ))
+}
+
+fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let rest = uri.strip_prefix("file://")?;
+    let rest = rest.strip_prefix("localhost").unwrap_or(rest);
+    if !rest.starts_with('/') {
+        return None;
+    }
+    let decoded = percent_decode(rest)?;
+    // Windows drive paths arrive as `/C:/...`.
+    let bytes = decoded.as_bytes();
+    if bytes.len() >= 3 && bytes[2] == b':' && bytes[1].is_ascii_alphabetic() {
+        return Some(PathBuf::from(&decoded[1..]));
+    }
+    Some(PathBuf::from(decoded))
+}
+
+fn path_to_uri(path: &Path) -> String {
+    let normalized = path.to_string_lossy().replace('\\', "/");
+    let mut uri = String::from("file://");
+    if !normalized.starts_with('/') {
+        uri.push('/');
+    }
+    for byte in normalized.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'/' | b'-' | b'_' | b'.' | b'~' | b':') {
+            uri.push(char::from(byte));
+        } else {
+            uri.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    uri
+}
+
+fn percent_decode(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::<u8>::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = value.get(index + 1..index + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Vec<u8>>> {
+    let mut content_length = None::<usize>;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            if content_length.is_some() {
+                break;
+            }
+            continue;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            content_length = Some(value.trim().parse().map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid Content-Length header '{}'", value.trim()),
+                )
+            })?);
+        }
+    }
+
+    let mut body = vec![0u8; content_length.unwrap_or(0)];
+    reader.read_exact(&mut body)?;
+    Ok(Some(body))
+}
+
+fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = serde_json::to_string(message)?;
+    write!(writer, "Content-Length: {}\r\n\r\n{body}", body.len())?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::path::{Path, PathBuf};
+
+    use aztec_lint_core::diagnostics::{
+        Applicability, Confidence, Diagnostic, Severity, StructuredMessage, SuggestionGroup,
+        TextEdit,
+    };
+    use aztec_lint_core::model::Span;
+    use serde_json::{Value, json};
+    use tempfile::tempdir;
+
+    use super::{
+        LspPosition, LspRange, LspServer, path_to_uri, position_for_offset, read_message,
+        uri_to_path, word_at_position, write_message,
+    };
+
+    #[test]
+    fn frames_messages_with_content_length() {
+        let mut buffer = Vec::<u8>::new();
+        write_message(&mut buffer, &json!({ "jsonrpc": "2.0", "method": "exit" }))
+            .expect("message should be written");
+        write_message(&mut buffer, &json!({ "jsonrpc": "2.0", "id": 1 }))
+            .expect("message should be written");
+
+        let mut reader = Cursor::new(buffer);
+        let first = read_message(&mut reader)
+            .expect("read should succeed")
+            .expect("first message should exist");
+        let second = read_message(&mut reader)
+            .expect("read should succeed")
+            .expect("second message should exist");
+
+        let first: Value = serde_json::from_slice(&first).expect("body should parse");
+        let second: Value = serde_json::from_slice(&second).expect("body should parse");
+        assert_eq!(first["method"], "exit");
+        assert_eq!(second["id"], 1);
+        assert!(
+            read_message(&mut reader)
+                .expect("read should succeed")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn converts_between_paths_and_file_uris() {
+        let path = Path::new("/work/my contract/src/main.nr");
+        let uri = path_to_uri(path);
+        assert_eq!(uri, "file:///work/my%20contract/src/main.nr");
+        assert_eq!(uri_to_path(&uri), Some(path.to_path_buf()));
+        assert_eq!(
+            uri_to_path("file:///C:/work/main.nr"),
+            Some(PathBuf::from("C:/work/main.nr"))
+        );
+        assert_eq!(uri_to_path("untitled:Untitled-1"), None);
+    }
+
+    #[test]
+    fn positions_use_zero_based_lines_and_utf16_columns() {
+        let text = "fn main() {\n    let é = 42;\n}\n";
+        let offset = text.find("42").expect("literal should exist");
+        assert_eq!(
+            position_for_offset(text, offset),
+            LspPosition {
+                line: 1,
+                character: 12
+            }
+        );
+    }
+
+    #[test]
+    fn finds_rule_id_word_under_cursor() {
+        let text = "#[allow(aztec::NOIR100)]\nfn main() {}\n";
+        let (word, range) = word_at_position(
+            text,
+            LspPosition {
+                line: 0,
+                character: 18,
+            },
+        )
+        .expect("word should be found");
+        assert_eq!(word, "NOIR100");
+        assert_eq!(range.start.character, 15);
+        assert_eq!(range.end.character, 22);
+    }
+
+    #[test]
+    fn maps_diagnostic_details_and_machine_applicable_code_actions() {
+        let tmp = tempdir().expect("temp dir should be created");
+        std::fs::create_dir_all(tmp.path().join("src")).expect("src dir should be created");
+        std::fs::write(
+            tmp.path().join("src/main.nr"),
+            "fn main() {\n    let x = 42;\n}\n",
+        )
+        .expect("source should be written");
+
+        let server = LspServer::new("aztec".to_string());
+        let mut sources = Default::default();
+        let published = server.publishable_diagnostic(&diagnostic(), tmp.path(), &mut sources);
+
+        assert_eq!(published.lsp["severity"], 1);
+        assert_eq!(published.lsp["code"], "NOIR100");
+        assert_eq!(
+            published.lsp["message"],
+            "magic number\nhelp: extract a named constant"
+        );
+        assert_eq!(
+            published.lsp["range"]["start"],
+            json!({ "line": 1, "character": 12 })
+        );
+        let related = published.lsp["relatedInformation"]
+            .as_array()
+            .expect("related information should be present");
+        assert_eq!(related.len(), 2);
+        assert_eq!(related[0]["message"], "related location");
+        assert_eq!(related[1]["message"], "note: literal declared here");
+
+        assert_eq!(published.actions.len(), 1);
+        let action = &published.actions[0];
+        assert_eq!(action["title"], "use named constant");
+        assert_eq!(action["kind"], "quickfix");
+        let uri = path_to_uri(&tmp.path().join("src/main.nr"));
+        assert_eq!(action["edit"]["changes"][&uri][0]["newText"], "ANSWER");
+        assert_eq!(
+            published.range,
+            LspRange {
+                start: LspPosition {
+                    line: 1,
+                    character: 12
+                },
+                end: LspPosition {
+                    line: 1,
+                    character: 14
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_requests_before_initialize_and_exits_after_shutdown() {
+        let mut server = LspServer::new("aztec".to_string());
+        let mut outgoing = Vec::new();
+
+        server.handle(
+            &json!({ "jsonrpc": "2.0", "id": 1, "method": "textDocument/hover", "params": {} }),
+            &mut outgoing,
+        );
+        assert_eq!(outgoing[0]["error"]["code"], -32002);
+
+        server.handle(
+            &json!({ "jsonrpc": "2.0", "id": 2, "method": "initialize", "params": {} }),
+            &mut outgoing,
+        );
+        assert_eq!(outgoing[1]["result"]["capabilities"]["hoverProvider"], true);
+
+        server.handle(
+            &json!({ "jsonrpc": "2.0", "id": 3, "method": "workspace/symbol", "params": {} }),
+            &mut outgoing,
+        );
+        assert_eq!(outgoing[2]["error"]["code"], -32601);
+
+        server.handle(
+            &json!({ "jsonrpc": "2.0", "id": 4, "method": "shutdown" }),
+            &mut outgoing,
+        );
+        let exit = server.handle(
+            &json!({ "jsonrpc": "2.0", "method": "exit" }),
+            &mut outgoing,
+        );
+        assert!(exit.is_some());
+        assert_eq!(outgoing[3]["result"], Value::Null);
+    }
+
+    fn diagnostic() -> Diagnostic {
+        let primary = Span::new("src/main.nr", 24, 26, 2, 13);
+        Diagnostic {
+            rule_id: "NOIR100".to_string(),
+            severity: Severity::Error,
+            confidence: Confidence::High,
+            policy: "maintainability".to_string(),
+            message: "magic number".to_string(),
+            primary_span: primary.clone(),
+            secondary_spans: vec![Span::new("src/main.nr", 0, 2, 1, 1)],
+            suggestions: Vec::new(),
+            notes: vec![StructuredMessage {
+                message: "literal declared here".to_string(),
+                span: Some(primary.clone()),
+            }],
+            helps: vec![StructuredMessage {
+                message: "extract a named constant".to_string(),
+                span: None,
+            }],
+            structured_suggestions: Vec::new(),
+            suggestion_groups: vec![
+                SuggestionGroup {
+                    id: "sg0001".to_string(),
+                    message: "use named constant".to_string(),
+                    applicability: Applicability::MachineApplicable,
+                    edits: vec![TextEdit {
+                        span: primary.clone(),
+                        replacement: "ANSWER".to_string(),
+                    }],
+                    provenance: None,
+                },
+                SuggestionGroup {
+                    id: "sg0002".to_string(),
+                    message: "guess a name".to_string(),
+                    applicability: Applicability::MaybeIncorrect,
+                    edits: vec![TextEdit {
+                        span: primary,
+                        replacement: "VALUE".to_string(),
+                    }],
+                    provenance: None,
+                },
+            ],
+            fixes: Vec::new(),
+            suppressed: false,
+            suppression_reason: None,
+        }
+    }
+}
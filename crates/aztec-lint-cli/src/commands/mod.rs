@@ -1,7 +1,12 @@
 pub mod aztec_scan;
+pub mod cache_clean;
 pub mod catalog;
 pub mod check;
+pub mod config;
 pub mod explain;
 pub mod fix;
+pub mod init;
+pub mod lsp;
 pub mod rules;
 pub mod update;
+pub mod watch;
@@ -1,18 +1,169 @@
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::path::PathBuf;
 use std::process::ExitCode;
 
-use clap::Args;
+use aztec_lint_core::config::{Config, RuleLevel, RuleLevelSource, RuleOverrides};
+use aztec_lint_core::lints::{LintCategory, LintLifecycleState, LintMaturityTier};
+use clap::{ArgAction, ArgGroup, Args, ValueEnum};
+use serde_json::{Value, json};
 
-use crate::cli::CliError;
-use crate::commands::catalog::{all_rules, confidence_label};
+use crate::cli::{CliError, ConfigUnknownKeys, RuleOverrideFlags};
+use crate::commands::catalog::{RuleDoc, all_rules, confidence_label, find_rule};
+use crate::commands::check::load_config;
 use crate::exit_codes;
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum RulesFormat {
+    Text,
+    Json,
+    Markdown,
+}
+
 #[derive(Debug, Args)]
-pub struct RulesArgs {}
+#[command(group(ArgGroup::new("config_query").args(["explain_level", "enabled_in"])))]
+#[command(group(
+    ArgGroup::new("level_overrides")
+        .args(["deny", "warn", "allow"])
+        .multiple(true)
+        .requires("config_query")
+))]
+pub struct RulesArgs {
+    /// Trace how RULE_ID gets its level in `--profile` instead of listing rules.
+    #[arg(
+        long,
+        value_name = "RULE_ID",
+        conflicts_with_all = [
+            "format",
+            "pack",
+            "category",
+            "maturity",
+            "policy",
+            "enabled_in",
+            "lifecycle",
+        ]
+    )]
+    pub explain_level: Option<String>,
+    #[arg(long, requires = "explain_level")]
+    pub profile: Option<String>,
+    /// Directory whose `aztec-lint.toml` is used for `--explain-level` and `--enabled-in`.
+    #[arg(
+        long,
+        value_name = "PATH",
+        default_value = ".",
+        requires = "config_query"
+    )]
+    pub path: PathBuf,
+    #[command(flatten)]
+    pub overrides: RuleOverrideFlags,
+    #[arg(
+        long,
+        default_value = "error",
+        value_enum,
+        value_name = "MODE",
+        requires = "config_query"
+    )]
+    pub config_unknown_keys: ConfigUnknownKeys,
+    #[arg(long, default_value = "text", value_enum)]
+    pub format: RulesFormat,
+    #[arg(long, value_name = "PACK", action = ArgAction::Append)]
+    pub pack: Vec<String>,
+    #[arg(
+        long,
+        value_name = "CATEGORY",
+        value_parser = parse_category,
+        action = ArgAction::Append
+    )]
+    pub category: Vec<LintCategory>,
+    #[arg(
+        long,
+        value_name = "TIER",
+        value_parser = parse_maturity,
+        action = ArgAction::Append
+    )]
+    pub maturity: Vec<LintMaturityTier>,
+    #[arg(long, value_name = "POLICY", action = ArgAction::Append)]
+    pub policy: Vec<String>,
+    /// Only list rules whose effective level in PROFILE is not `allow`.
+    #[arg(long, value_name = "PROFILE")]
+    pub enabled_in: Option<String>,
+    #[arg(
+        long,
+        value_name = "STATE",
+        value_parser = parse_lifecycle,
+        action = ArgAction::Append
+    )]
+    pub lifecycle: Vec<String>,
+}
+
+pub fn run(args: RulesArgs) -> Result<ExitCode, CliError> {
+    if let Some(rule_id) = &args.explain_level {
+        let loaded = load_config(&args.path, args.config_unknown_keys.policy())?;
+        let profile = args.profile.as_deref().unwrap_or("aztec");
+        print!(
+            "{}",
+            render_level_explanation(
+                &loaded.config,
+                profile,
+                rule_id,
+                &args.overrides.rule_overrides()
+            )?
+        );
+        return Ok(exit_codes::success());
+    }
+
+    let enabled = match &args.enabled_in {
+        Some(profile) => {
+            let loaded = load_config(&args.path, args.config_unknown_keys.policy())?;
+            let levels = loaded
+                .config
+                .effective_rule_levels(profile, &args.overrides.rule_overrides())?;
+            Some(
+                levels
+                    .into_iter()
+                    .filter(|(_, level)| *level != RuleLevel::Allow)
+                    .map(|(rule_id, _)| rule_id)
+                    .collect::<BTreeSet<_>>(),
+            )
+        }
+        None => None,
+    };
+    let rules = all_rules()
+        .iter()
+        .filter(|rule| matches_filters(rule, &args, enabled.as_ref()))
+        .collect::<Vec<_>>();
 
-pub fn run(_args: RulesArgs) -> Result<ExitCode, CliError> {
-    println!("RULE_ID\tPACK\tCATEGORY\tMATURITY\tPOLICY\tCONFIDENCE\tSUMMARY");
-    for rule in all_rules() {
-        println!(
+    let output = match args.format {
+        RulesFormat::Text => render_text(&rules),
+        RulesFormat::Json => render_json(&rules),
+        RulesFormat::Markdown => render_markdown(&rules),
+    };
+    print!("{output}");
+    Ok(exit_codes::success())
+}
+
+fn matches_filters(rule: &RuleDoc, args: &RulesArgs, enabled: Option<&BTreeSet<String>>) -> bool {
+    (args.pack.is_empty() || args.pack.iter().any(|pack| pack == rule.pack))
+        && (args.category.is_empty() || args.category.contains(&rule.category))
+        && (args.maturity.is_empty() || args.maturity.contains(&rule.maturity))
+        && (args.policy.is_empty() || args.policy.iter().any(|policy| policy == rule.policy))
+        && (args.lifecycle.is_empty()
+            || args
+                .lifecycle
+                .iter()
+                .any(|state| state == rule.lifecycle.as_str()))
+        && enabled.is_none_or(|enabled| enabled.contains(rule.id))
+}
+
+fn render_text(rules: &[&RuleDoc]) -> String {
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "RULE_ID\tPACK\tCATEGORY\tMATURITY\tPOLICY\tCONFIDENCE\tSUMMARY"
+    );
+    for rule in rules {
+        let _ = writeln!(
+            output,
             "{}\t{}\t{}\t{}\t{}\t{}\t{}",
             rule.id,
             rule.pack,
@@ -23,5 +174,213 @@ pub fn run(_args: RulesArgs) -> Result<ExitCode, CliError> {
             rule.docs.summary
         );
     }
-    Ok(exit_codes::success())
+    output
+}
+
+fn render_json(rules: &[&RuleDoc]) -> String {
+    let entries = rules.iter().map(|rule| rule_json(rule)).collect::<Vec<_>>();
+    let mut output =
+        serde_json::to_string_pretty(&Value::Array(entries)).unwrap_or_else(|_| "[]".to_string());
+    output.push('\n');
+    output
+}
+
+fn rule_json(rule: &RuleDoc) -> Value {
+    let (replacement, note) = match rule.lifecycle {
+        LintLifecycleState::Active => (None, None),
+        LintLifecycleState::Deprecated {
+            replacement, note, ..
+        } => (replacement, Some(note)),
+        LintLifecycleState::Renamed { to, .. } => (Some(to), None),
+        LintLifecycleState::Removed { note, .. } => (None, Some(note)),
+    };
+    json!({
+        "id": rule.id,
+        "pack": rule.pack,
+        "category": rule.category.as_str(),
+        "maturity": rule.maturity.as_str(),
+        "policy": rule.policy,
+        "confidence": confidence_label(rule.confidence),
+        "default_level": rule.default_level.to_string(),
+        "introduced_in": rule.introduced_in,
+        "lifecycle": {
+            "state": rule.lifecycle.as_str(),
+            "since": rule.lifecycle.since(),
+            "replacement": replacement,
+            "note": note,
+        },
+        "provides_fixes": rule.fix_applicability.is_some(),
+        "fix_applicability": rule.fix_applicability.map(|applicability| applicability.as_str()),
+        "summary": rule.docs.summary,
+    })
+}
+
+fn render_markdown(rules: &[&RuleDoc]) -> String {
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "| Rule | Pack | Category | Maturity | Policy | Default Level | Confidence | Introduced In | Lifecycle | Fixes | Summary |"
+    );
+    let _ = writeln!(output, "|---|---|---|---|---|---|---|---|---|---|---|");
+    for rule in rules {
+        let fixes = rule
+            .fix_applicability
+            .map_or("no", |applicability| applicability.as_str());
+        let _ = writeln!(
+            output,
+            "| `{}` | `{}` | {} | {} | {} | {} | {} | {} | {} | {} | {} |",
+            rule.id,
+            rule.pack,
+            rule.category.as_str(),
+            rule.maturity.as_str(),
+            rule.policy,
+            rule.default_level,
+            confidence_label(rule.confidence),
+            rule.introduced_in,
+            rule.lifecycle.as_str(),
+            fixes,
+            rule.docs.summary.replace('|', "\\|")
+        );
+    }
+    output
+}
+
+fn parse_category(value: &str) -> Result<LintCategory, String> {
+    LintCategory::parse(value).ok_or_else(|| format!("unknown lint category '{value}'"))
+}
+
+fn parse_maturity(value: &str) -> Result<LintMaturityTier, String> {
+    LintMaturityTier::parse(value).ok_or_else(|| format!("unknown maturity tier '{value}'"))
+}
+
+fn parse_lifecycle(value: &str) -> Result<String, String> {
+    let normalized = value.trim().to_ascii_lowercase();
+    match normalized.as_str() {
+        "active" | "deprecated" | "renamed" | "removed" => Ok(normalized),
+        _ => Err(format!(
+            "unknown lifecycle state '{value}' (expected active, deprecated, renamed, or removed)"
+        )),
+    }
+}
+
+/// Lists every decision that set `rule_id`'s level in `profile`, oldest
+/// first, ending with the effective level.
+pub(crate) fn render_level_explanation(
+    config: &Config,
+    profile: &str,
+    rule_id: &str,
+    overrides: &RuleOverrides,
+) -> Result<String, CliError> {
+    let rule_id = rule_id.trim().to_ascii_uppercase();
+    let rule = find_rule(&rule_id).ok_or(CliError::UnknownRule {
+        rule_id: rule_id.clone(),
+    })?;
+    let profile_order = config.resolve_profile_order(profile)?;
+    let mut trace = config.rule_level_trace(profile, overrides)?;
+    let steps = trace.remove(rule.id).unwrap_or_default();
+
+    let mut output = String::new();
+    let _ = writeln!(output, "Rule: {}", rule.id);
+    let _ = writeln!(output, "Profile: {profile}");
+    let _ = writeln!(output, "Profile Order: {}", profile_order.join(" -> "));
+    let _ = writeln!(output, "Default Level: {}", rule.default_level);
+    match steps.last() {
+        Some(step) => {
+            let _ = writeln!(output, "Effective Level: {}", step.level);
+        }
+        None => {
+            let _ = writeln!(output, "Effective Level: not enabled");
+        }
+    }
+    let _ = writeln!(output);
+    let _ = writeln!(output, "Decision Chain:");
+    if steps.is_empty() {
+        if rule.lifecycle.is_active() {
+            let _ = writeln!(
+                output,
+                "- no ruleset selector in profile '{profile}' matches pack '{}' or maturity '{}'",
+                rule.pack,
+                rule.maturity.as_str()
+            );
+        } else {
+            let _ = writeln!(
+                output,
+                "- rule is not active and cannot be enabled (run `aztec-lint explain {}`)",
+                rule.id
+            );
+        }
+    }
+    for (index, step) in steps.iter().enumerate() {
+        let action = match step.source {
+            RuleLevelSource::Ruleset { .. } => "enabled at",
+            RuleLevelSource::Profile { .. } | RuleLevelSource::Cli => "set to",
+        };
+        let _ = writeln!(
+            output,
+            "{}. {action} {} by {}",
+            index + 1,
+            step.level,
+            step.source
+        );
+    }
+    if steps
+        .iter()
+        .any(|step| matches!(step.source, RuleLevelSource::Cli))
+    {
+        let _ = writeln!(output);
+        let _ = writeln!(
+            output,
+            "Command-line flags changed this rule's level for this run."
+        );
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use aztec_lint_core::config::{Config, RawConfig, RuleOverrides};
+
+    use super::render_level_explanation;
+
+    #[test]
+    fn level_explanation_lists_ruleset_profile_and_cli_steps() {
+        let raw: RawConfig = toml::from_str(
+            r#"
+[profile.ci]
+extends = ["aztec"]
+warn = ["AZTEC036"]
+"#,
+        )
+        .expect("config must parse");
+        let config = Config::from_raw(raw);
+        let overrides = RuleOverrides {
+            deny: vec!["aztec036".to_string()],
+            warn: Vec::new(),
+            allow: Vec::new(),
+        };
+
+        let output = render_level_explanation(&config, "ci", "aztec036", &overrides)
+            .expect("explanation should render");
+
+        assert!(output.contains("Rule: AZTEC036\n"));
+        assert!(output.contains("Profile Order: default -> aztec -> ci\n"));
+        assert!(output.contains("Effective Level: deny\n"));
+        assert!(output.contains("by profile 'ci' override\n"));
+        assert!(output.contains("3. set to deny by command-line override\n"));
+        assert!(output.contains("Command-line flags changed"));
+    }
+
+    #[test]
+    fn level_explanation_reports_rules_outside_the_profile() {
+        let output = render_level_explanation(
+            &Config::default(),
+            "default",
+            "AZTEC001",
+            &RuleOverrides::default(),
+        )
+        .expect("explanation should render");
+
+        assert!(output.contains("Effective Level: not enabled\n"));
+        assert!(output.contains("no ruleset selector in profile 'default'"));
+    }
 }
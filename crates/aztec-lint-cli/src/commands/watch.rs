@@ -0,0 +1,351 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use aztec_lint_core::config::{CONFIG_FILE_FALLBACK, CONFIG_FILE_PRIMARY};
+use aztec_lint_core::diagnostics::Diagnostic;
+
+use crate::cli::CliError;
+use crate::commands::check::{
+    CheckArgs, LintSession, NoirProject, config_root_for_target, project_input_files,
+    report_lint_run,
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Last observed modification time and length of every watched file.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct WatchSnapshot {
+    files: BTreeMap<PathBuf, (Option<SystemTime>, u64)>,
+    /// Input files of each project, in the order the projects were given.
+    project_files: Vec<BTreeSet<PathBuf>>,
+    config_files: BTreeSet<PathBuf>,
+}
+
+pub(crate) fn run(args: &CheckArgs) -> Result<ExitCode, CliError> {
+    let mut session = prepare_session(args)?;
+    let mut config_files = watched_config_files(args.path.as_path(), &session.config_files);
+
+    let projects = session.projects.iter().collect::<Vec<_>>();
+    let mut project_diagnostics = BTreeMap::<PathBuf, Vec<Diagnostic>>::new();
+    for (project, project_lint) in projects.iter().zip(session.lint_projects(&projects)) {
+        project_diagnostics.insert(project.root.clone(), project_lint?.diagnostics);
+    }
+    report(args, &session, &project_diagnostics)?;
+
+    let mut snapshot = WatchSnapshot::capture(&session.projects, &config_files);
+    eprintln!(
+        "watching {} file(s) in {} project(s); press Ctrl-C to stop",
+        snapshot.files.len(),
+        session.projects.len()
+    );
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let next = WatchSnapshot::capture(&session.projects, &config_files);
+        let reload = if snapshot.config_changed(&next) {
+            Some("configuration changed")
+        } else if session
+            .discover_projects()
+            .is_ok_and(|projects| projects != session.projects)
+        {
+            Some("projects added or removed")
+        } else {
+            None
+        };
+        let affected = if let Some(reason) = reload {
+            // The config decides rules, filters, and even which projects are
+            // linted, so the whole session is rebuilt.
+            eprintln!("{reason}; reloading and re-running");
+            match prepare_session(args) {
+                Ok(prepared) => session = prepared,
+                Err(err) => {
+                    eprintln!("{err}");
+                    snapshot = next;
+                    continue;
+                }
+            }
+            project_diagnostics.clear();
+            config_files = watched_config_files(args.path.as_path(), &session.config_files);
+            snapshot = WatchSnapshot::capture(&session.projects, &config_files);
+            session.projects.iter().collect::<Vec<_>>()
+        } else {
+            let affected = snapshot.affected_projects(&next, &session.projects);
+            snapshot = next;
+            for project in &affected {
+                eprintln!(
+                    "change detected in '{}'; re-running",
+                    project.root.display()
+                );
+            }
+            affected
+        };
+        if affected.is_empty() {
+            continue;
+        }
+
+        for (project, project_lint) in affected.iter().zip(session.lint_projects(&affected)) {
+            match project_lint {
+                Ok(project_lint) => {
+                    project_diagnostics.insert(project.root.clone(), project_lint.diagnostics);
+                }
+                Err(err) => eprintln!("{err}"),
+            }
+        }
+        if let Err(err) = report(args, &session, &project_diagnostics) {
+            eprintln!("{err}");
+        }
+    }
+}
+
+fn prepare_session(args: &CheckArgs) -> Result<LintSession, CliError> {
+    LintSession::prepare(
+        args.path.as_path(),
+        &args.profile,
+        args.targets.resolve(),
+        args.lint.rule_overrides(),
+        args.lint.run_options(),
+    )
+}
+
+/// Config files that would be picked up for `target`, whether or not they
+/// exist yet, so creating or deleting one is noticed too, followed by the
+/// `loaded` files: the config actually read and the files it `extends`.
+fn watched_config_files(target: &Path, loaded: &[PathBuf]) -> Vec<PathBuf> {
+    let root = config_root_for_target(target);
+    let mut files = [CONFIG_FILE_PRIMARY, CONFIG_FILE_FALLBACK]
+        .into_iter()
+        .map(|file_name| root.join(file_name))
+        .collect::<Vec<_>>();
+    for file in loaded {
+        if !files.contains(file) {
+            files.push(file.clone());
+        }
+    }
+    files
+}
+
+fn report(
+    args: &CheckArgs,
+    session: &LintSession,
+    project_diagnostics: &BTreeMap<PathBuf, Vec<Diagnostic>>,
+) -> Result<(), CliError> {
+    let diagnostics = project_diagnostics.values().flatten().cloned().collect();
+    let lint_run = session.finish(diagnostics, args.changed_only)?;
+    report_lint_run(args, lint_run)?;
+    Ok(())
+}
+
+impl WatchSnapshot {
+    /// Records the manifest and sources of every project, including those of
+    /// its local path dependencies, along with `config_files`.
+    fn capture(projects: &[NoirProject], config_files: &[PathBuf]) -> Self {
+        let project_files = projects
+            .iter()
+            .map(|project| {
+                project_input_files(&project.root)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect::<BTreeSet<_>>()
+            })
+            .collect::<Vec<_>>();
+        let config_files = config_files.iter().cloned().collect::<BTreeSet<_>>();
+
+        let files = project_files
+            .iter()
+            .flatten()
+            .chain(&config_files)
+            .filter_map(|path| {
+                let metadata = fs::metadata(path).ok()?;
+                Some((path.clone(), (metadata.modified().ok(), metadata.len())))
+            })
+            .collect();
+        Self {
+            files,
+            project_files,
+            config_files,
+        }
+    }
+
+    /// Files that were added, removed, or modified between `self` and `next`.
+    fn changed_files<'a>(&'a self, next: &'a WatchSnapshot) -> BTreeSet<&'a PathBuf> {
+        self.files
+            .keys()
+            .chain(next.files.keys())
+            .filter(|path| self.files.get(*path) != next.files.get(*path))
+            .collect()
+    }
+
+    fn config_changed(&self, next: &WatchSnapshot) -> bool {
+        self.changed_files(next)
+            .into_iter()
+            .any(|path| self.config_files.contains(path))
+    }
+
+    /// Projects with any input file that changed between `self` and `next`. A
+    /// path dependency shared by several projects re-runs all of them.
+    fn affected_projects<'a>(
+        &self,
+        next: &WatchSnapshot,
+        projects: &'a [NoirProject],
+    ) -> Vec<&'a NoirProject> {
+        let changed = self.changed_files(next);
+        projects
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                [&self.project_files, &next.project_files]
+                    .into_iter()
+                    .filter_map(|project_files| project_files.get(*index))
+                    .any(|files| changed.iter().any(|path| files.contains(*path)))
+            })
+            .map(|(_, project)| project)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use aztec_lint_core::config::load_from_dir;
+    use tempfile::tempdir;
+
+    use super::{WatchSnapshot, watched_config_files};
+    use crate::commands::check::NoirProject;
+
+    #[test]
+    fn detects_modified_added_and_removed_sources_per_project() {
+        let tmp = tempdir().expect("temp dir should be created");
+        let a = project(tmp.path(), "a");
+        let b = project(tmp.path(), "b");
+        let projects = vec![a.clone(), b.clone()];
+
+        let initial = WatchSnapshot::capture(&projects, &[]);
+        assert_eq!(initial.files.len(), 4);
+        assert!(
+            initial
+                .affected_projects(&WatchSnapshot::capture(&projects, &[]), &projects)
+                .is_empty()
+        );
+
+        fs::write(a.root.join("src/main.nr"), "fn main() { let x = 10; }\n")
+            .expect("source should be rewritten");
+        let modified = WatchSnapshot::capture(&projects, &[]);
+        let affected = initial.affected_projects(&modified, &projects);
+        assert_eq!(affected.len(), 1);
+        assert_eq!(affected[0].root, a.root);
+
+        fs::write(b.root.join("src/extra.nr"), "fn extra() {}\n").expect("source should be added");
+        let added = WatchSnapshot::capture(&projects, &[]);
+        let affected = modified.affected_projects(&added, &projects);
+        assert_eq!(affected.len(), 1);
+        assert_eq!(affected[0].root, b.root);
+
+        fs::remove_file(b.root.join("src/extra.nr")).expect("source should be removed");
+        let removed = WatchSnapshot::capture(&projects, &[]);
+        assert_eq!(added.affected_projects(&removed, &projects).len(), 1);
+    }
+
+    #[test]
+    fn re_runs_projects_when_a_path_dependency_changes() {
+        let tmp = tempdir().expect("temp dir should be created");
+        let shared = project(tmp.path(), "shared");
+        let app = project(tmp.path(), "app");
+        fs::write(
+            app.root.join("Nargo.toml"),
+            "[package]\nname=\"app\"\ntype=\"bin\"\nauthors=[\"\"]\n\n[dependencies]\nshared = { path = \"../shared\" }\n",
+        )
+        .expect("manifest should be written");
+        let projects = vec![app.clone()];
+
+        let initial = WatchSnapshot::capture(&projects, &[]);
+        assert_eq!(initial.files.len(), 4);
+
+        fs::write(shared.root.join("src/main.nr"), "fn helper() {}\n")
+            .expect("dependency source should be rewritten");
+        let modified = WatchSnapshot::capture(&projects, &[]);
+        let affected = initial.affected_projects(&modified, &projects);
+        assert_eq!(affected.len(), 1);
+        assert_eq!(affected[0].root, app.root);
+    }
+
+    #[test]
+    fn reports_config_file_changes_separately_from_sources() {
+        let tmp = tempdir().expect("temp dir should be created");
+        let a = project(tmp.path(), "a");
+        let projects = vec![a.clone()];
+        let config_files = vec![a.root.join("aztec-lint.toml")];
+
+        let initial = WatchSnapshot::capture(&projects, &config_files);
+        assert_eq!(initial.files.len(), 2);
+
+        fs::write(
+            &config_files[0],
+            "[profile.default]\nruleset = [\"noir_core\"]\n",
+        )
+        .expect("config should be written");
+        let created = WatchSnapshot::capture(&projects, &config_files);
+        assert!(initial.config_changed(&created));
+        assert!(initial.affected_projects(&created, &projects).is_empty());
+
+        fs::write(a.root.join("src/main.nr"), "fn main() { let x = 10; }\n")
+            .expect("source should be rewritten");
+        let modified = WatchSnapshot::capture(&projects, &config_files);
+        assert!(!created.config_changed(&modified));
+        assert_eq!(created.affected_projects(&modified, &projects).len(), 1);
+    }
+
+    #[test]
+    fn watches_files_the_loaded_config_extends() {
+        let tmp = tempdir().expect("temp dir should be created");
+        let a = project(tmp.path(), "a");
+        let shared = tmp.path().join("shared/aztec-lint.toml");
+        fs::create_dir_all(tmp.path().join("shared")).expect("shared dir should be created");
+        fs::write(&shared, "[profile.default]\nruleset = [\"noir_core\"]\n")
+            .expect("shared config should be written");
+        fs::write(
+            a.root.join("aztec-lint.toml"),
+            "extends = [\"../shared/aztec-lint.toml\"]\n",
+        )
+        .expect("config should be written");
+        let loaded = load_from_dir(&a.root).expect("config should load");
+        let config_files = watched_config_files(&a.root, &loaded.files);
+        assert_eq!(config_files.len(), 3);
+        let projects = vec![a.clone()];
+
+        let initial = WatchSnapshot::capture(&projects, &config_files);
+        fs::write(&shared, "[profile.default]\nruleset = [\"aztec_pack\"]\n")
+            .expect("shared config should be rewritten");
+        let modified = WatchSnapshot::capture(&projects, &config_files);
+        assert!(initial.config_changed(&modified));
+        assert!(initial.affected_projects(&modified, &projects).is_empty());
+    }
+
+    fn project(root: &Path, name: &str) -> NoirProject {
+        let project_root = root.join(name);
+        fs::create_dir_all(project_root.join("src")).expect("src dir should be created");
+        fs::write(
+            project_root.join("Nargo.toml"),
+            format!("[package]\nname=\"{name}\"\ntype=\"bin\"\nauthors=[\"\"]\n"),
+        )
+        .expect("manifest should be written");
+        fs::write(project_root.join("src/main.nr"), "fn main() {}\n")
+            .expect("source should be written");
+        noir_project(
+            &project_root.to_string_lossy(),
+            &project_root.join("src/main.nr").to_string_lossy(),
+        )
+    }
+
+    fn noir_project(root: &str, entry: &str) -> NoirProject {
+        NoirProject {
+            root: PathBuf::from(root),
+            entry: PathBuf::from(entry),
+        }
+    }
+}
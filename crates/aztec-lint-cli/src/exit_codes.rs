@@ -9,10 +9,14 @@ pub fn success() -> ExitCode {
 }
 
 pub fn diagnostics_found(blocking_diagnostics: bool) -> ExitCode {
+    ExitCode::from(diagnostics_found_status(blocking_diagnostics))
+}
+
+pub fn diagnostics_found_status(blocking_diagnostics: bool) -> u8 {
     if blocking_diagnostics {
-        ExitCode::from(DIAGNOSTICS_FOUND)
+        DIAGNOSTICS_FOUND
     } else {
-        success()
+        SUCCESS
     }
 }
 
@@ -127,6 +127,55 @@ NOIR120\tnoir_core\tmaintainability\tpreview\tmaintainability\tlow\tFunction nes
     cmd.assert().success().stdout(expected);
 }
 
+#[test]
+fn rules_filters_and_json_output_include_catalog_metadata() {
+    let mut cmd = cli_bin();
+    cmd.args([
+        "rules",
+        "--category",
+        "privacy",
+        "--maturity",
+        "stable",
+        "--format",
+        "json",
+    ]);
+    let output = cmd.output().expect("rules should execute");
+    assert_eq!(output.status.code(), Some(0));
+    let value: Value = serde_json::from_slice(&output.stdout).expect("rules json should parse");
+    let ids = value
+        .as_array()
+        .expect("rules json should be an array")
+        .iter()
+        .filter_map(|rule| rule["id"].as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["AZTEC001", "AZTEC003"]);
+    assert_eq!(value[0]["default_level"], "deny");
+    assert_eq!(value[0]["introduced_in"], "0.1.0");
+    assert_eq!(value[0]["lifecycle"]["state"], "active");
+    assert_eq!(value[0]["provides_fixes"], false);
+
+    let mut markdown = cli_bin();
+    markdown.args([
+        "rules",
+        "--pack",
+        "noir_core",
+        "--enabled-in",
+        "default",
+        "--allow",
+        "NOIR100",
+        "--format",
+        "markdown",
+    ]);
+    let output = markdown.output().expect("rules should execute");
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("| Rule | Pack |"));
+    assert!(stdout.contains("| `NOIR001` | `noir_core` |"));
+    assert!(stdout.contains("| machine-applicable |"));
+    assert!(!stdout.contains("`NOIR100`"));
+    assert!(!stdout.contains("AZTEC"));
+}
+
 #[test]
 fn explain_command_matches_golden_output() {
     let expected = "\
@@ -174,6 +223,49 @@ fn invalid_flag_combination_returns_exit_code_two() {
     cmd.assert().code(2);
 }
 
+#[test]
+fn rules_rejects_flags_it_would_ignore() {
+    for args in [
+        &["rules", "--explain-level", "NOIR100", "--format", "json"][..],
+        &["rules", "--deny", "NOIR100"],
+        &["rules", "--path", "."],
+        &["rules", "--config-unknown-keys", "warn"],
+    ] {
+        let mut cmd = cli_bin();
+        cmd.args(args);
+        cmd.assert().code(2);
+    }
+}
+
+#[test]
+fn rules_honors_config_unknown_keys_policy() {
+    let workspace = tempdir().expect("temp dir should be created");
+    fs::write(
+        workspace.path().join("aztec-lint.toml"),
+        "[profile.default]\nrulesets = [\"aztec_pack\"]\n",
+    )
+    .expect("config should be written");
+    let path = workspace.path().to_string_lossy().to_string();
+
+    let mut strict = cli_bin();
+    strict.args(["rules", "--enabled-in", "aztec", "--path", path.as_str()]);
+    strict.assert().code(2);
+
+    let mut lenient = cli_bin();
+    lenient.args([
+        "rules",
+        "--enabled-in",
+        "aztec",
+        "--path",
+        path.as_str(),
+        "--config-unknown-keys",
+        "warn",
+    ]);
+    let output = lenient.output().expect("rules should execute");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("warning: "));
+}
+
 #[test]
 fn unknown_cli_override_fails_fast_with_actionable_error() {
     let mut cmd = cli_bin();
@@ -520,6 +612,310 @@ fn check_changed_only_includes_staged_changes() {
     cmd.assert().code(1);
 }
 
+#[test]
+fn check_baseline_filters_known_diagnostics_and_reports_fixed_entries() {
+    let (_workspace, project) =
+        create_git_project("fn main() {\n    let x = 42;\n    assert(x == 42);\n}\n");
+
+    let mut write = cli_bin();
+    write.current_dir(&project);
+    write.args(["check", ".", "--write-baseline", "lint-baseline.json"]);
+    let write_output = write.output().expect("command should execute");
+    assert_eq!(write_output.status.code(), Some(0));
+    assert!(project.join("lint-baseline.json").is_file());
+
+    fs::write(
+        project.join("src/main.nr"),
+        "// shifted\n\nfn main() {\n    let x = 42;\n    assert(x == 42);\n}\n",
+    )
+    .expect("shifted source should be written");
+    let mut shifted = cli_bin();
+    shifted.current_dir(&project);
+    shifted.args(["check", ".", "--baseline", "lint-baseline.json"]);
+    shifted.assert().code(0);
+
+    fs::write(
+        project.join("src/main.nr"),
+        "fn main() {\n    let x = 42;\n    assert(x == 42);\n    let y = 7;\n    assert(y == 7);\n}\n",
+    )
+    .expect("changed source should be written");
+    let mut introduced = cli_bin();
+    introduced.current_dir(&project);
+    introduced.args(["check", ".", "--baseline", "lint-baseline.json"]);
+    introduced.assert().code(1);
+
+    fs::write(
+        project.join("src/main.nr"),
+        "fn main() {\n    let x = 1;\n    assert(x == 1);\n}\n",
+    )
+    .expect("fixed source should be written");
+    let mut fixed = cli_bin();
+    fixed.current_dir(&project);
+    fixed.args(["check", ".", "--baseline", "lint-baseline.json"]);
+    let fixed_output = fixed.output().expect("command should execute");
+    assert_eq!(fixed_output.status.code(), Some(0));
+    let stderr = String::from_utf8_lossy(&fixed_output.stderr);
+    assert!(
+        stderr.contains("fixed since baseline: NOIR100 src/main.nr:"),
+        "stderr was: {stderr}"
+    );
+}
+
+#[test]
+fn check_baseline_with_changed_only_ignores_unanalyzed_files() {
+    let (_workspace, project) =
+        create_git_project("fn main() {\n    let x = 42;\n    assert(x == 42);\n}\n");
+
+    let mut write = cli_bin();
+    write.current_dir(&project);
+    write.args(["check", ".", "--write-baseline", "lint-baseline.json"]);
+    write.assert().code(0);
+
+    let mut changed_only = cli_bin();
+    changed_only.current_dir(&project);
+    changed_only.args([
+        "check",
+        ".",
+        "--changed-only",
+        "--baseline",
+        "lint-baseline.json",
+    ]);
+    let output = changed_only.output().expect("command should execute");
+    assert_eq!(output.status.code(), Some(0));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("0 fixed since baseline") && !stderr.contains("fixed since baseline: "),
+        "stderr was: {stderr}"
+    );
+
+    let mut partial_write = cli_bin();
+    partial_write.current_dir(&project);
+    partial_write.args([
+        "check",
+        ".",
+        "--changed-only",
+        "--write-baseline",
+        "partial-baseline.json",
+    ]);
+    partial_write.assert().code(2);
+    assert!(!project.join("partial-baseline.json").exists());
+}
+
+#[test]
+fn lsp_publishes_diagnostics_and_hover_docs_over_stdio() {
+    let (_workspace, project) =
+        create_git_project("fn main() {\n    let x = 42;\n    assert(x == 42);\n}\n");
+    let source = project
+        .join("src/main.nr")
+        .canonicalize()
+        .expect("source path should canonicalize");
+    let uri = format!("file://{}", source.to_string_lossy());
+
+    let messages = [
+        serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}),
+        serde_json::json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}),
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": {"textDocument": {"uri": uri, "languageId": "noir", "version": 1, "text": ""}},
+        }),
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "textDocument/hover",
+            "params": {"textDocument": {"uri": uri}, "position": {"line": 2, "character": 17}},
+        }),
+        serde_json::json!({"jsonrpc": "2.0", "id": 3, "method": "shutdown"}),
+        serde_json::json!({"jsonrpc": "2.0", "method": "exit"}),
+    ];
+    let mut input = Vec::<u8>::new();
+    for message in &messages {
+        let body = message.to_string();
+        input.extend_from_slice(format!("Content-Length: {}\r\n\r\n{body}", body.len()).as_bytes());
+    }
+
+    let mut child = cli_bin()
+        .arg("lsp")
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .expect("lsp server should start");
+    {
+        use std::io::Write;
+        let mut stdin = child.stdin.take().expect("stdin should be piped");
+        stdin
+            .write_all(&input)
+            .expect("lsp input should be written");
+    }
+    let output = child.wait_with_output().expect("lsp server should exit");
+    assert_eq!(output.status.code(), Some(0));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let responses = stdout
+        .split("Content-Length: ")
+        .filter_map(|frame| frame.split_once("\r\n\r\n"))
+        .map(|(_, body)| serde_json::from_str::<Value>(body).expect("lsp body should parse"))
+        .collect::<Vec<_>>();
+
+    let published = responses
+        .iter()
+        .find(|message| message["method"] == "textDocument/publishDiagnostics")
+        .expect("diagnostics should be published");
+    assert_eq!(published["params"]["uri"], Value::String(uri.clone()));
+    let diagnostics = published["params"]["diagnostics"]
+        .as_array()
+        .expect("published diagnostics should be an array");
+    assert!(
+        diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic["code"] == "NOIR100"),
+        "stdout was: {stdout}"
+    );
+
+    let hover = responses
+        .iter()
+        .find(|message| message["id"] == 2)
+        .expect("hover response should be present");
+    let contents = hover["result"]["contents"]["value"]
+        .as_str()
+        .expect("hover should include lint docs");
+    assert!(
+        contents.starts_with("Rule: NOIR100\n"),
+        "hover was: {contents}"
+    );
+}
+
+#[test]
+fn check_watch_rejects_write_baseline() {
+    let mut cmd = cli_bin();
+    let fixture = fixture_dir("noir_core/minimal");
+    cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--watch",
+        "--write-baseline",
+        "baseline.json",
+    ]);
+    cmd.assert().code(2);
+}
+
+#[test]
+fn check_reuses_cached_results_until_cache_is_cleaned() {
+    let (_workspace, project) =
+        create_git_project("fn main() {\n    let x = 42;\n    assert(x == 42);\n}\n");
+    let cache_root = project.join("target/aztec-lint");
+
+    let mut uncached = cli_bin();
+    uncached.current_dir(&project).env_remove("XDG_CACHE_HOME");
+    uncached.args(["check", ".", "--no-cache", "--format", "json"]);
+    let uncached_output = uncached.output().expect("command should execute");
+    assert_eq!(uncached_output.status.code(), Some(1));
+    assert!(!cache_root.exists());
+
+    let mut cold = cli_bin();
+    cold.current_dir(&project).env_remove("XDG_CACHE_HOME");
+    cold.args(["check", ".", "--format", "json"]);
+    let cold_output = cold.output().expect("command should execute");
+    assert!(cache_root.join("diagnostics").is_dir());
+
+    let mut warm = cli_bin();
+    warm.current_dir(&project).env_remove("XDG_CACHE_HOME");
+    warm.args(["check", ".", "--format", "json"]);
+    let warm_output = warm.output().expect("command should execute");
+    assert_eq!(warm_output.status.code(), Some(1));
+    assert_eq!(warm_output.stdout, cold_output.stdout);
+    assert_eq!(warm_output.stdout, uncached_output.stdout);
+
+    fs::write(
+        project.join("src/main.nr"),
+        "fn main() {\n    let x = 1;\n    assert(x == 1);\n}\n",
+    )
+    .expect("fixed source should be written");
+    let mut edited = cli_bin();
+    edited.current_dir(&project).env_remove("XDG_CACHE_HOME");
+    edited.args(["check", "."]);
+    edited.assert().code(0);
+
+    let mut clean = cli_bin();
+    clean.current_dir(&project).env_remove("XDG_CACHE_HOME");
+    clean.args(["cache", "clean"]);
+    let clean_output = clean.output().expect("command should execute");
+    assert_eq!(clean_output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&clean_output.stdout).contains("removed cache"));
+    assert!(!cache_root.exists());
+}
+
+#[test]
+fn check_parallel_jobs_produce_identical_output() {
+    let fixture = fixture_dir("noir_core");
+    let run = |jobs: &str| {
+        let mut cmd = cli_bin();
+        cmd.args([
+            "check",
+            fixture.to_string_lossy().as_ref(),
+            "--format",
+            "json",
+            "--no-cache",
+            "--jobs",
+            jobs,
+        ]);
+        cmd.output().expect("command should execute")
+    };
+
+    let sequential = run("1");
+    let parallel = run("8");
+
+    assert_eq!(sequential.status.code(), Some(1));
+    assert_eq!(parallel.status.code(), sequential.status.code());
+    assert_eq!(parallel.stdout, sequential.stdout);
+}
+
+#[test]
+fn check_rejects_zero_jobs() {
+    let mut cmd = cli_bin();
+    cmd.args(["check", ".", "--jobs", "0"]);
+    cmd.assert().code(2);
+}
+
+#[test]
+fn check_timings_adds_json_section_and_text_table() {
+    let (_workspace, project) =
+        create_git_project("fn main() {\n    let x = 42;\n    assert(x == 42);\n}\n");
+
+    let mut json = cli_bin();
+    json.current_dir(&project);
+    json.args(["check", ".", "--format", "json", "--timings"]);
+    let json_output = json.output().expect("command should execute");
+    assert_eq!(json_output.status.code(), Some(1));
+    let value: Value =
+        serde_json::from_slice(&json_output.stdout).expect("json output should parse");
+    assert!(
+        value["diagnostics"]
+            .as_array()
+            .is_some_and(|items| !items.is_empty())
+    );
+    let rules = value["timings"]["rules"]
+        .as_array()
+        .expect("timings should list rules");
+    assert!(
+        rules
+            .iter()
+            .any(|entry| entry["name"].as_str() == Some("NOIR100"))
+    );
+    assert!(rules.iter().all(|entry| entry["ms"].is_f64()));
+
+    let mut text = cli_bin();
+    text.current_dir(&project);
+    text.args(["check", ".", "--timings"]);
+    let text_output = text.output().expect("command should execute");
+    let stdout = String::from_utf8_lossy(&text_output.stdout);
+    assert!(
+        stdout.contains("timings (wall time):"),
+        "stdout was: {stdout}"
+    );
+    assert!(stdout.contains("  rule   NOIR100"), "stdout was: {stdout}");
+}
+
 #[test]
 fn check_json_output_includes_suppressed_diagnostics() {
     let (_workspace, project) =
@@ -638,6 +1034,161 @@ fn main() {
     }));
 }
 
+#[test]
+fn check_json_output_reports_winning_path_override() {
+    let (_workspace, project) = create_git_project("fn main() { let x = 42; assert(x == 42); }\n");
+    let config_path = project.join("aztec-lint.toml");
+    fs::write(
+        &config_path,
+        "[[overrides]]\npaths = [\"src/\"]\ndeny = [\"NOIR100\"]\n\n[[overrides]]\npaths = [\"src/*.nr\"]\nallow = [\"NOIR100\"]\n",
+    )
+    .expect("config should be written");
+
+    let mut cmd = cli_bin();
+    cmd.current_dir(&project);
+    cmd.args(["check", ".", "--format", "json"]);
+    let output = cmd.output().expect("command should execute");
+    assert_eq!(output.status.code(), Some(0));
+
+    let diagnostics: Value =
+        serde_json::from_slice(&output.stdout).expect("json output should parse");
+    let noir100 = diagnostics
+        .as_array()
+        .expect("json diagnostics should be an array")
+        .iter()
+        .filter(|diagnostic| diagnostic["rule_id"] == Value::String("NOIR100".to_string()))
+        .collect::<Vec<_>>();
+    assert!(
+        !noir100.is_empty(),
+        "expected suppressed NOIR100 diagnostics"
+    );
+    assert!(noir100.iter().all(|diagnostic| {
+        diagnostic["suppressed"] == Value::Bool(true)
+            && diagnostic["suppression_reason"]
+                == Value::String(
+                    "allow(NOIR100) by [[overrides]] entry 2 (paths: \"src/*.nr\")".to_string(),
+                )
+    }));
+
+    fs::write(
+        &config_path,
+        "[[overrides]]\npaths = [\"src/\"]\ndeny = [\"NOIR999\"]\n",
+    )
+    .expect("config should be rewritten");
+    let mut invalid = cli_bin();
+    invalid.current_dir(&project);
+    invalid.args(["check", "."]);
+    let output = invalid.output().expect("command should execute");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(
+        String::from_utf8_lossy(&output.stderr)
+            .contains("unknown rule id 'NOIR999' in [[overrides]] entry 1 deny override")
+    );
+}
+
+#[test]
+fn check_cache_keeps_overrides_of_identical_projects_apart() {
+    let workspace = tempdir().expect("temp dir should be created");
+    let root = workspace.path().join("workspace");
+    for project in ["contracts/test_token", "contracts/token"] {
+        let project_root = root.join(project);
+        fs::create_dir_all(project_root.join("src")).expect("src dir should be created");
+        fs::write(
+            project_root.join("Nargo.toml"),
+            "[package]\nname=\"token\"\ntype=\"bin\"\nauthors=[\"\"]\n",
+        )
+        .expect("nargo file should be written");
+        fs::write(
+            project_root.join("src/main.nr"),
+            "fn main() { let x = 42; assert(x == 42); }\n",
+        )
+        .expect("main source should be written");
+    }
+    fs::write(
+        root.join("aztec-lint.toml"),
+        "[[overrides]]\npaths = [\"contracts/test_*\"]\nallow = [\"NOIR100\"]\n",
+    )
+    .expect("config should be written");
+
+    for run in ["cold", "warm"] {
+        let mut cmd = cli_bin();
+        cmd.current_dir(&root).env_remove("XDG_CACHE_HOME");
+        cmd.args(["check", ".", "--format", "json", "--jobs", "1"]);
+        let output = cmd.output().expect("command should execute");
+        assert_eq!(output.status.code(), Some(1), "{run} run");
+
+        let diagnostics: Value =
+            serde_json::from_slice(&output.stdout).expect("json output should parse");
+        let noir100 = diagnostics
+            .as_array()
+            .expect("json diagnostics should be an array")
+            .iter()
+            .filter(|diagnostic| diagnostic["rule_id"] == Value::String("NOIR100".to_string()))
+            .collect::<Vec<_>>();
+        for (project, suppressed) in [("contracts/test_token/", true), ("contracts/token/", false)]
+        {
+            let in_project = noir100
+                .iter()
+                .filter(|diagnostic| {
+                    diagnostic["primary_span"]["file"]
+                        .as_str()
+                        .is_some_and(|file| file.starts_with(project))
+                })
+                .collect::<Vec<_>>();
+            assert!(!in_project.is_empty(), "{run} run: no NOIR100 in {project}");
+            assert!(
+                in_project
+                    .iter()
+                    .all(|diagnostic| diagnostic["suppressed"] == Value::Bool(suppressed)),
+                "{run} run: {project} should have suppressed={suppressed}"
+            );
+        }
+    }
+}
+
+#[test]
+fn check_skips_projects_matching_exclude_globs() {
+    let (_workspace, root) = create_workspace_with_members();
+    fs::write(
+        root.join("b/src/main.nr"),
+        "fn main() { let x = 42; assert(x == 42); }\n",
+    )
+    .expect("member source should be rewritten");
+
+    let noir100_count = |args: &[&str]| {
+        let mut cmd = cli_bin();
+        cmd.arg("check")
+            .arg(&root)
+            .args(["--format", "json"])
+            .args(args);
+        let output = cmd.output().expect("command should execute");
+        let diagnostics: Value =
+            serde_json::from_slice(&output.stdout).expect("json output should parse");
+        diagnostics
+            .as_array()
+            .expect("json diagnostics should be an array")
+            .iter()
+            .filter(|diagnostic| diagnostic["rule_id"] == Value::String("NOIR100".to_string()))
+            .count()
+    };
+
+    assert!(noir100_count(&[]) > 0);
+    assert_eq!(noir100_count(&["--exclude", "b"]), 0);
+
+    fs::write(root.join("aztec-lint.toml"), "exclude = [\"b/src/\"]\n")
+        .expect("config should be written");
+    assert_eq!(noir100_count(&[]), 0);
+
+    let mut all_excluded = cli_bin();
+    all_excluded
+        .arg("check")
+        .arg(&root)
+        .args(["--exclude", "a", "--exclude", "b"]);
+    let output = all_excluded.output().expect("command should execute");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no Noir project found"));
+}
+
 #[test]
 fn check_file_level_allow_is_non_blocking_with_error_threshold() {
     let source = r#"
@@ -739,6 +1290,600 @@ fn check_sarif_output_is_deterministic() {
     );
 }
 
+#[test]
+fn check_junit_output_reports_one_failing_testcase_per_diagnostic() {
+    let fixture = fixture_dir("noir_core/minimal");
+
+    let mut json_cmd = cli_bin();
+    json_cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--format",
+        "json",
+    ]);
+    let json_output = json_cmd.output().expect("json run should execute");
+    let diagnostics: Value =
+        serde_json::from_slice(&json_output.stdout).expect("json output should parse");
+    let unsuppressed = diagnostics
+        .as_array()
+        .expect("json output should be an array")
+        .iter()
+        .filter(|diagnostic| diagnostic["suppressed"] == Value::Bool(false))
+        .count();
+
+    let mut cmd = cli_bin();
+    cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--format",
+        "junit",
+    ]);
+    let output = cmd.output().expect("junit run should execute");
+    assert_eq!(output.status.code(), Some(1), "run should report findings");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+    assert!(stdout.contains("<testsuites name=\"aztec-lint\""));
+    assert!(unsuppressed > 0, "fixture should produce findings");
+    assert_eq!(stdout.matches("<failure ").count(), unsuppressed);
+    assert!(stdout.trim_end().ends_with("</testsuites>"));
+}
+
+#[test]
+fn check_gitlab_output_lists_unsuppressed_diagnostics_with_fingerprints() {
+    let fixture = fixture_dir("noir_core/minimal");
+
+    let mut cmd = cli_bin();
+    cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--format",
+        "gitlab",
+    ]);
+    let output = cmd.output().expect("gitlab run should execute");
+    assert_eq!(output.status.code(), Some(1), "run should report findings");
+
+    let report: Value = serde_json::from_slice(&output.stdout).expect("report should parse");
+    let issues = report.as_array().expect("report should be an array");
+    assert!(!issues.is_empty(), "fixture should produce findings");
+    for issue in issues {
+        assert!(issue["check_name"].as_str().is_some());
+        assert!(
+            issue["fingerprint"]
+                .as_str()
+                .is_some_and(|value| !value.is_empty())
+        );
+        assert!(matches!(
+            issue["severity"].as_str(),
+            Some("info" | "minor" | "major" | "critical")
+        ));
+        let path = issue["location"]["path"]
+            .as_str()
+            .expect("location path should be a string");
+        assert!(
+            !Path::new(path).is_absolute(),
+            "path should be relative: {path}"
+        );
+        assert!(issue["location"]["lines"]["begin"].as_u64().is_some());
+    }
+}
+
+#[test]
+fn check_github_output_emits_workflow_commands_with_workspace_relative_paths() {
+    let fixture = fixture_dir("noir_core/minimal");
+    let workspace = fixture
+        .parent()
+        .expect("fixture should have a parent")
+        .to_path_buf();
+
+    let mut cmd = cli_bin();
+    cmd.env("GITHUB_WORKSPACE", &workspace).args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--format",
+        "github",
+    ]);
+    let output = cmd.output().expect("github run should execute");
+    assert_eq!(output.status.code(), Some(1), "run should report findings");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let commands = stdout.lines().collect::<Vec<_>>();
+    assert!(!commands.is_empty(), "fixture should produce annotations");
+    for command in commands {
+        assert!(
+            command.starts_with("::warning file=minimal/")
+                || command.starts_with("::error file=minimal/"),
+            "unexpected workflow command: {command}"
+        );
+        assert!(command.contains(",line="));
+        assert!(command.contains(",endLine="));
+        assert!(command.contains(",title="));
+    }
+}
+
+#[test]
+fn check_html_output_is_a_self_contained_report() {
+    let fixture = fixture_dir("noir_core/minimal");
+
+    let mut cmd = cli_bin();
+    cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--format",
+        "html",
+    ]);
+    let output = cmd.output().expect("html run should execute");
+    assert_eq!(output.status.code(), Some(1), "run should report findings");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("<!DOCTYPE html>"));
+    assert!(stdout.trim_end().ends_with("</html>"));
+    assert!(stdout.contains("<style>") && stdout.contains("<script>"));
+    assert!(
+        !stdout.contains("<link "),
+        "report should not load external assets"
+    );
+    assert!(stdout.contains("<section class=\"diagnostic "));
+    assert!(
+        stdout.contains("<mark class=\"primary\">"),
+        "snippets should highlight spans"
+    );
+    assert!(stdout.contains("<section class=\"rule-docs\" id=\"rule-NOIR"));
+}
+
+#[test]
+fn check_markdown_output_summarizes_findings_for_job_summaries() {
+    let fixture = fixture_dir("noir_core/minimal");
+
+    let mut cmd = cli_bin();
+    cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--profile",
+        "default",
+        "--format",
+        "markdown",
+    ]);
+    let output = cmd.output().expect("markdown run should execute");
+    assert_eq!(output.status.code(), Some(1), "run should report findings");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("## aztec-lint\n"));
+    assert!(stdout.contains("profile `default` · changed_only `false` · active_rules `"));
+    assert!(stdout.contains("### Findings by rule"));
+    assert!(stdout.contains("### Findings by file"));
+    assert!(stdout.contains("| `src/main.nr` |"));
+    assert!(stdout.contains("/docs/portal/lints/noir100.md)"));
+    assert!(stdout.contains("<details>\n<summary>warning[<code>NOIR100</code>]"));
+    assert!(stdout.contains("```noir\n"));
+}
+
+#[test]
+fn check_rdjson_output_matches_reviewdog_schema() {
+    let fixture = fixture_dir("noir_core/minimal");
+
+    let mut cmd = cli_bin();
+    cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--format",
+        "rdjson",
+    ]);
+    let output = cmd.output().expect("rdjson run should execute");
+    assert_eq!(output.status.code(), Some(1), "run should report findings");
+
+    let report: Value = serde_json::from_slice(&output.stdout).expect("rdjson should parse");
+    assert_eq!(report["source"]["name"], "aztec-lint");
+    let diagnostics = report["diagnostics"]
+        .as_array()
+        .expect("diagnostics should be an array");
+    assert!(!diagnostics.is_empty(), "fixture should produce findings");
+    for diagnostic in diagnostics {
+        assert!(matches!(
+            diagnostic["severity"].as_str(),
+            Some("ERROR" | "WARNING")
+        ));
+        assert!(diagnostic["code"]["value"].as_str().is_some());
+        assert!(
+            diagnostic["location"]["range"]["start"]["line"]
+                .as_u64()
+                .is_some()
+        );
+    }
+
+    let mut lines_cmd = cli_bin();
+    lines_cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--format",
+        "rdjsonl",
+    ]);
+    let lines_output = lines_cmd.output().expect("rdjsonl run should execute");
+    let stdout = String::from_utf8_lossy(&lines_output.stdout);
+    let lines = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).expect("each line should parse"))
+        .collect::<Vec<_>>();
+    assert_eq!(&lines, diagnostics);
+}
+
+#[test]
+fn check_ndjson_output_streams_json_records_and_a_summary() {
+    let fixture = fixture_dir("noir_core/minimal");
+
+    let mut json_cmd = cli_bin();
+    json_cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--format",
+        "json",
+    ]);
+    let json_output = json_cmd.output().expect("json run should execute");
+    let expected: Value =
+        serde_json::from_slice(&json_output.stdout).expect("json output should parse");
+
+    let mut cmd = cli_bin();
+    cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--format",
+        "ndjson",
+    ]);
+    let output = cmd.output().expect("ndjson run should execute");
+    assert_eq!(output.status.code(), Some(1), "run should report findings");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut records = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).expect("each line should parse"))
+        .collect::<Vec<_>>();
+    let summary = records.pop().expect("summary record should be present");
+    assert_eq!(Value::Array(records.clone()), expected);
+    assert_eq!(summary["kind"], "summary");
+    assert_eq!(summary["exit_code"], 1);
+    assert_eq!(summary["config_source"], "default");
+    assert_eq!(summary["diagnostics"], records.len());
+    assert!(
+        summary["rules"]
+            .as_object()
+            .is_some_and(|rules| !rules.is_empty())
+    );
+}
+
+#[test]
+fn check_checkstyle_and_sonarqube_outputs_report_fixture_findings() {
+    let fixture = fixture_dir("noir_core/minimal");
+
+    let mut checkstyle_cmd = cli_bin();
+    checkstyle_cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--format",
+        "checkstyle",
+    ]);
+    let checkstyle = checkstyle_cmd
+        .output()
+        .expect("checkstyle run should execute");
+    assert_eq!(
+        checkstyle.status.code(),
+        Some(1),
+        "run should report findings"
+    );
+    let stdout = String::from_utf8_lossy(&checkstyle.stdout);
+    assert!(stdout.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle"));
+    assert!(stdout.contains("<file name=\"src/main.nr\">"));
+    assert!(stdout.contains(r#"<error line="9" column="17" severity="warning""#));
+    assert!(stdout.contains(r#"source="aztec-lint.NOIR100"/>"#));
+
+    let mut sonarqube_cmd = cli_bin();
+    sonarqube_cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--format",
+        "sonarqube",
+    ]);
+    let sonarqube = sonarqube_cmd
+        .output()
+        .expect("sonarqube run should execute");
+    assert_eq!(
+        sonarqube.status.code(),
+        Some(1),
+        "run should report findings"
+    );
+    let report: Value =
+        serde_json::from_slice(&sonarqube.stdout).expect("sonarqube output should parse");
+    let issues = report["issues"]
+        .as_array()
+        .expect("issues should be an array");
+    let magic = issues
+        .iter()
+        .find(|issue| issue["ruleId"] == "NOIR100")
+        .expect("fixture should report NOIR100");
+    assert_eq!(magic["engineId"], "aztec-lint");
+    assert_eq!(magic["type"], "CODE_SMELL");
+    assert_eq!(magic["primaryLocation"]["filePath"], "src/main.nr");
+    assert_eq!(magic["primaryLocation"]["textRange"]["startLine"], 9);
+}
+
+#[test]
+fn check_output_flag_writes_extra_reports_from_one_run() {
+    let fixture = fixture_dir("noir_core/minimal");
+    let reports = tempdir().expect("temp dir should be created");
+    let sarif_path = reports.path().join("nested/out.sarif");
+    let json_path = reports.path().join("out.json");
+
+    let mut cmd = cli_bin();
+    cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--output",
+        &format!("sarif={}", sarif_path.display()),
+        "--output",
+        &format!("json={}", json_path.display()),
+    ]);
+    let output = cmd.output().expect("check run should execute");
+    assert_eq!(output.status.code(), Some(1), "run should report findings");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.starts_with("checked="),
+        "text report stays on stdout"
+    );
+    assert!(stdout.contains("NOIR100"));
+
+    let sarif: Value = serde_json::from_str(
+        &fs::read_to_string(&sarif_path).expect("sarif report should be written"),
+    )
+    .expect("sarif report should parse");
+    assert_eq!(sarif["version"], "2.1.0");
+    let json: Value = serde_json::from_str(
+        &fs::read_to_string(&json_path).expect("json report should be written"),
+    )
+    .expect("json report should parse");
+    let results = sarif["runs"][0]["results"]
+        .as_array()
+        .expect("sarif results should be an array");
+    let diagnostics = json.as_array().expect("json report should be an array");
+    assert_eq!(results.len(), diagnostics.len());
+    assert!(
+        diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic["rule_id"] == "NOIR100")
+    );
+}
+
+#[test]
+fn check_output_flag_rejects_malformed_targets() {
+    let fixture = fixture_dir("noir_core/minimal");
+
+    let mut cmd = cli_bin();
+    cmd.args([
+        "check",
+        fixture.to_string_lossy().as_ref(),
+        "--output",
+        "sarif",
+    ]);
+    let output = cmd.output().expect("check run should execute");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("expected <format>=<path>"));
+}
+
+#[test]
+fn init_writes_config_that_silences_current_findings() {
+    let workspace = tempdir().expect("temp dir should be created");
+    let project = workspace.path().join("project");
+    fs::create_dir_all(project.join("src")).expect("src dir should be created");
+    let fixture = fixture_dir("noir_core/minimal");
+    fs::copy(fixture.join("Nargo.toml"), project.join("Nargo.toml"))
+        .expect("manifest should be copied");
+    for source in ["main.nr", "math.nr"] {
+        fs::copy(
+            fixture.join("src").join(source),
+            project.join("src").join(source),
+        )
+        .expect("source should be copied");
+    }
+
+    let mut init = cli_bin();
+    init.args([
+        "init",
+        project.to_string_lossy().as_ref(),
+        "--from-current-findings",
+    ]);
+    let output = init.output().expect("init should execute");
+    assert_eq!(output.status.code(), Some(0));
+    let config =
+        fs::read_to_string(project.join("aztec-lint.toml")).expect("config should be written");
+    assert!(config.contains("[profile.noir]"));
+    assert!(config.contains("\"NOIR100\", #"));
+    assert!(!config.contains("[aztec]"));
+
+    let mut check = cli_bin();
+    check.args([
+        "check",
+        project.to_string_lossy().as_ref(),
+        "--profile",
+        "noir",
+    ]);
+    let output = check.output().expect("check should execute");
+    assert_eq!(
+        output.status.code(),
+        Some(0),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+
+    let mut again = cli_bin();
+    again.args(["init", project.to_string_lossy().as_ref()]);
+    let output = again.output().expect("second init should execute");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("already exists"));
+}
+
+#[test]
+fn config_show_reports_profile_order_and_level_sources() {
+    let workspace = tempdir().expect("temp dir should be created");
+    fs::write(
+        workspace.path().join("aztec-lint.toml"),
+        "[profile.ci]\nextends = [\"aztec\"]\ndeny = [\"NOIR120\"]\n",
+    )
+    .expect("config should be written");
+
+    let mut cmd = cli_bin();
+    cmd.args([
+        "config",
+        "show",
+        workspace.path().to_string_lossy().as_ref(),
+        "--profile",
+        "ci",
+        "--allow",
+        "NOIR100",
+    ]);
+    let output = cmd.output().expect("config show should execute");
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("profile_order=default -> aztec -> ci\n"));
+    assert!(stdout.contains("rulesets=noir_core, aztec_pack\n"));
+    assert!(stdout.contains("[profile.ci]\n"));
+    assert!(stdout.contains("NOIR120\tdeny\tprofile 'ci' override\n"));
+    assert!(stdout.contains("NOIR100\tallow\tcommand-line override\n"));
+    assert!(stdout.contains("AZTEC001\t"));
+    assert!(stdout.contains("\truleset 'aztec_pack' in profile 'aztec'\n"));
+}
+
+#[test]
+fn config_validate_fails_on_invalid_profiles() {
+    let workspace = tempdir().expect("temp dir should be created");
+    let config_path = workspace.path().join("aztec-lint.toml");
+    fs::write(&config_path, "[profile.ci]\nextends = [\"aztec\"]\n")
+        .expect("config should be written");
+
+    let mut valid = cli_bin();
+    valid.args([
+        "config",
+        "validate",
+        workspace.path().to_string_lossy().as_ref(),
+    ]);
+    let output = valid.output().expect("config validate should execute");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("config ok: "));
+
+    fs::write(&config_path, "[profile.ci]\nextends = [\"missing\"]\n")
+        .expect("config should be rewritten");
+    let mut invalid = cli_bin();
+    invalid.args([
+        "config",
+        "validate",
+        workspace.path().to_string_lossy().as_ref(),
+    ]);
+    let output = invalid.output().expect("config validate should execute");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("missing"));
+}
+
+#[test]
+fn rules_explain_level_traces_decision_chain() {
+    let workspace = tempdir().expect("temp dir should be created");
+    fs::write(
+        workspace.path().join("aztec-lint.toml"),
+        "[profile.ci]\nextends = [\"aztec\"]\ndeny = [\"AZTEC036\"]\n",
+    )
+    .expect("config should be written");
+
+    let mut cmd = cli_bin();
+    cmd.args([
+        "rules",
+        "--explain-level",
+        "aztec036",
+        "--profile",
+        "ci",
+        "--path",
+        workspace.path().to_string_lossy().as_ref(),
+    ]);
+    let output = cmd.output().expect("rules --explain-level should execute");
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Rule: AZTEC036\nProfile: ci\n"));
+    assert!(stdout.contains("Effective Level: deny\n"));
+    assert!(stdout.contains("1. enabled at warn by ruleset 'aztec_pack' in profile 'aztec'"));
+    assert!(stdout.contains("2. set to deny by profile 'ci' override\n"));
+    assert!(!stdout.contains("Command-line flags"));
+}
+
+#[test]
+fn lint_options_are_explained_and_validated() {
+    let mut explain = cli_bin();
+    explain.args(["explain", "NOIR110"]);
+    let output = explain.output().expect("explain should execute");
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Options ([lints.NOIR110]):\n- complexity_limit (integer, default 6): ")
+    );
+
+    let workspace = tempdir().expect("temp dir should be created");
+    let config_path = workspace.path().join("aztec-lint.toml");
+    fs::write(&config_path, "[lints.NOIR110]\ncomplexity_limit = 12\n")
+        .expect("config should be written");
+    let mut valid = cli_bin();
+    valid.args([
+        "config",
+        "validate",
+        workspace.path().to_string_lossy().as_ref(),
+    ]);
+    let output = valid.output().expect("config validate should execute");
+    assert_eq!(output.status.code(), Some(0));
+
+    fs::write(&config_path, "[lints.NOIR110]\ncomplexity = 12\n")
+        .expect("config should be rewritten");
+    let mut invalid = cli_bin();
+    invalid.args([
+        "config",
+        "validate",
+        workspace.path().to_string_lossy().as_ref(),
+    ]);
+    let output = invalid.output().expect("config validate should execute");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(
+        String::from_utf8_lossy(&output.stderr)
+            .contains("unknown option 'complexity' in [lints.NOIR110]")
+    );
+}
+
+#[test]
+fn unknown_config_keys_error_with_suggestions_unless_warn_is_requested() {
+    let workspace = tempdir().expect("temp dir should be created");
+    fs::write(
+        workspace.path().join("aztec-lint.toml"),
+        "[profile.default]\nrulesets = [\"noir_core\"]\n",
+    )
+    .expect("config should be written");
+
+    let mut strict = cli_bin();
+    strict.args([
+        "config",
+        "validate",
+        workspace.path().to_string_lossy().as_ref(),
+    ]);
+    let output = strict.output().expect("config validate should execute");
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains(
+        "aztec-lint.toml:2: unknown key 'rulesets' in [profile.default]; did you mean 'ruleset'?"
+    ));
+
+    let mut lenient = cli_bin();
+    lenient.args([
+        "config",
+        "validate",
+        workspace.path().to_string_lossy().as_ref(),
+        "--config-unknown-keys=warn",
+    ]);
+    let output = lenient.output().expect("config validate should execute");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("warning: "));
+}
+
 #[test]
 fn check_text_output_is_deterministic() {
     let fixture = fixture_dir("noir_core/minimal");
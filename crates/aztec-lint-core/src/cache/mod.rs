@@ -0,0 +1,168 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+pub const CACHE_DIR_NAME: &str = "aztec-lint";
+const CACHE_FORMAT_VERSION: &str = "cache-v1";
+
+/// Incrementally hashes named inputs into a content-addressed cache key.
+/// Every key implicitly covers the cache format and tool version.
+#[derive(Clone, Debug)]
+pub struct CacheKeyBuilder {
+    hasher: blake3::Hasher,
+}
+
+impl CacheKeyBuilder {
+    pub fn new(kind: &str) -> Self {
+        let mut builder = Self {
+            hasher: blake3::Hasher::new(),
+        };
+        builder
+            .field("format", CACHE_FORMAT_VERSION.as_bytes())
+            .field("tool_version", crate::VERSION.as_bytes())
+            .field("kind", kind.as_bytes());
+        builder
+    }
+
+    pub fn field(&mut self, name: &str, value: &[u8]) -> &mut Self {
+        for part in [name.as_bytes(), value] {
+            self.hasher.update(&(part.len() as u64).to_le_bytes());
+            self.hasher.update(part);
+        }
+        self
+    }
+
+    pub fn finish(&self) -> String {
+        self.hasher.finalize().to_hex().to_string()
+    }
+}
+
+/// On-disk store of JSON-serialized values addressed by [`CacheKeyBuilder`] keys.
+/// Reads and writes are best effort: unreadable or corrupt entries are misses.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContentCache {
+    root: PathBuf,
+}
+
+impl ContentCache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Cache location for a lint target: `$XDG_CACHE_HOME/aztec-lint` when set,
+    /// otherwise `<target_root>/target/aztec-lint`.
+    pub fn for_target_root(target_root: &Path) -> Self {
+        let xdg = std::env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute());
+        match xdg {
+            Some(xdg) => Self::new(xdg.join(CACHE_DIR_NAME)),
+            None => Self::new(target_root.join("target").join(CACHE_DIR_NAME)),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn load<T: DeserializeOwned>(&self, namespace: &str, key: &str) -> Option<T> {
+        let contents = fs::read(self.entry_path(namespace, key)).ok()?;
+        serde_json::from_slice(&contents).ok()
+    }
+
+    pub fn store<T: Serialize>(&self, namespace: &str, key: &str, value: &T) -> io::Result<()> {
+        let path = self.entry_path(namespace, key);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let rendered = serde_json::to_vec(value)?;
+        // Write through a temporary file so concurrent readers never observe
+        // a partially written entry.
+        let temporary = path.with_extension(format!("json.{}.tmp", std::process::id()));
+        fs::write(&temporary, rendered)?;
+        fs::rename(&temporary, &path)
+    }
+
+    /// Removes the whole cache directory. Returns `false` when nothing existed.
+    pub fn clean(&self) -> io::Result<bool> {
+        if !self.root.exists() {
+            return Ok(false);
+        }
+        fs::remove_dir_all(&self.root)?;
+        Ok(true)
+    }
+
+    fn entry_path(&self, namespace: &str, key: &str) -> PathBuf {
+        let shard = key.get(..2).unwrap_or(key);
+        self.root
+            .join(namespace)
+            .join(shard)
+            .join(format!("{key}.json"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use tempfile::tempdir;
+
+    use super::{CacheKeyBuilder, ContentCache};
+
+    #[test]
+    fn keys_depend_on_every_field() {
+        let mut base = CacheKeyBuilder::new("diagnostics");
+        base.field("profile", b"aztec");
+        let mut other_profile = CacheKeyBuilder::new("diagnostics");
+        other_profile.field("profile", b"default");
+        let mut other_kind = CacheKeyBuilder::new("models");
+        other_kind.field("profile", b"aztec");
+        let mut shifted = CacheKeyBuilder::new("diagnostics");
+        shifted.field("profil", b"eaztec");
+
+        assert_eq!(base.finish(), base.clone().finish());
+        assert_ne!(base.finish(), other_profile.finish());
+        assert_ne!(base.finish(), other_kind.finish());
+        assert_ne!(base.finish(), shifted.finish());
+    }
+
+    #[test]
+    fn stores_loads_and_cleans_entries() {
+        let tmp = tempdir().expect("temp dir should be created");
+        let cache = ContentCache::new(tmp.path().join("cache"));
+        let key = CacheKeyBuilder::new("test").finish();
+        let value = BTreeMap::from([("NOIR100".to_string(), vec![1u32, 2, 3])]);
+
+        assert_eq!(
+            cache.load::<BTreeMap<String, Vec<u32>>>("rules", &key),
+            None
+        );
+        cache
+            .store("rules", &key, &value)
+            .expect("entry should be stored");
+        assert_eq!(cache.load("rules", &key), Some(value));
+
+        assert!(cache.clean().expect("cache should be removed"));
+        assert!(!cache.clean().expect("missing cache is not an error"));
+        assert_eq!(
+            cache.load::<BTreeMap<String, Vec<u32>>>("rules", &key),
+            None
+        );
+    }
+
+    #[test]
+    fn corrupt_entries_are_cache_misses() {
+        let tmp = tempdir().expect("temp dir should be created");
+        let cache = ContentCache::new(tmp.path());
+        let key = CacheKeyBuilder::new("test").finish();
+        let path = cache.entry_path("rules", &key);
+        std::fs::create_dir_all(path.parent().expect("entry has a parent"))
+            .expect("shard dir should be created");
+        std::fs::write(&path, b"{not json").expect("corrupt entry should be written");
+
+        assert_eq!(cache.load::<Vec<u32>>("rules", &key), None);
+    }
+}
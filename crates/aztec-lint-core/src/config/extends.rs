@@ -0,0 +1,218 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+use crate::config::{CONFIG_FILE_PRIMARY, ConfigError};
+
+/// Prefix of `extends` entries that point into a Nargo dependency, as in
+/// `dep:shared_lints/aztec-lint.toml`.
+pub const DEPENDENCY_PREFIX: &str = "dep:";
+
+/// Resolves one top-level `extends` entry of the config at `config_path`.
+///
+/// Plain entries are paths relative to the config's directory. `dep:<name>`
+/// entries name a dependency declared in the `Nargo.toml` next to the config
+/// (or in one of its workspace members), optionally followed by
+/// `/<path>` inside it; the dependency's `aztec-lint.toml` is used otherwise.
+pub(crate) fn resolve_extends_entry(
+    config_path: &Path,
+    entry: &str,
+) -> Result<PathBuf, ConfigError> {
+    let config_dir = config_path.parent().unwrap_or(Path::new("."));
+    let resolved = match entry.trim().strip_prefix(DEPENDENCY_PREFIX) {
+        Some(reference) => {
+            let (dependency, file) = reference
+                .split_once('/')
+                .unwrap_or((reference, CONFIG_FILE_PRIMARY));
+            nargo_dependency_root(config_dir, dependency)
+                .ok_or_else(|| ConfigError::ExtendsDependencyNotFound {
+                    path: config_path.to_path_buf(),
+                    dependency: dependency.to_string(),
+                })?
+                .join(file)
+        }
+        None => config_dir.join(entry.trim()),
+    };
+
+    if !resolved.is_file() {
+        return Err(ConfigError::ExtendsNotFound {
+            path: config_path.to_path_buf(),
+            extends: entry.to_string(),
+            resolved,
+        });
+    }
+    Ok(resolved)
+}
+
+/// Merges the config table `overlay` over `base`, which holds everything
+/// inherited so far.
+///
+/// A `[profile.<name>]` in `overlay` replaces the inherited profile of that
+/// name, `[[overrides]]` blocks are appended after the inherited ones so they
+/// win on conflicts, `include`/`exclude` globs are combined, and every other
+/// table (`[aztec]`, `[deprecated_path]`, `[lints.<RULE_ID>]`) is merged key
+/// by key with `overlay` taking precedence.
+///
+/// Globs are copied verbatim rather than rebased onto the directory of the
+/// file declaring them, so inherited `include`/`exclude` and `[[overrides]]`
+/// `paths` resolve against the directory of the config loaded for the run.
+/// Shared presets usually live outside the project (often in a Nargo
+/// dependency), where rebased globs could never match.
+pub(crate) fn merge_config_tables(base: &mut Table, overlay: Table) {
+    for (key, value) in overlay {
+        if key == "extends" {
+            continue;
+        }
+        let Some(existing) = base.get_mut(&key) else {
+            base.insert(key, value);
+            continue;
+        };
+        match (key.as_str(), existing, value) {
+            ("profile", Value::Table(profiles), Value::Table(overlay)) => profiles.extend(overlay),
+            ("overrides", Value::Array(blocks), Value::Array(overlay)) => blocks.extend(overlay),
+            ("include" | "exclude", Value::Array(globs), Value::Array(overlay)) => {
+                for glob in overlay {
+                    if !globs.contains(&glob) {
+                        globs.push(glob);
+                    }
+                }
+            }
+            (_, Value::Table(table), Value::Table(overlay)) => merge_tables(table, overlay),
+            (_, existing, value) => *existing = value,
+        }
+    }
+}
+
+fn merge_tables(base: &mut Table, overlay: Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Table(table)), Value::Table(overlay)) => merge_tables(table, overlay),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn nargo_dependency_root(dir: &Path, dependency: &str) -> Option<PathBuf> {
+    let manifest = read_manifest(&dir.join("Nargo.toml"))?;
+    if let Some(root) = dependency_root(dir, &manifest, dependency) {
+        return Some(root);
+    }
+    manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .find_map(|member| {
+            let member_dir = dir.join(member);
+            let manifest = read_manifest(&member_dir.join("Nargo.toml"))?;
+            dependency_root(&member_dir, &manifest, dependency)
+        })
+}
+
+fn read_manifest(path: &Path) -> Option<Table> {
+    toml::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+/// Where Nargo keeps `dependency` of the package in `package_dir`: the
+/// `path` of a local dependency, or `~/nargo/<host>/<repo>/<tag>[/<directory>]`
+/// for a git dependency.
+fn dependency_root(package_dir: &Path, manifest: &Table, dependency: &str) -> Option<PathBuf> {
+    let spec = manifest.get("dependencies")?.get(dependency)?;
+    if let Some(path) = spec.get("path").and_then(Value::as_str) {
+        return Some(package_dir.join(path));
+    }
+
+    let git = spec.get("git")?.as_str()?;
+    let tag = spec.get("tag")?.as_str()?;
+    let repository = git
+        .split_once("://")
+        .map_or(git, |(_, repository)| repository)
+        .trim_end_matches('/');
+    let mut root = PathBuf::from(std::env::var_os("HOME")?)
+        .join("nargo")
+        .join(repository)
+        .join(tag);
+    if let Some(directory) = spec.get("directory").and_then(Value::as_str) {
+        root.push(directory);
+    }
+    Some(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use toml::{Table, Value};
+
+    use super::merge_config_tables;
+
+    #[test]
+    fn merges_inherited_tables_with_defined_precedence() {
+        let mut merged = toml::from_str::<Table>(
+            r#"
+exclude = ["vendor/"]
+
+[profile.ci]
+ruleset = ["noir_core"]
+deny = ["NOIR100"]
+
+[profile.shared]
+ruleset = ["aztec_pack"]
+
+[aztec]
+contract_attribute = "contract"
+nullifier_fns = ["nullify"]
+
+[[overrides]]
+paths = ["examples/"]
+allow = ["all"]
+"#,
+        )
+        .expect("base should parse");
+        let overlay = toml::from_str::<Table>(
+            r#"
+extends = ["../shared/aztec-lint.toml"]
+exclude = ["vendor/", "**/generated/"]
+
+[profile.ci]
+ruleset = ["aztec_pack"]
+
+[aztec]
+nullifier_fns = ["emit_nullifier"]
+
+[[overrides]]
+paths = ["examples/"]
+deny = ["NOIR100"]
+"#,
+        )
+        .expect("overlay should parse");
+
+        merge_config_tables(&mut merged, overlay);
+
+        assert!(!merged.contains_key("extends"));
+        assert_eq!(
+            merged["exclude"],
+            Value::Array(vec!["vendor/".into(), "**/generated/".into()])
+        );
+        assert!(merged["profile"]["ci"].get("deny").is_none());
+        assert_eq!(
+            merged["profile"]["ci"]["ruleset"][0].as_str(),
+            Some("aztec_pack")
+        );
+        assert!(merged["profile"].get("shared").is_some());
+        assert_eq!(
+            merged["aztec"]["contract_attribute"].as_str(),
+            Some("contract")
+        );
+        assert_eq!(
+            merged["aztec"]["nullifier_fns"][0].as_str(),
+            Some("emit_nullifier")
+        );
+        let overrides = merged["overrides"].as_array().expect("overrides array");
+        assert_eq!(overrides.len(), 2);
+        assert!(overrides[1].get("deny").is_some());
+    }
+}
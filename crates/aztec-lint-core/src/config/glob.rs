@@ -0,0 +1,146 @@
+use serde::Serialize;
+
+/// Global `include`/`exclude` globs deciding which paths are linted at all,
+/// matched with [`path_matches_glob`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct PathFilter {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+impl PathFilter {
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Whether `path` or one of its ancestors matches an `exclude` glob.
+    /// Excluded directories are not walked.
+    pub fn excludes(&self, path: &str) -> bool {
+        self.exclude
+            .iter()
+            .any(|pattern| path_matches_glob(pattern, path))
+    }
+
+    /// Whether the source file at `path` is linted: it is not excluded and,
+    /// when `include` is non-empty, matches one of its globs.
+    pub fn includes_file(&self, path: &str) -> bool {
+        !self.excludes(path)
+            && (self.include.is_empty()
+                || self
+                    .include
+                    .iter()
+                    .any(|pattern| path_matches_glob(pattern, path)))
+    }
+}
+
+/// Matches `path` against a config path glob.
+///
+/// Both are `/`-separated and relative to the directory holding
+/// `aztec-lint.toml`. `*` and `?` match within one path segment, `**` matches
+/// any number of segments, and a pattern that matches a directory also
+/// matches everything below it, so `examples/` and `contracts/test_*` cover
+/// whole trees.
+pub fn path_matches_glob(pattern: &str, path: &str) -> bool {
+    let pattern = segments(pattern);
+    let path = segments(path);
+    if pattern.is_empty() {
+        return false;
+    }
+    (1..=path.len()).any(|len| match_segments(&pattern, &path[..len]))
+}
+
+fn segments(value: &str) -> Vec<&str> {
+    let trimmed = value.trim();
+    let trimmed = trimmed.strip_prefix("./").unwrap_or(trimmed);
+    trimmed
+        .split(['/', '\\'])
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+        .collect()
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        Some((segment, rest)) => path.split_first().is_some_and(|(name, remaining)| {
+            match_segment(segment, name) && match_segments(rest, remaining)
+        }),
+    }
+}
+
+fn match_segment(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+    let (mut p, mut n) = (0usize, 0usize);
+    // Position of the last `*` and the name index it is currently absorbing up to.
+    let mut backtrack = None::<(usize, usize)>;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(ch) if *ch == '?' || *ch == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => {
+                let Some((star, absorbed)) = backtrack else {
+                    return false;
+                };
+                p = star + 1;
+                n = absorbed + 1;
+                backtrack = Some((star, absorbed + 1));
+            }
+        }
+    }
+    pattern[p..].iter().all(|ch| *ch == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PathFilter, path_matches_glob};
+
+    #[test]
+    fn wildcards_match_within_a_segment() {
+        assert!(path_matches_glob("src/*.nr", "src/main.nr"));
+        assert!(path_matches_glob("src/ma?n.nr", "src/main.nr"));
+        assert!(!path_matches_glob("src/*.nr", "src/nested/main.nr"));
+        assert!(!path_matches_glob("*.nr", "src/main.nr"));
+    }
+
+    #[test]
+    fn double_star_matches_any_depth() {
+        assert!(path_matches_glob("**/*.nr", "main.nr"));
+        assert!(path_matches_glob("**/*.nr", "contracts/token/src/main.nr"));
+        assert!(path_matches_glob("**/generated/**", "a/b/generated/out.nr"));
+    }
+
+    #[test]
+    fn directory_patterns_cover_their_subtrees() {
+        assert!(path_matches_glob("examples/", "examples/demo/src/main.nr"));
+        assert!(path_matches_glob(
+            "contracts/test_*",
+            "contracts/test_token/src/main.nr"
+        ));
+        assert!(!path_matches_glob(
+            "contracts/test_*",
+            "contracts/token/src/test_main.nr"
+        ));
+        assert!(path_matches_glob("./examples", "examples/main.nr"));
+        assert!(!path_matches_glob("", "examples/main.nr"));
+    }
+
+    #[test]
+    fn filters_exclude_before_include() {
+        let filter = PathFilter {
+            include: vec!["contracts/".to_string()],
+            exclude: vec!["**/generated/".to_string()],
+        };
+        assert!(filter.includes_file("contracts/token/src/main.nr"));
+        assert!(!filter.includes_file("contracts/token/src/generated/bindings.nr"));
+        assert!(!filter.includes_file("vendor/aztec/src/lib.nr"));
+        assert!(filter.excludes("contracts/token/generated"));
+        assert!(PathFilter::default().includes_file("vendor/aztec/src/lib.nr"));
+    }
+}
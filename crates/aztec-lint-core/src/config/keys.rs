@@ -0,0 +1,465 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+/// Keys accepted at the top level of `aztec-lint.toml`.
+pub const TOP_LEVEL_KEYS: &[&str] = &[
+    "extends",
+    "profile",
+    "aztec",
+    "deprecated_path",
+    "lints",
+    "overrides",
+    "include",
+    "exclude",
+];
+/// Keys accepted in a `[profile.<name>]` table.
+pub const PROFILE_KEYS: &[&str] = &["extends", "ruleset", "deny", "warn", "allow"];
+/// Keys accepted in an `[[overrides]]` block.
+pub const OVERRIDE_KEYS: &[&str] = &["paths", "deny", "warn", "allow", "lints"];
+/// Keys accepted in the `[aztec]` table.
+pub const AZTEC_KEYS: &[&str] = &[
+    "contract_attribute",
+    "external_attribute",
+    "external_kinds",
+    "only_self_attribute",
+    "initializer_attribute",
+    "storage_attribute",
+    "imports_prefixes",
+    "note_getter_fns",
+    "nullifier_fns",
+    "enqueue_fn",
+    "contract_at_fn",
+    "domain_separation",
+];
+/// Keys accepted in the `[aztec.domain_separation]` table.
+pub const DOMAIN_SEPARATION_KEYS: &[&str] = &["nullifier_requires", "commitment_requires"];
+/// Keys accepted in the `[deprecated_path]` table.
+pub const DEPRECATED_PATH_KEYS: &[&str] = &[
+    "warn_on_blocked",
+    "try_absolute_root",
+    "verbose_blocked_notes",
+];
+
+/// What the loader does with keys that no config table recognizes.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum UnknownKeyPolicy {
+    /// Fail with [`crate::config::ConfigError::UnknownKey`].
+    #[default]
+    Error,
+    /// Ignore the key and report it in [`crate::config::LoadedConfig::warnings`].
+    Warn,
+}
+
+/// A config key that deserialization would otherwise silently ignore.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnknownConfigKey {
+    pub path: PathBuf,
+    /// 1-based line of the key, when it could be located in the file.
+    pub line: Option<usize>,
+    /// Dotted name of the enclosing table, e.g. `profile.default`; empty at
+    /// the top level.
+    pub table: String,
+    pub key: String,
+    /// Closest known key in the same table.
+    pub suggestion: Option<String>,
+}
+
+impl Display for UnknownConfigKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.path.display())?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+        }
+        if self.table.is_empty() {
+            write!(f, ": unknown top-level key '{}'", self.key)?;
+        } else {
+            write!(f, ": unknown key '{}' in [{}]", self.key, self.table)?;
+        }
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, "; did you mean '{suggestion}'?")?;
+        }
+        Ok(())
+    }
+}
+
+/// Lists keys in `raw` that the config schema does not recognize, in file
+/// order. `[lints.<RULE_ID>]` tables are checked separately against the lint
+/// catalog.
+pub fn find_unknown_keys(path: &Path, raw: &str, table: &Table) -> Vec<UnknownConfigKey> {
+    let mut unknown = Vec::<UnknownEntry>::new();
+    collect_unknown(&[], table, TOP_LEVEL_KEYS, &mut unknown);
+    if let Some(profiles) = table.get("profile").and_then(Value::as_table) {
+        for (name, profile) in profiles {
+            if let Some(profile) = profile.as_table() {
+                collect_unknown(
+                    &["profile", name.as_str()],
+                    profile,
+                    PROFILE_KEYS,
+                    &mut unknown,
+                );
+            }
+        }
+    }
+    if let Some(aztec) = table.get("aztec").and_then(Value::as_table) {
+        collect_unknown(&["aztec"], aztec, AZTEC_KEYS, &mut unknown);
+        if let Some(domain_separation) = aztec.get("domain_separation").and_then(Value::as_table) {
+            collect_unknown(
+                &["aztec", "domain_separation"],
+                domain_separation,
+                DOMAIN_SEPARATION_KEYS,
+                &mut unknown,
+            );
+        }
+    }
+    if let Some(deprecated_path) = table.get("deprecated_path").and_then(Value::as_table) {
+        collect_unknown(
+            &["deprecated_path"],
+            deprecated_path,
+            DEPRECATED_PATH_KEYS,
+            &mut unknown,
+        );
+    }
+    if let Some(overrides) = table.get("overrides").and_then(Value::as_array) {
+        for (position, block) in overrides.iter().enumerate() {
+            if let Some(block) = block.as_table() {
+                // Blocks are told apart by their 1-based index for line
+                // lookup, but reported under the shared `overrides` table.
+                let index = (position + 1).to_string();
+                collect_unknown_in(
+                    &["overrides", index.as_str()],
+                    "overrides",
+                    block,
+                    OVERRIDE_KEYS,
+                    &mut unknown,
+                );
+            }
+        }
+    }
+
+    let lines = key_lines(raw);
+    let mut keys = unknown
+        .into_iter()
+        .map(|(key_path, table, known)| {
+            let key = key_path.last().expect("key paths are never empty");
+            UnknownConfigKey {
+                path: path.to_path_buf(),
+                // Keys inside inline tables fall back to the line of their
+                // nearest enclosing key.
+                line: (1..=key_path.len())
+                    .rev()
+                    .find_map(|len| lines.get(&key_path[..len]).copied()),
+                table,
+                key: key.clone(),
+                suggestion: closest_key(key, known).map(str::to_string),
+            }
+        })
+        .collect::<Vec<_>>();
+    keys.sort_by_key(|key| {
+        (
+            key.line.unwrap_or(usize::MAX),
+            key.table.clone(),
+            key.key.clone(),
+        )
+    });
+    keys
+}
+
+/// An unknown key's full path, the table it is reported in, and the keys
+/// that table accepts.
+type UnknownEntry = (Vec<String>, String, &'static [&'static str]);
+
+fn collect_unknown(
+    prefix: &[&str],
+    table: &Table,
+    known: &'static [&'static str],
+    out: &mut Vec<UnknownEntry>,
+) {
+    collect_unknown_in(prefix, &prefix.join("."), table, known, out);
+}
+
+fn collect_unknown_in(
+    prefix: &[&str],
+    table_name: &str,
+    table: &Table,
+    known: &'static [&'static str],
+    out: &mut Vec<UnknownEntry>,
+) {
+    for key in table.keys() {
+        if !known.contains(&key.as_str()) {
+            let mut key_path = prefix
+                .iter()
+                .map(|segment| segment.to_string())
+                .collect::<Vec<_>>();
+            key_path.push(key.clone());
+            out.push((key_path, table_name.to_string(), known));
+        }
+    }
+}
+
+/// The known key within edit distance of a third of `key`'s length (at
+/// least one), preferring the closest.
+fn closest_key(key: &str, known: &[&'static str]) -> Option<&'static str> {
+    let max_distance = (key.chars().count() / 3).max(1);
+    known
+        .iter()
+        .map(|candidate| (edit_distance(key, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn edit_distance(left: &str, right: &str) -> usize {
+    let right = right.chars().collect::<Vec<_>>();
+    let mut previous = (0..=right.len()).collect::<Vec<_>>();
+    for (i, left_char) in left.chars().enumerate() {
+        let mut current = vec![i + 1; right.len() + 1];
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left_char != *right_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[right.len()]
+}
+
+/// Maps every dotted key path written in `raw` (table headers, their
+/// prefixes, and `key = value` lines) to the 1-based line that first
+/// mentions it. Each `[[array]]` header appends its 1-based element index to
+/// the path. This is a line scanner rather than a full TOML parser; keys it
+/// cannot place are reported without a line.
+fn key_lines(raw: &str) -> BTreeMap<Vec<String>, usize> {
+    let mut lines = BTreeMap::<Vec<String>, usize>::new();
+    let mut current = Vec::<String>::new();
+    let mut array_lengths = BTreeMap::<Vec<String>, usize>::new();
+    // Open `[`/`{` count of a value continuing across lines.
+    let mut value_depth = 0usize;
+    for (index, line) in raw.lines().enumerate() {
+        let trimmed = line.trim();
+        if value_depth > 0 {
+            value_depth = apply_bracket_delta(value_depth, trimmed);
+            continue;
+        }
+        let key_path = if let Some(header) = trimmed.strip_prefix('[') {
+            let (header, is_array) = match header.strip_prefix('[') {
+                Some(header) => (header, true),
+                None => (header, false),
+            };
+            let Some(end) = header.find(']') else {
+                continue;
+            };
+            current = split_dotted_key(&header[..end]);
+            if is_array {
+                let length = array_lengths.entry(current.clone()).or_default();
+                *length += 1;
+                current.push(length.to_string());
+            }
+            current.clone()
+        } else if let Some((key, value)) = trimmed.split_once('=') {
+            value_depth = apply_bracket_delta(0, value);
+            if key.trim().is_empty() || !key.chars().all(is_bare_key_char) {
+                continue;
+            }
+            let mut key_path = current.clone();
+            key_path.extend(split_dotted_key(key));
+            key_path
+        } else {
+            continue;
+        };
+        for len in 1..=key_path.len() {
+            lines.entry(key_path[..len].to_vec()).or_insert(index + 1);
+        }
+    }
+    lines
+}
+
+/// Adds the unbalanced brackets and braces of `text` to `depth`, skipping
+/// quoted strings and comments.
+fn apply_bracket_delta(depth: usize, text: &str) -> usize {
+    let mut depth = depth;
+    let mut quote = None::<char>;
+    for ch in text.chars() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '#') => break,
+            (None, '[' | '{') => depth += 1,
+            (None, ']' | '}') => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    depth
+}
+
+fn is_bare_key_char(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.' | '"' | '\'' | ' ' | '\t')
+}
+
+fn split_dotted_key(key: &str) -> Vec<String> {
+    let mut segments = Vec::<String>::new();
+    let mut segment = String::new();
+    let mut quote = None::<char>;
+    for ch in key.chars() {
+        match (quote, ch) {
+            (None, '"' | '\'') => quote = Some(ch),
+            (Some(open), _) if ch == open => quote = None,
+            (None, '.') => segments.push(std::mem::take(&mut segment).trim().to_string()),
+            _ => segment.push(ch),
+        }
+    }
+    segments.push(segment.trim().to_string());
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use toml::{Table, Value};
+
+    use super::{
+        AZTEC_KEYS, DEPRECATED_PATH_KEYS, DOMAIN_SEPARATION_KEYS, OVERRIDE_KEYS, PROFILE_KEYS,
+        TOP_LEVEL_KEYS, edit_distance, find_unknown_keys,
+    };
+    use crate::config::{
+        AztecConfig, DeprecatedPathConfig, DomainSeparationConfig, PathOverride, Profile, RawConfig,
+    };
+
+    fn serialized_keys<T: serde::Serialize>(value: &T) -> Vec<String> {
+        let mut keys = Value::try_from(value)
+            .expect("config should serialize")
+            .as_table()
+            .expect("config should serialize to a table")
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        keys.sort();
+        keys
+    }
+
+    fn sorted(keys: &[&str]) -> Vec<String> {
+        let mut keys = keys.iter().map(|key| key.to_string()).collect::<Vec<_>>();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn known_keys_match_config_structs() {
+        assert_eq!(
+            serialized_keys(&RawConfig::default()),
+            sorted(TOP_LEVEL_KEYS)
+        );
+        assert_eq!(serialized_keys(&Profile::default()), sorted(PROFILE_KEYS));
+        assert_eq!(
+            serialized_keys(&PathOverride::default()),
+            sorted(OVERRIDE_KEYS)
+        );
+        assert_eq!(serialized_keys(&AztecConfig::default()), sorted(AZTEC_KEYS));
+        assert_eq!(
+            serialized_keys(&DomainSeparationConfig::default()),
+            sorted(DOMAIN_SEPARATION_KEYS)
+        );
+        assert_eq!(
+            serialized_keys(&DeprecatedPathConfig::default()),
+            sorted(DEPRECATED_PATH_KEYS)
+        );
+    }
+
+    #[test]
+    fn reports_unknown_keys_with_lines_and_suggestions() {
+        let raw = r#"
+[profile.default]
+rulesets = [
+    ["not", "a", "header"],
+]
+
+[aztec]
+nulifier_fns = ["nullify"]
+
+[aztec.domain_separation]
+nullifier_requires = ["nonce"]
+bogus = 1
+"#;
+        let table = raw.parse::<Table>().expect("config should parse");
+
+        let unknown = find_unknown_keys(Path::new("aztec-lint.toml"), raw, &table);
+
+        let rendered = unknown.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            rendered,
+            vec![
+                "aztec-lint.toml:3: unknown key 'rulesets' in [profile.default]; did you mean 'ruleset'?",
+                "aztec-lint.toml:8: unknown key 'nulifier_fns' in [aztec]; did you mean 'nullifier_fns'?",
+                "aztec-lint.toml:12: unknown key 'bogus' in [aztec.domain_separation]",
+            ]
+        );
+    }
+
+    #[test]
+    fn locates_dotted_keys_inline_tables_and_top_level_tables() {
+        let raw = "aztec.enque_fn = \"enqueue\"\ndeprecated_path = { warn_on_block = true }\n\n[aztek]\ncontract_attribute = \"aztec\"\n";
+        let table = raw.parse::<Table>().expect("config should parse");
+
+        let unknown = find_unknown_keys(Path::new("aztec-lint.toml"), raw, &table)
+            .into_iter()
+            .map(|key| (key.line, key.table, key.key, key.suggestion))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            unknown,
+            vec![
+                (
+                    Some(1),
+                    "aztec".to_string(),
+                    "enque_fn".to_string(),
+                    Some("enqueue_fn".to_string())
+                ),
+                (
+                    Some(2),
+                    "deprecated_path".to_string(),
+                    "warn_on_block".to_string(),
+                    Some("warn_on_blocked".to_string())
+                ),
+                (
+                    Some(4),
+                    String::new(),
+                    "aztek".to_string(),
+                    Some("aztec".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn locates_unknown_keys_per_override_block() {
+        let raw = r#"
+[[overrides]]
+paths = ["examples/"]
+allow = ["all"]
+
+[[overrides]]
+paths = ["contracts/test_*"]
+alow = ["NOIR100"]
+"#;
+        let table = raw.parse::<Table>().expect("config should parse");
+
+        let unknown = find_unknown_keys(Path::new("aztec-lint.toml"), raw, &table);
+
+        let rendered = unknown.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            rendered,
+            vec!["aztec-lint.toml:8: unknown key 'alow' in [overrides]; did you mean 'allow'?"]
+        );
+    }
+
+    #[test]
+    fn edit_distance_counts_single_character_edits() {
+        assert_eq!(edit_distance("ruleset", "ruleset"), 0);
+        assert_eq!(edit_distance("rulesets", "ruleset"), 1);
+        assert_eq!(edit_distance("alow", "allow"), 1);
+        assert_eq!(edit_distance("deny", "warn"), 4);
+    }
+}
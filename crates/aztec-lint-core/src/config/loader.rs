@@ -1,7 +1,11 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::config::{Config, ConfigError, RawConfig};
+use toml::Table;
+
+use crate::config::extends::{merge_config_tables, resolve_extends_entry};
+use crate::config::keys::find_unknown_keys;
+use crate::config::{Config, ConfigError, RawConfig, UnknownConfigKey, UnknownKeyPolicy};
 
 pub const CONFIG_FILE_PRIMARY: &str = "aztec-lint.toml";
 pub const CONFIG_FILE_FALLBACK: &str = "noir-lint.toml";
@@ -16,50 +20,124 @@ pub enum ConfigSource {
 pub struct LoadedConfig {
     pub config: Config,
     pub source: ConfigSource,
+    /// Unknown keys that were ignored under [`UnknownKeyPolicy::Warn`].
+    pub warnings: Vec<UnknownConfigKey>,
+    /// Every config file read: the loaded file first, then the files it
+    /// `extends`, in load order. Empty for the default config.
+    pub files: Vec<PathBuf>,
 }
 
 pub fn load_from_dir(dir: &Path) -> Result<LoadedConfig, ConfigError> {
-    let primary = dir.join(CONFIG_FILE_PRIMARY);
-    if primary.is_file() {
-        let config = load_from_path(&primary)?;
-        return Ok(LoadedConfig {
-            config,
-            source: ConfigSource::File(primary),
-        });
-    }
+    load_from_dir_with_policy(dir, UnknownKeyPolicy::Error)
+}
 
-    let fallback = dir.join(CONFIG_FILE_FALLBACK);
-    if fallback.is_file() {
-        let config = load_from_path(&fallback)?;
-        return Ok(LoadedConfig {
-            config,
-            source: ConfigSource::File(fallback),
-        });
+pub fn load_from_dir_with_policy(
+    dir: &Path,
+    policy: UnknownKeyPolicy,
+) -> Result<LoadedConfig, ConfigError> {
+    for file_name in [CONFIG_FILE_PRIMARY, CONFIG_FILE_FALLBACK] {
+        let path = dir.join(file_name);
+        if path.is_file() {
+            let (config, warnings, files) = load_file(&path, policy)?;
+            return Ok(LoadedConfig {
+                config,
+                source: ConfigSource::File(path),
+                warnings,
+                files,
+            });
+        }
     }
 
     Ok(LoadedConfig {
         config: Config::default(),
         source: ConfigSource::Default,
+        warnings: Vec::new(),
+        files: Vec::new(),
     })
 }
 
 pub fn load_from_path(path: &Path) -> Result<Config, ConfigError> {
+    load_file(path, UnknownKeyPolicy::Error).map(|(config, _, _)| config)
+}
+
+fn load_file(
+    path: &Path,
+    policy: UnknownKeyPolicy,
+) -> Result<(Config, Vec<UnknownConfigKey>, Vec<PathBuf>), ConfigError> {
+    let mut unknown_keys = Vec::<UnknownConfigKey>::new();
+    let mut files = Vec::<PathBuf>::new();
+    let table = load_table(path, policy, &mut Vec::new(), &mut unknown_keys, &mut files)?;
+    let parsed = toml::Value::Table(table)
+        .try_into::<RawConfig>()
+        .map_err(|source| ConfigError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+    let config = Config::from_raw(parsed);
+    config.validate_lint_options()?;
+    config.resolve_path_overrides()?;
+    Ok((config, unknown_keys, files))
+}
+
+/// Reads the config at `path` merged over the files it `extends`, which are
+/// applied in listed order. `stack` holds the files currently being loaded,
+/// for cycle detection; every file read is appended to `files`.
+fn load_table(
+    path: &Path,
+    policy: UnknownKeyPolicy,
+    stack: &mut Vec<PathBuf>,
+    unknown_keys: &mut Vec<UnknownConfigKey>,
+    files: &mut Vec<PathBuf>,
+) -> Result<Table, ConfigError> {
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if let Some(start) = stack.iter().position(|item| *item == canonical) {
+        let mut cycle = stack[start..].to_vec();
+        cycle.push(canonical);
+        return Err(ConfigError::ExtendsCycle { cycle });
+    }
+
+    files.push(path.to_path_buf());
     let raw = fs::read_to_string(path).map_err(|source| ConfigError::Io {
         path: path.to_path_buf(),
         source,
     })?;
-    let parsed = toml::from_str::<RawConfig>(&raw).map_err(|source| ConfigError::Parse {
+    let parse_error = |source| ConfigError::Parse {
         path: path.to_path_buf(),
         source,
-    })?;
-    Ok(Config::from_raw(parsed))
+    };
+    let parsed = toml::from_str::<RawConfig>(&raw).map_err(parse_error)?;
+    let table = toml::from_str::<Table>(&raw).map_err(parse_error)?;
+    let mut file_unknown_keys = find_unknown_keys(path, &raw, &table);
+    if policy == UnknownKeyPolicy::Error && !file_unknown_keys.is_empty() {
+        return Err(ConfigError::UnknownKey(file_unknown_keys.remove(0)));
+    }
+    unknown_keys.append(&mut file_unknown_keys);
+
+    stack.push(canonical);
+    let mut merged = Table::new();
+    for entry in &parsed.extends {
+        let base = resolve_extends_entry(path, entry)?;
+        merge_config_tables(
+            &mut merged,
+            load_table(&base, policy, stack, unknown_keys, files)?,
+        );
+    }
+    stack.pop();
+    merge_config_tables(&mut merged, table);
+    Ok(merged)
 }
 
 #[cfg(test)]
 mod tests {
     use std::fs;
 
-    use super::{CONFIG_FILE_FALLBACK, CONFIG_FILE_PRIMARY, ConfigSource, load_from_dir};
+    use super::{
+        CONFIG_FILE_FALLBACK, CONFIG_FILE_PRIMARY, ConfigSource, load_from_dir,
+        load_from_dir_with_policy,
+    };
+    use crate::config::{ConfigError, UnknownKeyPolicy};
+    use crate::lints::LintOptionValue;
 
     #[test]
     fn prefers_aztec_lint_file_when_both_exist() {
@@ -119,4 +197,215 @@ mod tests {
         assert_eq!(loaded.source, ConfigSource::Default);
         assert!(loaded.config.profile.contains_key("default"));
     }
+
+    #[test]
+    fn loads_lint_option_tables() {
+        let temp_dir = tempfile::tempdir().expect("tempdir should be created");
+        fs::write(
+            temp_dir.path().join(CONFIG_FILE_PRIMARY),
+            "[lints.noir110]\ncomplexity_limit = 10\n",
+        )
+        .expect("config should be written");
+
+        let loaded = load_from_dir(temp_dir.path()).expect("config should load");
+
+        assert_eq!(
+            loaded.config.lints["NOIR110"].get("complexity_limit"),
+            Some(&LintOptionValue::Integer(10))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_and_mistyped_lint_options() {
+        let temp_dir = tempfile::tempdir().expect("tempdir should be created");
+        let path = temp_dir.path().join(CONFIG_FILE_PRIMARY);
+
+        fs::write(&path, "[lints.NOIR120]\nnest_limit = 4\n").expect("config should be written");
+        let err = load_from_dir(temp_dir.path()).expect_err("unknown option should fail");
+        assert!(
+            matches!(err, ConfigError::UnknownLintOption { ref option, .. } if option == "nest_limit")
+        );
+        assert!(err.to_string().contains("expected one of: nesting_limit"));
+
+        for value in ["\"4\"", "true", "4.5", "[\"4\"]"] {
+            fs::write(&path, format!("[lints.NOIR120]\nnesting_limit = {value}\n"))
+                .expect("config should be written");
+            let err = load_from_dir(temp_dir.path()).expect_err("mistyped option should fail");
+            assert_eq!(
+                err.to_string(),
+                "invalid value for 'nesting_limit' in [lints.NOIR120]: expected a non-negative integer"
+            );
+        }
+
+        fs::write(&path, "[lints.NOIR999]\nlimit = 1\n").expect("config should be written");
+        let err = load_from_dir(temp_dir.path()).expect_err("unknown rule should fail");
+        assert!(
+            matches!(err, ConfigError::UnknownRuleId { ref rule_id, .. } if rule_id == "NOIR999")
+        );
+    }
+
+    #[test]
+    fn unknown_keys_fail_by_default_and_warn_on_request() {
+        let temp_dir = tempfile::tempdir().expect("tempdir should be created");
+        fs::write(
+            temp_dir.path().join(CONFIG_FILE_PRIMARY),
+            "[profile.default]\nrulesets = [\"aztec_pack\"]\n",
+        )
+        .expect("config should be written");
+
+        let err = load_from_dir(temp_dir.path()).expect_err("unknown key should fail");
+        let ConfigError::UnknownKey(key) = &err else {
+            panic!("expected unknown key error, got {err}");
+        };
+        assert_eq!(key.line, Some(2));
+        assert_eq!(key.suggestion.as_deref(), Some("ruleset"));
+
+        let loaded = load_from_dir_with_policy(temp_dir.path(), UnknownKeyPolicy::Warn)
+            .expect("unknown keys should only warn");
+        assert_eq!(loaded.warnings.len(), 1);
+        assert!(loaded.config.profile["default"].ruleset.is_empty());
+    }
+
+    #[test]
+    fn merges_extended_files_under_the_extending_config() {
+        let temp_dir = tempfile::tempdir().expect("tempdir should be created");
+        let shared = temp_dir.path().join("shared");
+        let project = temp_dir.path().join("project");
+        fs::create_dir_all(&shared).expect("shared dir should be created");
+        fs::create_dir_all(&project).expect("project dir should be created");
+        fs::write(
+            shared.join(CONFIG_FILE_PRIMARY),
+            "exclude = [\"vendor/\"]\n\n[profile.ci]\nextends = [\"aztec\"]\ndeny = [\"NOIR100\"]\n\n[aztec]\ncontract_attribute = \"contract\"\n\n[[overrides]]\npaths = [\"examples/\"]\nallow = [\"all\"]\n",
+        )
+        .expect("shared config should be written");
+        fs::write(
+            project.join(CONFIG_FILE_PRIMARY),
+            "extends = [\"../shared/aztec-lint.toml\"]\n\n[aztec]\nenqueue_fn = \"call\"\n\n[[overrides]]\npaths = [\"examples/\"]\ndeny = [\"NOIR100\"]\n",
+        )
+        .expect("project config should be written");
+
+        let loaded = load_from_dir(&project).expect("config should load");
+        let config = loaded.config;
+
+        assert_eq!(
+            loaded.files,
+            vec![
+                project.join(CONFIG_FILE_PRIMARY),
+                project.join("../shared/aztec-lint.toml"),
+            ]
+        );
+        assert_eq!(config.profile["ci"].deny, vec!["NOIR100".to_string()]);
+        assert_eq!(config.aztec.contract_attribute, "contract");
+        assert_eq!(config.aztec.enqueue_fn, "call");
+        assert_eq!(config.exclude, vec!["vendor/".to_string()]);
+        assert_eq!(config.overrides.len(), 2);
+        assert_eq!(config.overrides[1].deny, vec!["NOIR100".to_string()]);
+    }
+
+    #[test]
+    fn inherited_globs_resolve_against_the_loaded_config() {
+        let temp_dir = tempfile::tempdir().expect("tempdir should be created");
+        let shared = temp_dir.path().join("presets/shared");
+        let project = temp_dir.path().join("project");
+        fs::create_dir_all(&shared).expect("shared dir should be created");
+        fs::create_dir_all(&project).expect("project dir should be created");
+        fs::write(
+            shared.join(CONFIG_FILE_PRIMARY),
+            "include = [\"src/\"]\nexclude = [\"src/vendor/\"]\n\n[[overrides]]\npaths = [\"src/generated/\"]\nallow = [\"all\"]\n",
+        )
+        .expect("shared config should be written");
+        fs::write(
+            project.join(CONFIG_FILE_PRIMARY),
+            "extends = [\"../presets/shared/aztec-lint.toml\"]\n",
+        )
+        .expect("project config should be written");
+
+        let config = load_from_dir(&project).expect("config should load").config;
+        let filter = config.path_filter(&[]);
+        let overrides = config
+            .resolve_path_overrides()
+            .expect("overrides should resolve");
+
+        assert!(filter.includes_file("src/main.nr"));
+        assert!(filter.excludes("src/vendor/lib.nr"));
+        assert!(!filter.includes_file("../presets/shared/src/main.nr"));
+        assert_eq!(
+            overrides[0].matching_pattern("src/generated/types.nr"),
+            Some("src/generated/")
+        );
+        assert_eq!(
+            overrides[0].matching_pattern("../presets/shared/src/generated/types.nr"),
+            None
+        );
+    }
+
+    #[test]
+    fn resolves_extends_inside_nargo_dependencies() {
+        let temp_dir = tempfile::tempdir().expect("tempdir should be created");
+        let dependency = temp_dir.path().join("lint_presets");
+        let project = temp_dir.path().join("project");
+        fs::create_dir_all(dependency.join("presets")).expect("dependency should be created");
+        fs::create_dir_all(&project).expect("project dir should be created");
+        fs::write(
+            dependency.join("presets/strict.toml"),
+            "[profile.default]\nruleset = [\"aztec_pack\"]\n",
+        )
+        .expect("preset should be written");
+        fs::write(
+            project.join("Nargo.toml"),
+            "[package]\nname = \"project\"\ntype = \"contract\"\n\n[dependencies]\nlint_presets = { path = \"../lint_presets\" }\n",
+        )
+        .expect("manifest should be written");
+        fs::write(
+            project.join(CONFIG_FILE_PRIMARY),
+            "extends = [\"dep:lint_presets/presets/strict.toml\"]\n",
+        )
+        .expect("config should be written");
+
+        let config = load_from_dir(&project).expect("config should load").config;
+        assert_eq!(
+            config.profile["default"].ruleset,
+            vec!["aztec_pack".to_string()]
+        );
+
+        fs::write(
+            project.join(CONFIG_FILE_PRIMARY),
+            "extends = [\"dep:missing_presets\"]\n",
+        )
+        .expect("config should be rewritten");
+        let err = load_from_dir(&project).expect_err("undeclared dependency should fail");
+        assert!(
+            matches!(err, ConfigError::ExtendsDependencyNotFound { ref dependency, .. } if dependency == "missing_presets")
+        );
+    }
+
+    #[test]
+    fn rejects_missing_and_cyclic_extends() {
+        let temp_dir = tempfile::tempdir().expect("tempdir should be created");
+        let root = temp_dir.path();
+        fs::write(
+            root.join(CONFIG_FILE_PRIMARY),
+            "extends = [\"shared/aztec-lint.toml\"]\n",
+        )
+        .expect("config should be written");
+
+        let err = load_from_dir(root).expect_err("missing base should fail");
+        assert!(
+            matches!(err, ConfigError::ExtendsNotFound { ref extends, .. } if extends == "shared/aztec-lint.toml")
+        );
+        assert!(err.to_string().contains("does not exist"));
+
+        fs::create_dir_all(root.join("shared")).expect("shared dir should be created");
+        fs::write(
+            root.join("shared").join(CONFIG_FILE_PRIMARY),
+            "extends = [\"../aztec-lint.toml\"]\n",
+        )
+        .expect("shared config should be written");
+        let err = load_from_dir(root).expect_err("cycle should fail");
+        let ConfigError::ExtendsCycle { cycle } = &err else {
+            panic!("expected extends cycle, got {err}");
+        };
+        assert_eq!(cycle.len(), 3);
+        assert_eq!(cycle.first(), cycle.last());
+    }
 }
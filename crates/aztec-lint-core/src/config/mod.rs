@@ -2,15 +2,22 @@ use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
 
+pub mod extends;
+pub mod glob;
+pub mod keys;
 pub mod loader;
 pub mod types;
 
+pub use glob::{PathFilter, path_matches_glob};
+pub use keys::{UnknownConfigKey, UnknownKeyPolicy};
 pub use loader::{
     CONFIG_FILE_FALLBACK, CONFIG_FILE_PRIMARY, ConfigSource, LoadedConfig, load_from_dir,
+    load_from_dir_with_policy,
 };
 pub use types::{
-    AztecConfig, Config, DeprecatedPathConfig, DomainSeparationConfig, Profile, RawConfig,
-    ResolvedProfile, RuleLevel, RuleOverrides,
+    AztecConfig, Config, DeprecatedPathConfig, DomainSeparationConfig, PathOverride, Profile,
+    RawConfig, ResolvedPathOverride, ResolvedProfile, RuleLevel, RuleLevelSource, RuleLevelStep,
+    RuleOverrides,
 };
 
 #[derive(Debug)]
@@ -46,6 +53,32 @@ pub enum ConfigError {
         source: String,
         replacement: Option<String>,
     },
+    UnknownLintOption {
+        rule_id: String,
+        option: String,
+        known: Vec<String>,
+    },
+    InvalidLintOption {
+        rule_id: String,
+        option: String,
+        expected: &'static str,
+    },
+    UnknownKey(UnknownConfigKey),
+    EmptyOverridePaths {
+        index: usize,
+    },
+    ExtendsNotFound {
+        path: PathBuf,
+        extends: String,
+        resolved: PathBuf,
+    },
+    ExtendsDependencyNotFound {
+        path: PathBuf,
+        dependency: String,
+    },
+    ExtendsCycle {
+        cycle: Vec<PathBuf>,
+    },
 }
 
 impl Display for ConfigError {
@@ -107,6 +140,64 @@ impl Display for ConfigError {
                     )
                 }
             }
+            Self::UnknownLintOption {
+                rule_id,
+                option,
+                known,
+            } => {
+                if known.is_empty() {
+                    write!(
+                        f,
+                        "unknown option '{option}' in [lints.{rule_id}]; {rule_id} has no options"
+                    )
+                } else {
+                    write!(
+                        f,
+                        "unknown option '{option}' in [lints.{rule_id}] (expected one of: {})",
+                        known.join(", ")
+                    )
+                }
+            }
+            Self::InvalidLintOption {
+                rule_id,
+                option,
+                expected,
+            } => write!(
+                f,
+                "invalid value for '{option}' in [lints.{rule_id}]: expected {expected}"
+            ),
+            Self::UnknownKey(key) => write!(
+                f,
+                "{key} (pass `--config-unknown-keys=warn` to ignore unknown keys)"
+            ),
+            Self::EmptyOverridePaths { index } => write!(
+                f,
+                "[[overrides]] entry {index} needs a non-empty `paths` list of globs"
+            ),
+            Self::ExtendsNotFound {
+                path,
+                extends,
+                resolved,
+            } => write!(
+                f,
+                "config file '{}' extends '{extends}', but '{}' does not exist",
+                path.display(),
+                resolved.display()
+            ),
+            Self::ExtendsDependencyNotFound { path, dependency } => write!(
+                f,
+                "config file '{}' extends Nargo dependency '{dependency}', which is not declared in the Nargo.toml next to it",
+                path.display()
+            ),
+            Self::ExtendsCycle { cycle } => write!(
+                f,
+                "config extends cycle detected: {}",
+                cycle
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> ")
+            ),
         }
     }
 }
@@ -121,7 +212,14 @@ impl Error for ConfigError {
             | Self::ProfileCycle { .. }
             | Self::UnknownRuleset { .. }
             | Self::ConflictingRuleOverride { .. }
-            | Self::UnknownRuleId { .. } => None,
+            | Self::UnknownRuleId { .. }
+            | Self::UnknownLintOption { .. }
+            | Self::InvalidLintOption { .. }
+            | Self::UnknownKey(_)
+            | Self::EmptyOverridePaths { .. }
+            | Self::ExtendsNotFound { .. }
+            | Self::ExtendsDependencyNotFound { .. }
+            | Self::ExtendsCycle { .. } => None,
         }
     }
 }
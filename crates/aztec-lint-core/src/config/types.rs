@@ -4,16 +4,34 @@ use std::fmt::{Display, Formatter};
 use serde::{Deserialize, Serialize};
 
 use crate::config::ConfigError;
-use crate::lints::{LintLifecycleState, LintMaturityTier, LintSpec, all_lints};
+use crate::config::glob::{PathFilter, path_matches_glob};
+use crate::lints::{
+    LintGroup, LintLifecycleState, LintMaturityTier, LintOptionTable, LintSpec, all_lints,
+};
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct RawConfig {
+    /// Config files this one inherits from, lowest precedence first.
+    #[serde(default)]
+    pub extends: Vec<String>,
     #[serde(default)]
     pub profile: BTreeMap<String, Profile>,
     #[serde(default)]
     pub aztec: AztecConfig,
     #[serde(default)]
     pub deprecated_path: DeprecatedPathConfig,
+    /// Per-lint option tables, keyed by rule id (`[lints.NOIR110]`).
+    #[serde(default)]
+    pub lints: BTreeMap<String, LintOptionTable>,
+    /// Path-scoped `[[overrides]]` blocks, in file order.
+    #[serde(default)]
+    pub overrides: Vec<PathOverride>,
+    /// Globs of the only paths to lint; everything is linted when empty.
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Globs of paths never discovered or linted.
+    #[serde(default)]
+    pub exclude: Vec<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -21,6 +39,52 @@ pub struct Config {
     pub profile: BTreeMap<String, Profile>,
     pub aztec: AztecConfig,
     pub deprecated_path: DeprecatedPathConfig,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub lints: BTreeMap<String, LintOptionTable>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<PathOverride>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+}
+
+/// A `[[overrides]]` block: rule levels and lint options for files matching
+/// `paths`, which are globs relative to the directory holding the config file.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PathOverride {
+    #[serde(default)]
+    pub paths: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+    #[serde(default)]
+    pub warn: Vec<String>,
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub lints: BTreeMap<String, LintOptionTable>,
+}
+
+/// A `[[overrides]]` block with lint groups expanded and rule ids made
+/// canonical, ready to be matched against source paths.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedPathOverride {
+    /// 1-based position of the block in the config file.
+    pub index: usize,
+    pub paths: Vec<String>,
+    pub levels: BTreeMap<String, RuleLevel>,
+    pub lints: BTreeMap<String, LintOptionTable>,
+}
+
+impl ResolvedPathOverride {
+    /// The first of `paths` matching `path` (relative to the config
+    /// directory), if any.
+    pub fn matching_pattern(&self, path: &str) -> Option<&str> {
+        self.paths
+            .iter()
+            .find(|pattern| path_matches_glob(pattern, path))
+            .map(String::as_str)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -61,6 +125,35 @@ impl Display for RuleLevel {
     }
 }
 
+/// One decision that set a rule's level while resolving a profile.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RuleLevelStep {
+    pub level: RuleLevel,
+    pub source: RuleLevelSource,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RuleLevelSource {
+    /// Default level of a lint matched by a `ruleset` selector of `profile`.
+    Ruleset { profile: String, ruleset: String },
+    /// A `deny`/`warn`/`allow` entry of `profile`.
+    Profile { profile: String },
+    /// A `--deny`/`--warn`/`--allow` flag.
+    Cli,
+}
+
+impl Display for RuleLevelSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ruleset { profile, ruleset } => {
+                write!(f, "ruleset '{ruleset}' in profile '{profile}'")
+            }
+            Self::Profile { profile } => write!(f, "profile '{profile}' override"),
+            Self::Cli => write!(f, "command-line override"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct RuleOverrides {
     pub deny: Vec<String>,
@@ -158,6 +251,10 @@ impl Default for Config {
             profile: builtin_profiles(),
             aztec: AztecConfig::default(),
             deprecated_path: DeprecatedPathConfig::default(),
+            lints: BTreeMap::new(),
+            overrides: Vec::new(),
+            include: Vec::new(),
+            exclude: Vec::new(),
         }
     }
 }
@@ -172,9 +269,75 @@ impl Config {
             profile,
             aztec: raw.aztec,
             deprecated_path: raw.deprecated_path,
+            lints: normalize_lint_tables(raw.lints),
+            overrides: raw
+                .overrides
+                .into_iter()
+                .map(|path_override| PathOverride {
+                    lints: normalize_lint_tables(path_override.lints),
+                    ..path_override
+                })
+                .collect(),
+            include: raw.include,
+            exclude: raw.exclude,
+        }
+    }
+
+    /// The `include`/`exclude` globs, with `extra_exclude` (from `--exclude`)
+    /// appended to `exclude`.
+    pub fn path_filter(&self, extra_exclude: &[String]) -> PathFilter {
+        let mut exclude = self.exclude.clone();
+        append_unique(&mut exclude, extra_exclude);
+        PathFilter {
+            include: self.include.clone(),
+            exclude,
         }
     }
 
+    /// Checks every `[lints.<RULE_ID>]` table, including those nested in
+    /// `[[overrides]]`, against the option schema the lint declares in the
+    /// catalog.
+    pub fn validate_lint_options(&self) -> Result<(), ConfigError> {
+        validate_lint_tables(&self.lints)?;
+        for path_override in &self.overrides {
+            validate_lint_tables(&path_override.lints)?;
+        }
+        Ok(())
+    }
+
+    /// Resolves the `[[overrides]]` blocks, rejecting blocks without `paths`
+    /// and unknown rule ids the same way profile overrides do.
+    pub fn resolve_path_overrides(&self) -> Result<Vec<ResolvedPathOverride>, ConfigError> {
+        self.overrides
+            .iter()
+            .enumerate()
+            .map(|(position, path_override)| {
+                let index = position + 1;
+                if path_override
+                    .paths
+                    .iter()
+                    .all(|path| path.trim().is_empty())
+                {
+                    return Err(ConfigError::EmptyOverridePaths { index });
+                }
+                let levels = collect_rule_overrides(
+                    &RuleOverrides {
+                        deny: path_override.deny.clone(),
+                        warn: path_override.warn.clone(),
+                        allow: path_override.allow.clone(),
+                    },
+                    &|level| format!("[[overrides]] entry {index} {level}"),
+                )?;
+                Ok(ResolvedPathOverride {
+                    index,
+                    paths: path_override.paths.clone(),
+                    levels,
+                    lints: path_override.lints.clone(),
+                })
+            })
+            .collect()
+    }
+
     pub fn resolve_profile(&self, profile_name: &str) -> Result<ResolvedProfile, ConfigError> {
         if !self.profile.contains_key(profile_name) {
             return Err(ConfigError::ProfileNotFound {
@@ -196,41 +359,76 @@ impl Config {
         profile_name: &str,
         overrides: &RuleOverrides,
     ) -> Result<BTreeMap<String, RuleLevel>, ConfigError> {
+        let trace = self.rule_level_trace(profile_name, overrides)?;
+        Ok(trace
+            .into_iter()
+            .filter_map(|(rule_id, steps)| steps.last().map(|step| (rule_id, step.level)))
+            .collect())
+    }
+
+    /// Every step that set each rule's level, in the order they are applied:
+    /// ruleset defaults, profile overrides from parent to child, then CLI
+    /// overrides. The last step is the effective level.
+    pub fn rule_level_trace(
+        &self,
+        profile_name: &str,
+        overrides: &RuleOverrides,
+    ) -> Result<BTreeMap<String, Vec<RuleLevelStep>>, ConfigError> {
         let resolved = self.resolve_profile(profile_name)?;
         let profile_resolution_order = self.resolve_profile_order(profile_name)?;
-        let mut levels = BTreeMap::<String, RuleLevel>::new();
+        let mut trace = BTreeMap::<String, Vec<RuleLevelStep>>::new();
 
         for ruleset in &resolved.rulesets {
             let defaults = ruleset_defaults(ruleset).map_err(|()| ConfigError::UnknownRuleset {
                 ruleset: ruleset.clone(),
             })?;
+            // Rulesets are merged parent-first, so the first profile in
+            // resolution order that lists the selector is the one that added it.
+            let declaring_profile = profile_resolution_order
+                .iter()
+                .find(|name| {
+                    self.profile
+                        .get(*name)
+                        .is_some_and(|profile| profile.ruleset.contains(ruleset))
+                })
+                .map_or(profile_name, String::as_str);
             for (rule_id, level) in defaults {
-                levels.insert(rule_id.to_string(), level);
+                trace
+                    .entry(rule_id.to_string())
+                    .or_default()
+                    .push(RuleLevelStep {
+                        level,
+                        source: RuleLevelSource::Ruleset {
+                            profile: declaring_profile.to_string(),
+                            ruleset: ruleset.clone(),
+                        },
+                    });
             }
         }
 
-        for resolved_profile_name in profile_resolution_order {
-            let profile = self.profile.get(&resolved_profile_name).ok_or_else(|| {
+        for resolved_profile_name in &profile_resolution_order {
+            let profile = self.profile.get(resolved_profile_name).ok_or_else(|| {
                 ConfigError::ProfileNotFound {
                     profile: resolved_profile_name.clone(),
                 }
             })?;
             apply_rule_overrides(
-                &mut levels,
+                &mut trace,
                 &RuleOverrides {
                     deny: profile.deny.clone(),
                     warn: profile.warn.clone(),
                     allow: profile.allow.clone(),
                 },
-                RuleOverrideSource::Profile(&resolved_profile_name),
+                RuleOverrideSource::Profile(resolved_profile_name),
             )?;
         }
 
-        apply_rule_overrides(&mut levels, overrides, RuleOverrideSource::Cli)?;
-        Ok(levels)
+        apply_rule_overrides(&mut trace, overrides, RuleOverrideSource::Cli)?;
+        Ok(trace)
     }
 
-    fn resolve_profile_order(&self, profile_name: &str) -> Result<Vec<String>, ConfigError> {
+    /// Profiles whose overrides apply for `profile_name`, parents first.
+    pub fn resolve_profile_order(&self, profile_name: &str) -> Result<Vec<String>, ConfigError> {
         let mut stack = Vec::<String>::new();
         let mut cache = BTreeMap::<String, Vec<String>>::new();
         self.resolve_profile_order_inner(profile_name, &mut stack, &mut cache)
@@ -379,6 +577,48 @@ pub fn normalize_rule_id(rule_id: &str) -> String {
     rule_id.trim().to_ascii_uppercase()
 }
 
+fn normalize_lint_tables(
+    tables: BTreeMap<String, LintOptionTable>,
+) -> BTreeMap<String, LintOptionTable> {
+    tables
+        .into_iter()
+        .map(|(rule_id, options)| (normalize_rule_id(&rule_id), options))
+        .collect()
+}
+
+fn validate_lint_tables(tables: &BTreeMap<String, LintOptionTable>) -> Result<(), ConfigError> {
+    for (rule_id, options) in tables {
+        let canonical_rule_id = resolve_override_rule_id(rule_id).map_err(|replacement| {
+            ConfigError::UnknownRuleId {
+                rule_id: rule_id.clone(),
+                source: "[lints]".to_string(),
+                replacement: replacement.map(|rule_id| rule_id.to_string()),
+            }
+        })?;
+        let schema = all_lints()
+            .iter()
+            .find(|lint| lint.id == canonical_rule_id)
+            .map_or(&[][..], |lint| lint.options);
+        for (option, value) in options {
+            let Some(spec) = schema.iter().find(|spec| spec.name == option) else {
+                return Err(ConfigError::UnknownLintOption {
+                    rule_id: rule_id.clone(),
+                    option: option.clone(),
+                    known: schema.iter().map(|spec| spec.name.to_string()).collect(),
+                });
+            };
+            if !value.matches(spec.default) {
+                return Err(ConfigError::InvalidLintOption {
+                    rule_id: rule_id.clone(),
+                    option: option.clone(),
+                    expected: spec.default.expected(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
 fn append_unique(target: &mut Vec<String>, values: &[String]) {
     for value in values {
         if !target.contains(value) {
@@ -388,36 +628,83 @@ fn append_unique(target: &mut Vec<String>, values: &[String]) {
 }
 
 fn apply_rule_overrides(
-    levels: &mut BTreeMap<String, RuleLevel>,
+    trace: &mut BTreeMap<String, Vec<RuleLevelStep>>,
     overrides: &RuleOverrides,
     source: RuleOverrideSource<'_>,
 ) -> Result<(), ConfigError> {
-    let mut seen = BTreeMap::<String, RuleLevel>::new();
-    register_override(&mut seen, &overrides.allow, RuleLevel::Allow, source)?;
-    register_override(&mut seen, &overrides.warn, RuleLevel::Warn, source)?;
-    register_override(&mut seen, &overrides.deny, RuleLevel::Deny, source)?;
-
-    for (rule_id, level) in seen {
-        levels.insert(rule_id, level);
+    for (rule_id, level) in collect_rule_overrides(overrides, &|level| source.label_for(level))? {
+        trace.entry(rule_id).or_default().push(RuleLevelStep {
+            level,
+            source: source.level_source(),
+        });
     }
     Ok(())
 }
 
+/// Resolves one scope's `deny`/`warn`/`allow` lists to a level per canonical
+/// rule id; `source_label` names the scope in errors.
+fn collect_rule_overrides(
+    overrides: &RuleOverrides,
+    source_label: &dyn Fn(RuleLevel) -> String,
+) -> Result<BTreeMap<String, RuleLevel>, ConfigError> {
+    let mut seen = BTreeMap::<String, RuleLevel>::new();
+    let mut grouped = BTreeMap::<String, RuleLevel>::new();
+    register_override(
+        &mut seen,
+        &mut grouped,
+        &overrides.allow,
+        RuleLevel::Allow,
+        source_label,
+    )?;
+    register_override(
+        &mut seen,
+        &mut grouped,
+        &overrides.warn,
+        RuleLevel::Warn,
+        source_label,
+    )?;
+    register_override(
+        &mut seen,
+        &mut grouped,
+        &overrides.deny,
+        RuleLevel::Deny,
+        source_label,
+    )?;
+    // Within one scope an explicit rule id is more specific than a group.
+    for (rule_id, level) in grouped {
+        seen.entry(rule_id).or_insert(level);
+    }
+    Ok(seen)
+}
+
+/// Records explicit rule ids in `seen` and expands lint groups into
+/// `grouped`. Groups registered later (stricter levels) replace earlier ones,
+/// so `allow = ["all"]` with `deny = ["privacy"]` denies privacy lints.
 fn register_override(
     seen: &mut BTreeMap<String, RuleLevel>,
+    grouped: &mut BTreeMap<String, RuleLevel>,
     rules: &[String],
     requested: RuleLevel,
-    source: RuleOverrideSource<'_>,
+    source_label: &dyn Fn(RuleLevel) -> String,
 ) -> Result<(), ConfigError> {
     for rule in rules {
         let normalized = normalize_rule_id(rule);
-        let canonical_rule_id = resolve_override_rule_id(&normalized).map_err(|replacement| {
-            ConfigError::UnknownRuleId {
-                rule_id: normalized.clone(),
-                source: source.label_for(requested),
-                replacement: replacement.map(|rule_id| rule_id.to_string()),
+        let canonical_rule_id = match resolve_override_rule_id(&normalized) {
+            Ok(rule_id) => rule_id,
+            Err(replacement) => {
+                if let (None, Some(group)) = (replacement, LintGroup::parse(rule)) {
+                    for member in group.members() {
+                        grouped.insert(member.to_string(), requested);
+                    }
+                    continue;
+                }
+                return Err(ConfigError::UnknownRuleId {
+                    rule_id: normalized,
+                    source: source_label(requested),
+                    replacement: replacement.map(|rule_id| rule_id.to_string()),
+                });
             }
-        })?;
+        };
 
         if let Some(existing) = seen.get(canonical_rule_id) {
             if *existing != requested {
@@ -447,6 +734,15 @@ impl RuleOverrideSource<'_> {
             Self::Profile(profile_name) => format!("profile '{profile_name}' {level}"),
         }
     }
+
+    fn level_source(self) -> RuleLevelSource {
+        match self {
+            Self::Cli => RuleLevelSource::Cli,
+            Self::Profile(profile_name) => RuleLevelSource::Profile {
+                profile: profile_name.to_string(),
+            },
+        }
+    }
 }
 
 fn resolve_override_rule_id(rule_id: &str) -> Result<&'static str, Option<&'static str>> {
@@ -601,7 +897,7 @@ fn default_deprecated_path_verbose_blocked_notes() -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, RawConfig, RuleLevel, RuleOverrides};
+    use super::{Config, RawConfig, RuleLevel, RuleLevelSource, RuleLevelStep, RuleOverrides};
     use crate::config::ConfigError;
     use crate::diagnostics::Confidence;
     use crate::lints::{LintCategory, LintDocs, LintLifecycleState, LintMaturityTier, LintSpec};
@@ -889,6 +1185,95 @@ deny = ["NOIR001"]
         assert_eq!(levels.get("NOIR001"), Some(&RuleLevel::Allow));
     }
 
+    #[test]
+    fn lint_groups_expand_and_yield_to_explicit_rule_ids() {
+        let raw: RawConfig = toml::from_str(
+            r#"
+[profile.tests]
+extends = ["aztec"]
+allow = ["privacy", "AZTEC001"]
+deny = ["aztec::maintainability"]
+warn = ["AZTEC003"]
+"#,
+        )
+        .expect("config with lint groups must parse");
+        let config = Config::from_raw(raw);
+        let overrides = RuleOverrides {
+            deny: Vec::new(),
+            warn: vec!["Nursery".to_string()],
+            allow: Vec::new(),
+        };
+
+        let levels = config
+            .effective_rule_levels("tests", &overrides)
+            .expect("group overrides should resolve");
+
+        assert_eq!(levels.get("AZTEC001"), Some(&RuleLevel::Allow));
+        assert_eq!(levels.get("AZTEC002"), Some(&RuleLevel::Allow));
+        assert_eq!(levels.get("AZTEC003"), Some(&RuleLevel::Warn));
+        assert_eq!(levels.get("NOIR100"), Some(&RuleLevel::Deny));
+        assert_eq!(levels.get("NOIR001"), Some(&RuleLevel::Deny));
+    }
+
+    #[test]
+    fn rule_level_trace_records_each_decision_in_order() {
+        let raw: RawConfig = toml::from_str(
+            r#"
+[profile.ci]
+extends = ["aztec"]
+warn = ["NOIR001"]
+"#,
+        )
+        .expect("config with ci profile must parse");
+        let config = Config::from_raw(raw);
+        let overrides = RuleOverrides {
+            deny: vec!["noir001".to_string()],
+            warn: Vec::new(),
+            allow: Vec::new(),
+        };
+
+        let trace = config
+            .rule_level_trace("ci", &overrides)
+            .expect("trace should resolve");
+        let steps = trace.get("NOIR001").expect("NOIR001 should be traced");
+
+        assert_eq!(steps.len(), 3);
+        assert_eq!(
+            steps[0].source,
+            RuleLevelSource::Ruleset {
+                profile: "default".to_string(),
+                ruleset: "noir_core".to_string(),
+            }
+        );
+        assert_eq!(
+            steps[1],
+            RuleLevelStep {
+                level: RuleLevel::Warn,
+                source: RuleLevelSource::Profile {
+                    profile: "ci".to_string(),
+                },
+            }
+        );
+        assert_eq!(steps[2].level, RuleLevel::Deny);
+        assert_eq!(steps[2].source, RuleLevelSource::Cli);
+        assert_eq!(
+            trace
+                .get("AZTEC001")
+                .and_then(|steps| steps.last())
+                .map(|step| &step.source),
+            Some(&RuleLevelSource::Ruleset {
+                profile: "aztec".to_string(),
+                ruleset: "aztec_pack".to_string(),
+            })
+        );
+        assert_eq!(
+            config
+                .resolve_profile_order("ci")
+                .expect("order should resolve"),
+            vec!["default", "aztec", "ci"]
+        );
+    }
+
     #[test]
     fn unknown_profile_rule_overrides_are_rejected() {
         let raw: RawConfig = toml::from_str(
@@ -919,6 +1304,63 @@ deny = ["NOIR404"]
         }
     }
 
+    #[test]
+    fn path_overrides_resolve_groups_and_reject_invalid_blocks() {
+        let raw: RawConfig = toml::from_str(
+            r#"
+[[overrides]]
+paths = ["examples/"]
+allow = ["maintainability"]
+deny = ["noir120"]
+
+[overrides.lints.noir110]
+complexity_limit = 12
+"#,
+        )
+        .expect("config with overrides must parse");
+        let config = Config::from_raw(raw);
+        config
+            .validate_lint_options()
+            .expect("override lint options should validate");
+
+        let overrides = config
+            .resolve_path_overrides()
+            .expect("overrides should resolve");
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].index, 1);
+        assert_eq!(overrides[0].levels.get("NOIR110"), Some(&RuleLevel::Allow));
+        assert_eq!(overrides[0].levels.get("NOIR120"), Some(&RuleLevel::Deny));
+        assert!(overrides[0].lints.contains_key("NOIR110"));
+        assert_eq!(
+            overrides[0].matching_pattern("examples/demo/src/main.nr"),
+            Some("examples/")
+        );
+
+        let raw: RawConfig = toml::from_str("[[overrides]]\npaths = []\nallow = [\"all\"]\n")
+            .expect("config must parse");
+        let err = Config::from_raw(raw)
+            .resolve_path_overrides()
+            .expect_err("override without paths should fail");
+        assert!(matches!(err, ConfigError::EmptyOverridePaths { index: 1 }));
+
+        let raw: RawConfig = toml::from_str(
+            "[[overrides]]\npaths = [\"src/\"]\n\n[[overrides]]\npaths = [\"src/\"]\nwarn = [\"NOIR404\"]\n",
+        )
+        .expect("config must parse");
+        let err = Config::from_raw(raw)
+            .resolve_path_overrides()
+            .expect_err("unknown override rule should fail");
+        match err {
+            ConfigError::UnknownRuleId {
+                rule_id, source, ..
+            } => {
+                assert_eq!(rule_id, "NOIR404");
+                assert_eq!(source, "[[overrides]] entry 2 warn");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
     #[test]
     fn renamed_override_rule_suggests_replacement() {
         let catalog = [
@@ -932,6 +1374,8 @@ deny = ["NOIR404"]
                 default_level: RuleLevel::Deny,
                 confidence: Confidence::High,
                 lifecycle: LintLifecycleState::Active,
+                fix_applicability: None,
+                options: &[],
                 docs: LintDocs {
                     summary: "active",
                     what_it_does: "active",
@@ -955,6 +1399,8 @@ deny = ["NOIR404"]
                     since: "0.2.0",
                     to: "NOIR001",
                 },
+                fix_applicability: None,
+                options: &[],
                 docs: LintDocs {
                     summary: "renamed",
                     what_it_does: "renamed",
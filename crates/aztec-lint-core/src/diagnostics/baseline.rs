@@ -0,0 +1,457 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::diagnostics::fingerprint::{diagnostic_fingerprint, message_hash, normalize_file_path};
+use crate::diagnostics::types::Diagnostic;
+
+pub const BASELINE_VERSION: u32 = 1;
+
+/// Number of non-blank lines above and below the primary span line hashed
+/// into the context fingerprint used for fuzzy matching.
+const CONTEXT_RADIUS: u32 = 2;
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub entries: Vec<BaselineEntry>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub rule_id: String,
+    pub file: String,
+    pub line: u32,
+    pub message: String,
+    pub message_hash: String,
+    pub fingerprint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_hash: Option<String>,
+}
+
+/// Result of filtering a diagnostic set against a baseline.
+#[derive(Clone, Debug, Default)]
+pub struct BaselineMatch {
+    /// Diagnostics that are not covered by the baseline.
+    pub new_diagnostics: Vec<Diagnostic>,
+    /// Number of diagnostics that matched a baseline entry.
+    pub known: usize,
+    /// Baseline entries that no longer match any diagnostic.
+    pub fixed: Vec<BaselineEntry>,
+}
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    Serialize {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    UnsupportedVersion {
+        path: PathBuf,
+        version: u32,
+    },
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, source } => {
+                write!(
+                    f,
+                    "failed to access baseline '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::Parse { path, source } => {
+                write!(f, "failed to parse baseline '{}': {source}", path.display())
+            }
+            Self::Serialize { path, source } => {
+                write!(
+                    f,
+                    "failed to serialize baseline '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::UnsupportedVersion { path, version } => write!(
+                f,
+                "baseline '{}' has unsupported version {version} (expected {BASELINE_VERSION})",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for BaselineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Parse { source, .. } | Self::Serialize { source, .. } => Some(source),
+            Self::UnsupportedVersion { .. } => None,
+        }
+    }
+}
+
+impl Baseline {
+    /// Builds a baseline from unsuppressed diagnostics. File paths are resolved
+    /// against `source_root` to hash the code surrounding each finding.
+    pub fn from_diagnostics(source_root: &Path, diagnostics: &[Diagnostic]) -> Self {
+        let mut sources = SourceCache::new(source_root);
+        let mut entries = diagnostics
+            .iter()
+            .filter(|diagnostic| !diagnostic.suppressed)
+            .map(|diagnostic| BaselineEntry {
+                rule_id: diagnostic.rule_id.clone(),
+                file: normalize_file_path(&diagnostic.primary_span.file),
+                line: diagnostic.primary_span.line,
+                message: diagnostic.message.clone(),
+                message_hash: message_hash(&diagnostic.message),
+                fingerprint: diagnostic_fingerprint(diagnostic),
+                context_hash: sources.context_hash(diagnostic),
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by(|left, right| {
+            (&left.file, left.line, &left.rule_id, &left.message_hash).cmp(&(
+                &right.file,
+                right.line,
+                &right.rule_id,
+                &right.message_hash,
+            ))
+        });
+
+        Self {
+            version: BASELINE_VERSION,
+            entries,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        let contents = fs::read_to_string(path).map_err(|source| BaselineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let baseline =
+            serde_json::from_str::<Self>(&contents).map_err(|source| BaselineError::Parse {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        if baseline.version != BASELINE_VERSION {
+            return Err(BaselineError::UnsupportedVersion {
+                path: path.to_path_buf(),
+                version: baseline.version,
+            });
+        }
+        Ok(baseline)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), BaselineError> {
+        let rendered =
+            serde_json::to_string_pretty(self).map_err(|source| BaselineError::Serialize {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent).map_err(|source| BaselineError::Io {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        }
+        fs::write(path, format!("{rendered}\n")).map_err(|source| BaselineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Splits `diagnostics` into findings already recorded in the baseline and
+    /// new ones. Entries are first matched by exact fingerprint, then by rule
+    /// id, file, message hash and surrounding code so that findings survive
+    /// line shifts. Suppressed diagnostics are passed through untouched.
+    pub fn filter(&self, source_root: &Path, diagnostics: Vec<Diagnostic>) -> BaselineMatch {
+        let mut consumed = vec![false; self.entries.len()];
+        let mut matched = vec![false; diagnostics.len()];
+
+        let mut by_fingerprint = BTreeMap::<&str, Vec<usize>>::new();
+        for (index, entry) in self.entries.iter().enumerate() {
+            by_fingerprint
+                .entry(entry.fingerprint.as_str())
+                .or_default()
+                .push(index);
+        }
+        for (diagnostic_index, diagnostic) in diagnostics.iter().enumerate() {
+            if diagnostic.suppressed {
+                continue;
+            }
+            let fingerprint = diagnostic_fingerprint(diagnostic);
+            let Some(candidates) = by_fingerprint.get(fingerprint.as_str()) else {
+                continue;
+            };
+            let file = normalize_file_path(&diagnostic.primary_span.file);
+            let hash = message_hash(&diagnostic.message);
+            if let Some(&entry_index) = candidates.iter().find(|&&index| {
+                !consumed[index]
+                    && self.entries[index].file == file
+                    && self.entries[index].message_hash == hash
+            }) {
+                consumed[entry_index] = true;
+                matched[diagnostic_index] = true;
+            }
+        }
+
+        let mut sources = SourceCache::new(source_root);
+        for (diagnostic_index, diagnostic) in diagnostics.iter().enumerate() {
+            if diagnostic.suppressed || matched[diagnostic_index] {
+                continue;
+            }
+            let Some(context_hash) = sources.context_hash(diagnostic) else {
+                continue;
+            };
+            let file = normalize_file_path(&diagnostic.primary_span.file);
+            let hash = message_hash(&diagnostic.message);
+            let candidate = self
+                .entries
+                .iter()
+                .enumerate()
+                .filter(|(index, entry)| {
+                    !consumed[*index]
+                        && entry.rule_id == diagnostic.rule_id
+                        && entry.file == file
+                        && entry.message_hash == hash
+                        && entry.context_hash.as_deref() == Some(context_hash.as_str())
+                })
+                .min_by_key(|(_, entry)| entry.line.abs_diff(diagnostic.primary_span.line))
+                .map(|(index, _)| index);
+            if let Some(entry_index) = candidate {
+                consumed[entry_index] = true;
+                matched[diagnostic_index] = true;
+            }
+        }
+
+        let known = matched.iter().filter(|is_matched| **is_matched).count();
+        let new_diagnostics = diagnostics
+            .into_iter()
+            .zip(matched)
+            .filter(|(_, is_matched)| !is_matched)
+            .map(|(diagnostic, _)| diagnostic)
+            .collect();
+        let fixed = self
+            .entries
+            .iter()
+            .zip(consumed)
+            .filter(|(_, is_consumed)| !is_consumed)
+            .map(|(entry, _)| entry.clone())
+            .collect();
+
+        BaselineMatch {
+            new_diagnostics,
+            known,
+            fixed,
+        }
+    }
+}
+
+struct SourceCache<'a> {
+    root: &'a Path,
+    files: BTreeMap<String, Option<Vec<String>>>,
+}
+
+impl<'a> SourceCache<'a> {
+    fn new(root: &'a Path) -> Self {
+        Self {
+            root,
+            files: BTreeMap::new(),
+        }
+    }
+
+    fn context_hash(&mut self, diagnostic: &Diagnostic) -> Option<String> {
+        let file = normalize_file_path(&diagnostic.primary_span.file);
+        let root = self.root;
+        let lines = self
+            .files
+            .entry(file.clone())
+            .or_insert_with(|| {
+                fs::read_to_string(root.join(&file))
+                    .ok()
+                    .map(|text| text.lines().map(str::to_string).collect())
+            })
+            .as_ref()?;
+        context_hash(lines, diagnostic.primary_span.line)
+    }
+}
+
+fn context_hash(lines: &[String], line: u32) -> Option<String> {
+    if line == 0 || line as usize > lines.len() {
+        return None;
+    }
+    let index = line as usize - 1;
+    let radius = CONTEXT_RADIUS as usize;
+    let mut above = lines[..index]
+        .iter()
+        .rev()
+        .map(|line| normalize_context_line(line))
+        .filter(|line| !line.is_empty())
+        .take(radius)
+        .collect::<Vec<_>>();
+    above.reverse();
+    let below = lines[index + 1..]
+        .iter()
+        .map(|line| normalize_context_line(line))
+        .filter(|line| !line.is_empty())
+        .take(radius);
+
+    let window = above
+        .into_iter()
+        .chain(std::iter::once(normalize_context_line(&lines[index])))
+        .chain(below)
+        .collect::<Vec<_>>()
+        .join("\n");
+    Some(blake3::hash(window.as_bytes()).to_hex().to_string())
+}
+
+fn normalize_context_line(line: &str) -> String {
+    line.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use super::{BASELINE_VERSION, Baseline, BaselineError};
+    use crate::diagnostics::types::{Confidence, Diagnostic, Severity};
+    use crate::model::Span;
+
+    const SOURCE: &str = "fn main() {\n    let x = 42;\n    assert(x == 42);\n}\n";
+
+    fn diagnostic(start: u32, line: u32, message: &str) -> Diagnostic {
+        Diagnostic {
+            rule_id: "NOIR100".to_string(),
+            severity: Severity::Warning,
+            confidence: Confidence::Low,
+            policy: "maintainability".to_string(),
+            message: message.to_string(),
+            primary_span: Span::new("src/main.nr", start, start + 2, line, 13),
+            secondary_spans: Vec::new(),
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
+            structured_suggestions: Vec::new(),
+            suggestion_groups: Vec::new(),
+            fixes: Vec::new(),
+            suppressed: false,
+            suppression_reason: None,
+        }
+    }
+
+    #[test]
+    fn filters_diagnostics_recorded_in_baseline() {
+        let tmp = tempdir().expect("temp dir should be created");
+        fs::create_dir_all(tmp.path().join("src")).expect("src dir should be created");
+        fs::write(tmp.path().join("src/main.nr"), SOURCE).expect("source should be written");
+
+        let known = diagnostic(24, 2, "magic number 42");
+        let baseline = Baseline::from_diagnostics(tmp.path(), std::slice::from_ref(&known));
+        let fresh = diagnostic(44, 3, "magic number 42 in assertion");
+
+        let result = baseline.filter(tmp.path(), vec![known, fresh.clone()]);
+
+        assert_eq!(result.known, 1);
+        assert_eq!(result.new_diagnostics, vec![fresh]);
+        assert!(result.fixed.is_empty());
+    }
+
+    #[test]
+    fn matches_entries_after_line_shift() {
+        let tmp = tempdir().expect("temp dir should be created");
+        fs::create_dir_all(tmp.path().join("src")).expect("src dir should be created");
+        fs::write(tmp.path().join("src/main.nr"), SOURCE).expect("source should be written");
+        let baseline =
+            Baseline::from_diagnostics(tmp.path(), &[diagnostic(24, 2, "magic number 42")]);
+
+        let shifted = format!("\n\n{SOURCE}");
+        fs::write(tmp.path().join("src/main.nr"), shifted).expect("source should be rewritten");
+        let result = baseline.filter(tmp.path(), vec![diagnostic(26, 4, "magic number 42")]);
+
+        assert_eq!(result.known, 1);
+        assert!(result.new_diagnostics.is_empty());
+        assert!(result.fixed.is_empty());
+    }
+
+    #[test]
+    fn reports_entries_fixed_since_baseline() {
+        let tmp = tempdir().expect("temp dir should be created");
+        fs::create_dir_all(tmp.path().join("src")).expect("src dir should be created");
+        fs::write(tmp.path().join("src/main.nr"), SOURCE).expect("source should be written");
+        let baseline =
+            Baseline::from_diagnostics(tmp.path(), &[diagnostic(24, 2, "magic number 42")]);
+
+        let result = baseline.filter(tmp.path(), Vec::new());
+
+        assert_eq!(result.known, 0);
+        assert_eq!(result.fixed.len(), 1);
+        assert_eq!(result.fixed[0].rule_id, "NOIR100");
+        assert_eq!(result.fixed[0].line, 2);
+    }
+
+    #[test]
+    fn does_not_match_when_surrounding_code_changes() {
+        let tmp = tempdir().expect("temp dir should be created");
+        fs::create_dir_all(tmp.path().join("src")).expect("src dir should be created");
+        fs::write(tmp.path().join("src/main.nr"), SOURCE).expect("source should be written");
+        let baseline =
+            Baseline::from_diagnostics(tmp.path(), &[diagnostic(24, 2, "magic number 42")]);
+
+        fs::write(
+            tmp.path().join("src/main.nr"),
+            "fn main() {\n    let y = 1;\n    let x = 42;\n    assert(x == y);\n}\n",
+        )
+        .expect("source should be rewritten");
+        let result = baseline.filter(tmp.path(), vec![diagnostic(39, 3, "magic number 42")]);
+
+        assert_eq!(result.known, 0);
+        assert_eq!(result.new_diagnostics.len(), 1);
+        assert_eq!(result.fixed.len(), 1);
+    }
+
+    #[test]
+    fn round_trips_through_disk() {
+        let tmp = tempdir().expect("temp dir should be created");
+        let baseline =
+            Baseline::from_diagnostics(tmp.path(), &[diagnostic(24, 2, "magic number 42")]);
+        let path = tmp.path().join("nested/baseline.json");
+
+        baseline.write(&path).expect("baseline should be written");
+        let loaded = Baseline::load(&path).expect("baseline should load");
+
+        assert_eq!(loaded, baseline);
+        assert_eq!(loaded.version, BASELINE_VERSION);
+        assert!(loaded.entries[0].context_hash.is_none());
+    }
+
+    #[test]
+    fn rejects_unsupported_version() {
+        let tmp = tempdir().expect("temp dir should be created");
+        let path = tmp.path().join("baseline.json");
+        fs::write(&path, "{\"version\":99,\"entries\":[]}").expect("baseline should be written");
+
+        let err = Baseline::load(&path).expect_err("version should be rejected");
+
+        assert!(matches!(
+            err,
+            BaselineError::UnsupportedVersion { version: 99, .. }
+        ));
+    }
+}
@@ -1,7 +1,9 @@
+pub mod baseline;
 pub mod fingerprint;
 pub mod types;
 pub mod validate;
 
+pub use baseline::{BASELINE_VERSION, Baseline, BaselineEntry, BaselineError, BaselineMatch};
 pub use fingerprint::{
     diagnostic_fingerprint, message_hash, normalize_file_path, span_fingerprint,
 };
@@ -1,5 +1,6 @@
 #![forbid(unsafe_code)]
 
+pub mod cache;
 pub mod config;
 pub mod diagnostics;
 pub mod fix;
@@ -7,9 +8,11 @@ pub mod lints;
 pub mod model;
 pub mod noir;
 pub mod output;
+pub mod parallel;
 #[cfg(feature = "plugin-api")]
 pub mod plugin;
 pub mod policy;
+pub mod timings;
 pub mod vcs;
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,113 @@
+use crate::lints::{LintCategory, LintMaturityTier, LintSpec, all_lints};
+
+/// Tool prefix that marks a lint group in source directives, e.g.
+/// `#[allow(aztec::privacy)]`.
+pub const LINT_GROUP_TOOL_PREFIX: &str = "aztec::";
+
+/// Every group name accepted by [`LintGroup::parse`], in documentation order.
+pub const LINT_GROUP_NAMES: &[&str] = &[
+    "all",
+    "correctness",
+    "maintainability",
+    "privacy",
+    "protocol",
+    "soundness",
+    "pedantic",
+    "nursery",
+];
+
+/// A named set of lints usable wherever a single rule id is accepted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LintGroup {
+    /// Every active lint.
+    All,
+    /// Every active lint in one category.
+    Category(LintCategory),
+    /// Preview-tier lints: useful but noisier than the stable set.
+    Pedantic,
+    /// Experimental-tier lints that are still being tuned.
+    Nursery,
+}
+
+impl LintGroup {
+    /// Parses a group name case-insensitively, with or without the
+    /// `aztec::` tool prefix.
+    pub fn parse(value: &str) -> Option<Self> {
+        let trimmed = value.trim();
+        let name = trimmed
+            .strip_prefix(LINT_GROUP_TOOL_PREFIX)
+            .unwrap_or(trimmed)
+            .to_ascii_lowercase();
+        match name.as_str() {
+            "all" => Some(Self::All),
+            "pedantic" => Some(Self::Pedantic),
+            "nursery" => Some(Self::Nursery),
+            other => LintCategory::parse(other).map(Self::Category),
+        }
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Category(category) => category.as_str(),
+            Self::Pedantic => "pedantic",
+            Self::Nursery => "nursery",
+        }
+    }
+
+    pub fn matches(self, lint: &LintSpec) -> bool {
+        match self {
+            Self::All => true,
+            Self::Category(category) => lint.category == category,
+            Self::Pedantic => lint.maturity == LintMaturityTier::Preview,
+            Self::Nursery => lint.maturity == LintMaturityTier::Experimental,
+        }
+    }
+
+    /// Active lint ids in the group, sorted.
+    pub fn members(self) -> Vec<&'static str> {
+        let mut members = all_lints()
+            .iter()
+            .filter(|lint| lint.lifecycle.is_active() && self.matches(lint))
+            .map(|lint| lint.id)
+            .collect::<Vec<_>>();
+        members.sort_unstable();
+        members
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LINT_GROUP_NAMES, LintGroup};
+    use crate::lints::{LintCategory, all_lints};
+
+    #[test]
+    fn every_documented_group_name_parses() {
+        for name in LINT_GROUP_NAMES {
+            let group = LintGroup::parse(name).expect("documented group should parse");
+            assert_eq!(group.as_str(), *name);
+        }
+        assert_eq!(
+            LintGroup::parse(" aztec::Privacy "),
+            Some(LintGroup::Category(LintCategory::Privacy))
+        );
+        assert_eq!(LintGroup::parse("NOIR001"), None);
+    }
+
+    #[test]
+    fn group_members_follow_catalog_metadata() {
+        let privacy = LintGroup::Category(LintCategory::Privacy).members();
+        assert!(privacy.contains(&"AZTEC001"));
+        assert!(!privacy.contains(&"NOIR001"));
+
+        let pedantic = LintGroup::Pedantic.members();
+        assert!(pedantic.contains(&"NOIR101"));
+        assert!(!pedantic.contains(&"NOIR001"));
+
+        let active = all_lints()
+            .iter()
+            .filter(|lint| lint.lifecycle.is_active())
+            .count();
+        assert_eq!(LintGroup::All.members().len(), active);
+    }
+}
@@ -1,11 +1,15 @@
 use std::fmt::Write;
 
 use crate::config::RuleLevel;
-use crate::diagnostics::Confidence;
+use crate::diagnostics::{Applicability, Confidence};
 use crate::policy::{CORRECTNESS, MAINTAINABILITY, PRIVACY, PROTOCOL, SOUNDNESS};
 
+pub mod groups;
+pub mod options;
 pub mod types;
 
+pub use groups::{LINT_GROUP_NAMES, LINT_GROUP_TOOL_PREFIX, LintGroup};
+pub use options::{LintOptionDefault, LintOptionSpec, LintOptionTable, LintOptionValue};
 pub use types::{LintCategory, LintDocs, LintLifecycleState, LintMaturityTier, LintSpec};
 
 const INTRODUCED_IN_V0_1_0: &str = "0.1.0";
@@ -17,6 +21,34 @@ const DOCS_REFERENCE_SUPPRESSION: &str = "docs/suppression.md";
 const DOCS_REFERENCE_DECISION_0001: &str = "docs/decisions/0001-aztec010-scope.md";
 const DOCS_REFERENCE_DECISION_0003: &str = "docs/decisions/0003-confidence-model.md";
 
+const NOIR100_OPTIONS: &[LintOptionSpec] = &[
+    LintOptionSpec {
+        name: "allowed_literals",
+        default: LintOptionDefault::StringList(&["0", "1"]),
+        description: "Literals never reported as magic numbers (also used by NOIR101).",
+    },
+    LintOptionSpec {
+        name: "hash_domain_tags",
+        default: LintOptionDefault::StringList(&[
+            "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15",
+            "16", "32", "64", "128", "160", "192", "256", "512", "1024",
+        ]),
+        description: "Literals accepted as the leading tag of a `poseidon2_hash([...])` call.",
+    },
+];
+
+const NOIR110_OPTIONS: &[LintOptionSpec] = &[LintOptionSpec {
+    name: "complexity_limit",
+    default: LintOptionDefault::Integer(6),
+    description: "Highest number of branch and loop decision points a function may have.",
+}];
+
+const NOIR120_OPTIONS: &[LintOptionSpec] = &[LintOptionSpec {
+    name: "nesting_limit",
+    default: LintOptionDefault::Integer(3),
+    description: "Deepest block nesting a function body may reach.",
+}];
+
 const ALL_LINT_SPECS: &[LintSpec] = &[
     LintSpec {
         id: "AZTEC001",
@@ -28,6 +60,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Private data reaches a public sink.",
             what_it_does: "Flags flows where secret or note-derived values are emitted through public channels.",
@@ -48,6 +82,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::Low,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Secret-dependent branching affects public state.",
             what_it_does: "Detects control flow where secret inputs influence public behavior.",
@@ -68,6 +104,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Private entrypoint uses debug logging.",
             what_it_does: "Reports debug logging in private contexts where logging may leak sensitive state.",
@@ -88,6 +126,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::High,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Private to public bridge requires #[only_self].",
             what_it_does: "Checks enqueue-based private-to-public transitions enforce self-only invocation constraints.",
@@ -108,6 +148,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::High,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Unconstrained influence reaches commitments, storage, or nullifiers.",
             what_it_does: "Detects unconstrained values that affect constrained Aztec protocol artifacts.",
@@ -128,6 +170,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: Some(Applicability::MaybeIncorrect),
+        options: &[],
         docs: LintDocs {
             summary: "Missing range constraints before hashing or serialization.",
             what_it_does: "Reports values hashed or serialized without proving required numeric bounds first.",
@@ -148,6 +192,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Suspicious Merkle witness usage.",
             what_it_does: "Finds witness handling patterns that likely violate expected Merkle proof semantics.",
@@ -170,6 +216,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::High,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Note consumption without nullifier emission.",
             what_it_does: "Reports note pop/consume patterns when the same function does not emit a nullifier.",
@@ -192,6 +240,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Nullifier hash appears missing domain separation inputs.",
             what_it_does: "Flags nullifier hash call sites where required domain components are not present in hash inputs.",
@@ -214,6 +264,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Commitment hash appears missing domain separation inputs.",
             what_it_does: "Detects commitment-style hash sinks that do not include configured domain-separation components.",
@@ -236,6 +288,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::High,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Public entrypoint mutates private state without #[only_self].",
             what_it_does: "Reports public entrypoints that appear to mutate private note/state transitions and lack only-self protection.",
@@ -258,6 +312,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Hash input cast to Field without prior range guard.",
             what_it_does: "Finds hash inputs that are cast or converted to Field without an earlier range-style constraint.",
@@ -278,6 +334,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Suspicious repeated nested storage key.",
             what_it_does: "Flags `.at(x).at(x)`-style nested key repetition that often indicates copy-paste key mistakes.",
@@ -300,6 +358,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Secret-dependent branch affects enqueue behavior.",
             what_it_does: "Flags private or secret-influenced branching that changes whether or how enqueue-style bridge calls are emitted.",
@@ -322,6 +382,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Secret-dependent branch affects delivery count.",
             what_it_does: "Reports branch-dependent behavior where secret inputs influence the number or presence of delivery-style effects.",
@@ -342,6 +404,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::Low,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Change note appears to miss fresh randomness.",
             what_it_does: "Detects change-note construction patterns that appear to reuse deterministic randomness or omit freshness inputs.",
@@ -364,6 +428,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::Low,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Partial spend logic appears unbalanced.",
             what_it_does: "Flags partial-spend arithmetic patterns that do not clearly reconcile consumed, spent, and change values.",
@@ -386,6 +452,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::High,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Initializer entrypoint missing #[only_self].",
             what_it_does: "Reports initializer functions that are not protected by the expected only-self access restriction.",
@@ -408,6 +476,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Field/integer cast may truncate or wrap unexpectedly.",
             what_it_does: "Finds cast patterns between Field and bounded integers that lack nearby guard conditions proving safe range.",
@@ -430,6 +500,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::High,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: Some(Applicability::MachineApplicable),
+        options: &[],
         docs: LintDocs {
             summary: "Unused variable or import.",
             what_it_does: "Detects declared bindings and imports that are not used.",
@@ -450,6 +522,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Suspicious shadowing.",
             what_it_does: "Reports variable declarations that shadow earlier bindings in the same function scope.",
@@ -470,6 +544,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::High,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Boolean computed but not asserted.",
             what_it_does: "Flags boolean expressions that appear intended for checks but never drive an assertion.",
@@ -490,6 +566,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::High,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Array indexing without bounds validation.",
             what_it_does: "Detects index operations lacking an obvious preceding range constraint.",
@@ -510,6 +588,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Deny,
         confidence: Confidence::Medium,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: &[],
         docs: LintDocs {
             summary: "Unconstrained value influences constrained logic.",
             what_it_does: "Reports suspicious influence of unconstrained data over constrained computation paths.",
@@ -530,6 +610,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::High,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: Some(Applicability::MaybeIncorrect),
+        options: NOIR100_OPTIONS,
         docs: LintDocs {
             summary: "Magic number literal should be named.",
             what_it_does: "Detects high-signal numeric literals used in branch/assert/hash/serialization and related protocol-sensitive contexts.",
@@ -550,6 +632,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::Low,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: Some(Applicability::MaybeIncorrect),
+        options: &[],
         docs: LintDocs {
             summary: "Repeated local initializer magic number should be named.",
             what_it_does: "Reports repeated literal values used in plain local initializer assignments within the same function/module scope.",
@@ -570,6 +654,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::Low,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: NOIR110_OPTIONS,
         docs: LintDocs {
             summary: "Function complexity exceeds threshold.",
             what_it_does: "Flags functions whose control flow complexity passes the configured limit.",
@@ -590,6 +676,8 @@ const ALL_LINT_SPECS: &[LintSpec] = &[
         default_level: RuleLevel::Warn,
         confidence: Confidence::Low,
         lifecycle: LintLifecycleState::Active,
+        fix_applicability: None,
+        options: NOIR120_OPTIONS,
         docs: LintDocs {
             summary: "Function nesting depth exceeds threshold.",
             what_it_does: "Flags deeply nested control flow that reduces readability and maintainability.",
@@ -692,6 +780,49 @@ pub fn render_lints_reference_markdown() -> String {
     output
 }
 
+/// Markers delimiting the generated per-lint options table in
+/// `docs/configuration.md`.
+pub const LINT_OPTIONS_DOC_START: &str = "<!-- lint-options:start -->";
+pub const LINT_OPTIONS_DOC_END: &str = "<!-- lint-options:end -->";
+
+pub fn render_lint_options_markdown() -> String {
+    let mut output = String::new();
+    let _ = writeln!(output, "| Rule | Option | Type | Default | Description |");
+    let _ = writeln!(output, "|---|---|---|---|---|");
+    let mut lints = all_lints()
+        .iter()
+        .filter(|lint| lint.lifecycle.is_active() && !lint.options.is_empty())
+        .collect::<Vec<_>>();
+    lints.sort_unstable_by_key(|lint| lint.id);
+    for lint in lints {
+        for option in lint.options {
+            let _ = writeln!(
+                output,
+                "| `{}` | `{}` | `{}` | `{}` | {} |",
+                lint.id,
+                option.name,
+                option.default.type_name(),
+                option.default.value(),
+                option.description
+            );
+        }
+    }
+    output
+}
+
+/// Replaces the text between the lint-options markers in `doc` with freshly
+/// rendered content. Returns `None` when the markers are missing.
+pub fn splice_lint_options_markdown(doc: &str) -> Option<String> {
+    let start = doc.find(LINT_OPTIONS_DOC_START)? + LINT_OPTIONS_DOC_START.len();
+    let end = start + doc[start..].find(LINT_OPTIONS_DOC_END)?;
+    Some(format!(
+        "{}\n{}{}",
+        &doc[..start],
+        render_lint_options_markdown(),
+        &doc[end..]
+    ))
+}
+
 fn pack_heading(pack: &str) -> String {
     let mut words = pack
         .split('_')
@@ -867,7 +998,8 @@ mod tests {
 
     use super::{
         LintCategory, LintDocs, LintLifecycleState, LintMaturityTier, LintSpec, all_lints,
-        find_lint, render_lints_reference_markdown, validate_catalog_integrity,
+        find_lint, render_lints_reference_markdown, splice_lint_options_markdown,
+        validate_catalog_integrity,
     };
     use crate::config::RuleLevel;
     use crate::diagnostics::Confidence;
@@ -889,6 +1021,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn configuration_doc_lint_options_match_catalog() {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../docs/configuration.md");
+        let actual = fs::read_to_string(&path).expect("configuration doc should be readable");
+        let expected =
+            splice_lint_options_markdown(&actual).expect("lint options markers should exist");
+        assert_eq!(
+            actual, expected,
+            "docs/configuration.md lint options are out of date; run `cargo xtask update-lints`"
+        );
+    }
+
     #[test]
     fn find_lint_accepts_non_canonical_input() {
         let by_canonical = find_lint("NOIR100").expect("NOIR100 should exist");
@@ -913,6 +1057,8 @@ mod tests {
                 since: "0.3.0",
                 to: "NOIR404",
             },
+            fix_applicability: None,
+            options: &[],
             docs: sample_docs(),
         });
 
@@ -941,6 +1087,8 @@ mod tests {
                     replacement: Some("NOIR001"),
                     note: "",
                 },
+                fix_applicability: None,
+                options: &[],
                 docs: sample_docs(),
             },
             LintSpec {
@@ -956,6 +1104,8 @@ mod tests {
                     since: "",
                     note: "",
                 },
+                fix_applicability: None,
+                options: &[],
                 docs: sample_docs(),
             },
         ];
@@ -985,6 +1135,8 @@ mod tests {
                 replacement: Some("NOIR404"),
                 note: "use replacement",
             },
+            fix_applicability: None,
+            options: &[],
             docs: sample_docs(),
         });
 
@@ -1007,6 +1159,8 @@ mod tests {
             default_level: RuleLevel::Deny,
             confidence: Confidence::High,
             lifecycle: LintLifecycleState::Active,
+            fix_applicability: None,
+            options: &[],
             docs: sample_docs(),
         }
     }
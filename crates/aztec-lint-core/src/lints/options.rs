@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Options set for one lint in a `[lints.<RULE_ID>]` table, keyed by option name.
+pub type LintOptionTable = BTreeMap<String, LintOptionValue>;
+
+/// Type and default value of one per-lint option.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LintOptionDefault {
+    /// A non-negative integer.
+    Integer(u64),
+    /// An array of strings.
+    StringList(&'static [&'static str]),
+}
+
+impl LintOptionDefault {
+    pub const fn type_name(self) -> &'static str {
+        match self {
+            Self::Integer(_) => "integer",
+            Self::StringList(_) => "array<string>",
+        }
+    }
+
+    /// Phrase used in validation errors, e.g. "a non-negative integer".
+    pub const fn expected(self) -> &'static str {
+        match self {
+            Self::Integer(_) => "a non-negative integer",
+            Self::StringList(_) => "an array of strings",
+        }
+    }
+
+    pub fn value(self) -> LintOptionValue {
+        match self {
+            Self::Integer(value) => {
+                LintOptionValue::Integer(i64::try_from(value).unwrap_or(i64::MAX))
+            }
+            Self::StringList(values) => {
+                LintOptionValue::StringList(values.iter().map(|value| value.to_string()).collect())
+            }
+        }
+    }
+}
+
+/// One entry of a lint's option schema, declared in [`crate::lints::LintSpec::options`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LintOptionSpec {
+    pub name: &'static str,
+    pub default: LintOptionDefault,
+    pub description: &'static str,
+}
+
+/// A configured option value as read from `aztec-lint.toml`; displays in TOML
+/// syntax.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum LintOptionValue {
+    Integer(i64),
+    StringList(Vec<String>),
+    /// Any other TOML value, kept in TOML syntax so validation can reject it
+    /// against the lint's option schema instead of failing to parse.
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for LintOptionValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = toml::Value::deserialize(deserializer)?;
+        Ok(match value {
+            toml::Value::Integer(value) => Self::Integer(value),
+            toml::Value::Array(values) if values.iter().all(toml::Value::is_str) => {
+                Self::StringList(
+                    values
+                        .iter()
+                        .filter_map(|value| value.as_str().map(str::to_string))
+                        .collect(),
+                )
+            }
+            other => Self::Other(other.to_string()),
+        })
+    }
+}
+
+impl LintOptionValue {
+    /// Whether the value has the type declared by `default`.
+    pub fn matches(&self, default: LintOptionDefault) -> bool {
+        match (self, default) {
+            (Self::Integer(value), LintOptionDefault::Integer(_)) => *value >= 0,
+            (Self::StringList(_), LintOptionDefault::StringList(_)) => true,
+            _ => false,
+        }
+    }
+
+    pub fn as_usize(&self) -> Option<usize> {
+        match self {
+            Self::Integer(value) => usize::try_from(*value).ok(),
+            Self::StringList(_) | Self::Other(_) => None,
+        }
+    }
+
+    pub fn as_string_list(&self) -> Option<&[String]> {
+        match self {
+            Self::StringList(values) => Some(values),
+            Self::Integer(_) | Self::Other(_) => None,
+        }
+    }
+}
+
+impl Display for LintOptionValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::StringList(values) => {
+                let quoted = values
+                    .iter()
+                    .map(|value| format!("\"{value}\""))
+                    .collect::<Vec<_>>();
+                write!(f, "[{}]", quoted.join(", "))
+            }
+            Self::Other(value) => f.write_str(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LintOptionDefault, LintOptionValue};
+
+    #[test]
+    fn values_match_declared_types() {
+        let limit = LintOptionDefault::Integer(6);
+        assert!(LintOptionValue::Integer(8).matches(limit));
+        assert!(!LintOptionValue::Integer(-1).matches(limit));
+        assert!(!LintOptionValue::StringList(Vec::new()).matches(limit));
+        assert!(
+            LintOptionValue::StringList(Vec::new()).matches(LintOptionDefault::StringList(&[]))
+        );
+        assert!(!LintOptionValue::Other("\"8\"".to_string()).matches(limit));
+    }
+
+    #[test]
+    fn deserializes_values_of_any_toml_type() {
+        let table: toml::Table =
+            toml::from_str("limit = 4\nliterals = [\"0\"]\nquoted = \"4\"\nmixed = [1, \"a\"]\n")
+                .expect("toml should parse");
+        let value = |key: &str| {
+            table[key]
+                .clone()
+                .try_into::<LintOptionValue>()
+                .expect("any value should deserialize")
+        };
+
+        assert_eq!(value("limit"), LintOptionValue::Integer(4));
+        assert_eq!(
+            value("literals"),
+            LintOptionValue::StringList(vec!["0".to_string()])
+        );
+        assert_eq!(value("quoted"), LintOptionValue::Other("\"4\"".to_string()));
+        assert!(matches!(value("mixed"), LintOptionValue::Other(_)));
+    }
+
+    #[test]
+    fn defaults_render_as_toml() {
+        assert_eq!(LintOptionDefault::Integer(6).value().to_string(), "6");
+        assert_eq!(
+            LintOptionDefault::StringList(&["0", "1"])
+                .value()
+                .to_string(),
+            "[\"0\", \"1\"]"
+        );
+    }
+}
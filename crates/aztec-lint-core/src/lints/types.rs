@@ -1,5 +1,6 @@
 use crate::config::RuleLevel;
-use crate::diagnostics::Confidence;
+use crate::diagnostics::{Applicability, Confidence};
+use crate::lints::LintOptionSpec;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum LintCategory {
@@ -20,6 +21,17 @@ impl LintCategory {
             Self::Soundness => "soundness",
         }
     }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "correctness" => Some(Self::Correctness),
+            "maintainability" => Some(Self::Maintainability),
+            "privacy" => Some(Self::Privacy),
+            "protocol" => Some(Self::Protocol),
+            "soundness" => Some(Self::Soundness),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -71,6 +83,24 @@ impl LintLifecycleState {
         matches!(self, Self::Active)
     }
 
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Deprecated { .. } => "deprecated",
+            Self::Renamed { .. } => "renamed",
+            Self::Removed { .. } => "removed",
+        }
+    }
+
+    pub const fn since(self) -> Option<&'static str> {
+        match self {
+            Self::Active => None,
+            Self::Deprecated { since, .. }
+            | Self::Renamed { since, .. }
+            | Self::Removed { since, .. } => Some(since),
+        }
+    }
+
     pub const fn has_required_metadata(self) -> bool {
         match self {
             Self::Active => true,
@@ -126,5 +156,10 @@ pub struct LintSpec {
     pub default_level: RuleLevel,
     pub confidence: Confidence,
     pub lifecycle: LintLifecycleState,
+    /// Strongest applicability among the suggestion groups the lint emits;
+    /// `None` when it never suggests edits.
+    pub fix_applicability: Option<Applicability>,
+    /// Settings accepted in the lint's `[lints.<RULE_ID>]` config table.
+    pub options: &'static [LintOptionSpec],
     pub docs: LintDocs,
 }
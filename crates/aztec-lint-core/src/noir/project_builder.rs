@@ -3,6 +3,7 @@ use std::path::Path;
 use crate::config::DeprecatedPathConfig;
 use crate::model::{ProjectModel, SemanticModel};
 use crate::noir::NoirFrontendError;
+use crate::timings::Timings;
 
 #[cfg(feature = "noir-compiler")]
 use crate::model::CallEdge;
@@ -19,6 +20,8 @@ use crate::noir::semantic_builder::extract_semantic_model;
 #[cfg(feature = "noir-compiler")]
 use crate::noir::span_mapper::SpanMapper;
 #[cfg(feature = "noir-compiler")]
+use crate::timings::{FRONTEND_PHASE, SEMANTIC_EXTRACTION_PHASE};
+#[cfg(feature = "noir-compiler")]
 use noirc_frontend::hir::def_map::LocalModuleId;
 #[cfg(feature = "noir-compiler")]
 use noirc_frontend::parser::ItemKind;
@@ -62,8 +65,29 @@ pub fn build_project_semantic_bundle_with_options(
     entry: &Path,
     deprecated_path: DeprecatedPathConfig,
 ) -> Result<ProjectSemanticBundle, NoirFrontendError> {
-    let checked = load_and_check_project_with_options(root, entry, deprecated_path)?;
-    build_bundle_from_checked(&checked)
+    build_project_semantic_bundle_with_timings(
+        root,
+        entry,
+        deprecated_path,
+        &mut Timings::default(),
+    )
+}
+
+/// Same as [`build_project_semantic_bundle_with_options`], additionally recording
+/// frontend checking and semantic extraction wall time into `timings`.
+#[cfg(feature = "noir-compiler")]
+pub fn build_project_semantic_bundle_with_timings(
+    root: &Path,
+    entry: &Path,
+    deprecated_path: DeprecatedPathConfig,
+    timings: &mut Timings,
+) -> Result<ProjectSemanticBundle, NoirFrontendError> {
+    let checked = timings.time(FRONTEND_PHASE, || {
+        load_and_check_project_with_options(root, entry, deprecated_path)
+    })?;
+    timings.time(SEMANTIC_EXTRACTION_PHASE, || {
+        build_bundle_from_checked(&checked)
+    })
 }
 
 #[cfg(not(feature = "noir-compiler"))]
@@ -88,6 +112,16 @@ pub fn build_project_semantic_bundle_with_options(
     Err(NoirFrontendError::CompilerFeatureDisabled)
 }
 
+#[cfg(not(feature = "noir-compiler"))]
+pub fn build_project_semantic_bundle_with_timings(
+    root: &Path,
+    entry: &Path,
+    deprecated_path: DeprecatedPathConfig,
+    _timings: &mut Timings,
+) -> Result<ProjectSemanticBundle, NoirFrontendError> {
+    build_project_semantic_bundle_with_options(root, entry, deprecated_path)
+}
+
 #[cfg(feature = "noir-compiler")]
 fn build_bundle_from_checked(
     checked: &NoirCheckedProject,
@@ -18,6 +18,11 @@ impl Colorizer {
         }
     }
 
+    /// A colorizer that never emits escape codes, for reports written to files.
+    pub fn disabled() -> Self {
+        Self { enabled: false }
+    }
+
     pub fn error(self, text: &str) -> String {
         self.style(text, "1;31")
     }
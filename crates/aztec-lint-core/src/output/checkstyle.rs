@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::diagnostics::{Diagnostic, Severity, diagnostic_sort_key};
+use crate::output::junit::escape_xml;
+use crate::output::sarif::repository_relative_uri;
+
+/// Renders diagnostics as Checkstyle XML: one `<file>` element per source
+/// file, ordered by path, with one `<error>` per unsuppressed diagnostic.
+/// The `source` attribute carries the rule id as `aztec-lint.<RULE_ID>`.
+pub fn render_diagnostics(repo_root: &Path, diagnostics: &[&Diagnostic]) -> String {
+    let mut files = BTreeMap::<String, Vec<&Diagnostic>>::new();
+    for diagnostic in diagnostics
+        .iter()
+        .filter(|diagnostic| !diagnostic.suppressed)
+    {
+        files
+            .entry(repository_relative_uri(
+                repo_root,
+                &diagnostic.primary_span.file,
+            ))
+            .or_default()
+            .push(diagnostic);
+    }
+
+    let mut output = String::new();
+    let _ = writeln!(output, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(output, r#"<checkstyle version="4.3">"#);
+    for (file, mut entries) in files {
+        entries.sort_by_key(|diagnostic| diagnostic_sort_key(diagnostic));
+        let _ = writeln!(output, r#"  <file name="{}">"#, escape_xml(&file));
+        for diagnostic in entries {
+            let _ = writeln!(
+                output,
+                r#"    <error line="{}" column="{}" severity="{}" message="{}" source="aztec-lint.{}"/>"#,
+                diagnostic.primary_span.line,
+                diagnostic.primary_span.col,
+                checkstyle_severity(diagnostic.severity),
+                escape_xml(&diagnostic.message),
+                escape_xml(&diagnostic.rule_id),
+            );
+        }
+        let _ = writeln!(output, "  </file>");
+    }
+    let _ = writeln!(output, "</checkstyle>");
+    output
+}
+
+fn checkstyle_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::render_diagnostics;
+    use crate::diagnostics::{Confidence, Diagnostic, Severity};
+    use crate::model::Span;
+
+    fn diagnostic(rule_id: &str, file: &str, start: u32, line: u32, message: &str) -> Diagnostic {
+        Diagnostic {
+            rule_id: rule_id.to_string(),
+            severity: Severity::Warning,
+            confidence: Confidence::Medium,
+            policy: "privacy".to_string(),
+            message: message.to_string(),
+            primary_span: Span::new(file, start, start + 1, line, 2),
+            secondary_spans: Vec::new(),
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
+            structured_suggestions: Vec::new(),
+            suggestion_groups: Vec::new(),
+            fixes: Vec::new(),
+            suppressed: false,
+            suppression_reason: None,
+        }
+    }
+
+    #[test]
+    fn checkstyle_output_groups_errors_by_file() {
+        let root = Path::new("/repo");
+        let mut leak = diagnostic(
+            "AZTEC001",
+            "/repo/src/main.nr",
+            10,
+            3,
+            "a \"secret\" & more",
+        );
+        leak.severity = Severity::Error;
+        let magic = diagnostic("NOIR100", "src/lib.nr", 4, 1, "magic number");
+        let mut suppressed = diagnostic("NOIR100", "src/main.nr", 2, 1, "magic number");
+        suppressed.suppressed = true;
+
+        let rendered = render_diagnostics(root, &[&suppressed, &leak, &magic]);
+
+        assert_eq!(
+            rendered,
+            concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+                "<checkstyle version=\"4.3\">\n",
+                "  <file name=\"src/lib.nr\">\n",
+                "    <error line=\"1\" column=\"2\" severity=\"warning\" message=\"magic number\" source=\"aztec-lint.NOIR100\"/>\n",
+                "  </file>\n",
+                "  <file name=\"src/main.nr\">\n",
+                "    <error line=\"3\" column=\"2\" severity=\"error\" message=\"a &quot;secret&quot; &amp; more\" source=\"aztec-lint.AZTEC001\"/>\n",
+                "  </file>\n",
+                "</checkstyle>\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::diagnostics::{Diagnostic, Severity, diagnostic_sort_key, normalize_file_path};
+use crate::model::Span;
+
+/// Renders diagnostics as GitHub Actions workflow commands
+/// (`::error file=...,line=...::message`), which the runner turns into
+/// pull request annotations.
+///
+/// File paths are emitted as given, so callers should rebase them onto the
+/// repository root first; `source_root` is where those paths are read from to
+/// place the end of each annotation. Suppressed diagnostics are omitted.
+pub fn render_diagnostics(source_root: &Path, diagnostics: &[&Diagnostic]) -> String {
+    let mut sorted = diagnostics
+        .iter()
+        .filter(|diagnostic| !diagnostic.suppressed)
+        .copied()
+        .collect::<Vec<_>>();
+    sorted.sort_by_key(|diagnostic| diagnostic_sort_key(diagnostic));
+
+    let mut sources = HashMap::<&str, Option<String>>::new();
+    let mut output = String::new();
+    for diagnostic in sorted {
+        let span = &diagnostic.primary_span;
+        let text = sources
+            .entry(span.file.as_str())
+            .or_insert_with(|| fs::read_to_string(source_root.join(&span.file)).ok());
+        let _ = writeln!(
+            output,
+            "::{} file={},{},title={}::{}",
+            command_for_severity(diagnostic.severity),
+            escape_property(&normalize_file_path(&span.file)),
+            location_properties(span, text.as_deref()),
+            escape_property(&diagnostic.rule_id),
+            escape_data(&annotation_message(diagnostic)),
+        );
+    }
+    output
+}
+
+/// `line`/`col`/`endLine`/`endColumn` properties for `span`, with columns
+/// counted in characters. The runner only accepts columns on single-line
+/// annotations, so spans crossing lines carry `endLine` alone, and without
+/// the source only the start position is emitted.
+fn location_properties(span: &Span, text: Option<&str>) -> String {
+    let Some(text) = text else {
+        return format!("line={},col={}", span.line, span.col);
+    };
+    let (line, col) = char_position(text, span.start as usize);
+    let (end_line, end_col) = char_position(text, span.end as usize);
+    if end_line == line {
+        format!("line={line},col={col},endLine={end_line},endColumn={end_col}")
+    } else {
+        format!("line={line},endLine={end_line}")
+    }
+}
+
+/// 1-based line and character column of byte `offset` in `text`.
+fn char_position(text: &str, offset: usize) -> (usize, usize) {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+fn annotation_message(diagnostic: &Diagnostic) -> String {
+    let mut message = diagnostic.message.clone();
+    for help in &diagnostic.helps {
+        let _ = write!(message, "\nhelp: {}", help.message);
+    }
+    for suggestion in &diagnostic.suggestions {
+        let _ = write!(message, "\nhelp: {suggestion}");
+    }
+    message
+}
+
+fn command_for_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+/// Escapes a workflow command message, following the runner's own encoding.
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes a workflow command property value, which additionally cannot
+/// contain the `:` and `,` delimiters.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use tempfile::tempdir;
+
+    use super::render_diagnostics;
+    use crate::diagnostics::{Confidence, Diagnostic, Severity, StructuredMessage};
+    use crate::model::Span;
+
+    fn diagnostic(rule_id: &str, file: &str, start: u32, line: u32, message: &str) -> Diagnostic {
+        Diagnostic {
+            rule_id: rule_id.to_string(),
+            severity: Severity::Warning,
+            confidence: Confidence::Medium,
+            policy: "privacy".to_string(),
+            message: message.to_string(),
+            primary_span: Span::new(file, start, start + 3, line, 2),
+            secondary_spans: Vec::new(),
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
+            structured_suggestions: Vec::new(),
+            suggestion_groups: Vec::new(),
+            fixes: Vec::new(),
+            suppressed: false,
+            suppression_reason: None,
+        }
+    }
+
+    #[test]
+    fn github_output_emits_escaped_workflow_commands() {
+        let mut leak = diagnostic("AZTEC001", "./src/main.nr", 10, 3, "100% leaked");
+        leak.severity = Severity::Error;
+        leak.helps = vec![StructuredMessage {
+            message: "hash it first".to_string(),
+            span: None,
+        }];
+        let warning = diagnostic("NOIR100", "src/a,b.nr", 4, 1, "magic number");
+        let mut suppressed = diagnostic("NOIR001", "src/main.nr", 1, 1, "unused");
+        suppressed.suppressed = true;
+
+        let root = tempdir().expect("temp dir should be created");
+        fs::create_dir_all(root.path().join("src")).expect("src dir should be created");
+        fs::write(root.path().join("src/main.nr"), "abc\nefgh\n let x = 1;\n")
+            .expect("source should be written");
+
+        let rendered = render_diagnostics(root.path(), &[&suppressed, &leak, &warning]);
+
+        assert_eq!(
+            rendered,
+            "::warning file=src/a%2Cb.nr,line=1,col=2,title=NOIR100::magic number\n\
+             ::error file=src/main.nr,line=3,col=2,endLine=3,endColumn=5,title=AZTEC001::100%25 leaked%0Ahelp: hash it first\n"
+        );
+    }
+
+    #[test]
+    fn github_output_places_end_of_multiline_and_non_ascii_spans() {
+        let root = tempdir().expect("temp dir should be created");
+        fs::write(
+            root.path().join("main.nr"),
+            "fn main() {\n    let s = \"é\"; let n = 42;\n    assert(\n        n == 42\n    );\n}\n",
+        )
+        .expect("source should be written");
+        let source = fs::read_to_string(root.path().join("main.nr")).expect("source should load");
+        let literal = u32::try_from(source.find("42").expect("literal")).expect("offset");
+        let assert = u32::try_from(source.find("assert").expect("assert")).expect("offset");
+        let end = u32::try_from(source.find(");").expect("end")).expect("offset") + 2;
+
+        let mut literal_diagnostic = diagnostic("NOIR100", "main.nr", 0, 2, "magic number");
+        literal_diagnostic.primary_span = Span::new("main.nr", literal, literal + 2, 2, 27);
+        let mut assert_diagnostic = diagnostic("NOIR010", "main.nr", 0, 3, "assert");
+        assert_diagnostic.primary_span = Span::new("main.nr", assert, end, 3, 5);
+
+        assert_eq!(
+            render_diagnostics(root.path(), &[&literal_diagnostic, &assert_diagnostic]),
+            "::warning file=main.nr,line=2,col=26,endLine=2,endColumn=28,title=NOIR100::magic number\n\
+             ::warning file=main.nr,line=3,endLine=5,title=NOIR010::assert\n"
+        );
+    }
+
+    #[test]
+    fn github_output_is_deterministic_across_reordered_input() {
+        let first = diagnostic("AZTEC001", "src/a.nr", 10, 1, "first");
+        let second = diagnostic("AZTEC020", "src/b.nr", 11, 2, "second");
+
+        assert_eq!(
+            render_diagnostics(Path::new("."), &[&second, &first]),
+            render_diagnostics(Path::new("."), &[&first, &second])
+        );
+    }
+}
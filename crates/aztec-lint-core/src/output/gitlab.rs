@@ -0,0 +1,180 @@
+use std::path::Path;
+
+use serde_json::{Value, json};
+
+use crate::diagnostics::{
+    Confidence, Diagnostic, Severity, diagnostic_fingerprint, diagnostic_sort_key,
+};
+use crate::lints::find_lint;
+use crate::output::sarif::repository_relative_uri;
+
+/// Renders diagnostics as a GitLab Code Quality report (a Code Climate style
+/// JSON array). Suppressed diagnostics are omitted because GitLab has no way
+/// to display them as anything other than open findings.
+pub fn render_diagnostics(
+    repo_root: &Path,
+    diagnostics: &[&Diagnostic],
+) -> Result<String, serde_json::Error> {
+    let mut sorted = diagnostics
+        .iter()
+        .filter(|diagnostic| !diagnostic.suppressed)
+        .copied()
+        .collect::<Vec<_>>();
+    sorted.sort_by_key(|diagnostic| diagnostic_sort_key(diagnostic));
+
+    let issues = sorted
+        .into_iter()
+        .map(|diagnostic| render_issue(repo_root, diagnostic))
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&issues)
+}
+
+fn render_issue(repo_root: &Path, diagnostic: &Diagnostic) -> Value {
+    let span = &diagnostic.primary_span;
+    json!({
+        "description": diagnostic.message,
+        "check_name": diagnostic.rule_id,
+        "fingerprint": diagnostic_fingerprint(diagnostic),
+        "severity": gitlab_severity(diagnostic.severity, diagnostic.confidence),
+        "categories": [code_climate_category(diagnostic)],
+        "location": {
+            "path": repository_relative_uri(repo_root, &span.file),
+            "lines": {
+                "begin": span.line,
+                "end": span.line,
+            }
+        }
+    })
+}
+
+/// Maps the lint category onto the fixed set of Code Climate categories
+/// (`Bug Risk`, `Clarity`, `Compatibility`, `Complexity`, `Duplication`,
+/// `Performance`, `Security`, `Style`).
+fn code_climate_category(diagnostic: &Diagnostic) -> &'static str {
+    let category = find_lint(&diagnostic.rule_id)
+        .map(|lint| lint.category.as_str())
+        .unwrap_or(diagnostic.policy.as_str());
+    match category {
+        "privacy" | "soundness" => "Security",
+        "correctness" | "protocol" => "Bug Risk",
+        _ => "Clarity",
+    }
+}
+
+/// Maps severity and confidence onto GitLab's five-level scale: confident
+/// errors are `critical`, while low-confidence warnings are only `info`.
+fn gitlab_severity(severity: Severity, confidence: Confidence) -> &'static str {
+    match (severity, confidence) {
+        (Severity::Error, Confidence::High) => "critical",
+        (Severity::Error, Confidence::Medium) | (Severity::Warning, Confidence::High) => "major",
+        (Severity::Error, Confidence::Low) | (Severity::Warning, Confidence::Medium) => "minor",
+        (Severity::Warning, Confidence::Low) => "info",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use serde_json::{Value, json};
+
+    use super::{gitlab_severity, render_diagnostics};
+    use crate::diagnostics::{Confidence, Diagnostic, Severity, diagnostic_fingerprint};
+    use crate::model::Span;
+
+    fn diagnostic(rule_id: &str, file: &str, start: u32, line: u32, message: &str) -> Diagnostic {
+        Diagnostic {
+            rule_id: rule_id.to_string(),
+            severity: Severity::Warning,
+            confidence: Confidence::Medium,
+            policy: "privacy".to_string(),
+            message: message.to_string(),
+            primary_span: Span::new(file, start, start + 1, line, 2),
+            secondary_spans: Vec::new(),
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
+            structured_suggestions: Vec::new(),
+            suggestion_groups: Vec::new(),
+            fixes: Vec::new(),
+            suppressed: false,
+            suppression_reason: None,
+        }
+    }
+
+    #[test]
+    fn gitlab_output_matches_code_quality_schema() {
+        let root = Path::new("/repo");
+        let mut issue = diagnostic("AZTEC001", "/repo/src/main.nr", 10, 3, "secret leaks");
+        issue.severity = Severity::Error;
+        issue.confidence = Confidence::High;
+        let mut suppressed = diagnostic("NOIR100", "src/lib.nr", 4, 1, "magic number");
+        suppressed.suppressed = true;
+
+        let rendered =
+            render_diagnostics(root, &[&suppressed, &issue]).expect("gitlab render should pass");
+        let value: Value = serde_json::from_str(&rendered).expect("report should parse");
+
+        assert_eq!(
+            value,
+            json!([{
+                "description": "secret leaks",
+                "check_name": "AZTEC001",
+                "fingerprint": diagnostic_fingerprint(&issue),
+                "severity": "critical",
+                "categories": ["Security"],
+                "location": {
+                    "path": "src/main.nr",
+                    "lines": { "begin": 3, "end": 3 }
+                }
+            }])
+        );
+    }
+
+    #[test]
+    fn gitlab_output_is_deterministic_across_reordered_input() {
+        let root = Path::new("/repo");
+        let first = diagnostic("AZTEC001", "src/a.nr", 10, 1, "first");
+        let second = diagnostic("AZTEC020", "src/b.nr", 11, 2, "second");
+
+        let left = render_diagnostics(root, &[&second, &first]).expect("left render should pass");
+        let right = render_diagnostics(root, &[&first, &second]).expect("right render should pass");
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn gitlab_categories_use_code_climate_names() {
+        let root = Path::new("/repo");
+        let unused = diagnostic("NOIR001", "src/a.nr", 1, 1, "unused");
+        let magic = diagnostic("NOIR100", "src/a.nr", 2, 2, "magic number");
+        let mut unknown = diagnostic("CUSTOM001", "src/a.nr", 3, 3, "custom");
+        unknown.policy = "protocol".to_string();
+
+        let rendered = render_diagnostics(root, &[&unused, &magic, &unknown])
+            .expect("gitlab render should pass");
+        let value: Value = serde_json::from_str(&rendered).expect("report should parse");
+        let categories = value
+            .as_array()
+            .expect("report should be an array")
+            .iter()
+            .map(|issue| issue["categories"][0].as_str().expect("category"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(categories, vec!["Bug Risk", "Clarity", "Bug Risk"]);
+    }
+
+    #[test]
+    fn gitlab_severity_scales_with_confidence() {
+        assert_eq!(
+            gitlab_severity(Severity::Error, Confidence::High),
+            "critical"
+        );
+        assert_eq!(gitlab_severity(Severity::Error, Confidence::Low), "minor");
+        assert_eq!(
+            gitlab_severity(Severity::Warning, Confidence::High),
+            "major"
+        );
+        assert_eq!(gitlab_severity(Severity::Warning, Confidence::Low), "info");
+    }
+}
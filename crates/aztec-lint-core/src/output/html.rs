@@ -0,0 +1,665 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use crate::diagnostics::{
+    Confidence, Diagnostic, Severity, diagnostic_sort_key, normalize_file_path,
+};
+use crate::lints::{LintSpec, find_lint};
+use crate::model::Span;
+use crate::output::junit::escape_xml;
+
+const UNCATEGORIZED: &str = "uncategorized";
+const SNIPPET_CONTEXT_LINES: usize = 2;
+
+const STYLE: &str = r#"
+:root { --error: #b42318; --warning: #b54708; --muted: #667085; --border: #d0d5dd; --code: #f8f9fb; }
+body { font: 14px/1.5 system-ui, -apple-system, "Segoe UI", sans-serif; margin: 0; color: #101828; }
+header, main { max-width: 1100px; margin: 0 auto; padding: 16px 24px; }
+header { border-bottom: 1px solid var(--border); }
+h1 { margin: 0 0 4px; font-size: 22px; }
+h2 { margin-top: 32px; border-bottom: 1px solid var(--border); padding-bottom: 4px; }
+h3 { margin: 20px 0 8px; color: var(--muted); text-transform: capitalize; }
+h4 { margin: 12px 0 4px; font-size: 14px; }
+.meta, .location, .empty { color: var(--muted); }
+.counts span { margin-right: 16px; }
+.filters label { margin-right: 12px; }
+.diagnostic { border: 1px solid var(--border); border-left-width: 4px; border-radius: 4px; margin: 12px 0; padding: 8px 12px; }
+.diagnostic.error { border-left-color: var(--error); }
+.diagnostic.warning { border-left-color: var(--warning); }
+.diagnostic.suppressed { border-left-color: var(--muted); opacity: 0.6; }
+.badge { display: inline-block; border-radius: 3px; padding: 0 6px; font-size: 12px; font-weight: 600; color: #fff; }
+.badge.error { background: var(--error); }
+.badge.warning { background: var(--warning); }
+.badge.suppressed { background: var(--muted); }
+pre { background: var(--code); border: 1px solid var(--border); border-radius: 4px; padding: 8px; overflow-x: auto; margin: 6px 0; }
+.gutter { color: var(--muted); user-select: none; }
+mark.primary { background: #fecdca; }
+mark.secondary { background: #fef0c7; }
+ul.annotations { margin: 6px 0; padding-left: 20px; }
+.rule-docs dt { font-weight: 600; margin-top: 8px; }
+.rule-docs dd { margin-left: 0; }
+.hidden { display: none; }
+"#;
+
+const SCRIPT: &str = r#"
+var filters = document.querySelectorAll('.filters input');
+function applyFilters() {
+  var hidden = [];
+  filters.forEach(function (input) { if (!input.checked) { hidden.push(input.value); } });
+  document.querySelectorAll('.diagnostic').forEach(function (node) {
+    var hide = hidden.some(function (name) { return node.classList.contains(name); });
+    node.classList.toggle('hidden', hide);
+  });
+}
+filters.forEach(function (input) { input.addEventListener('change', applyFilters); });
+"#;
+
+pub struct HtmlReport<'a> {
+    pub path: &'a Path,
+    pub source_root: &'a Path,
+    pub profile: &'a str,
+    pub diagnostics: &'a [&'a Diagnostic],
+}
+
+/// Renders a self-contained HTML audit report. Diagnostics are grouped by
+/// contract, lint category, and severity, with source snippets and the
+/// documentation of every triggered rule. Styles and scripts are inlined so
+/// the file works offline.
+pub fn render_html_report(report: HtmlReport<'_>) -> String {
+    let mut diagnostics = report.diagnostics.to_vec();
+    diagnostics.sort_by_key(|diagnostic| diagnostic_sort_key(diagnostic));
+    let mut sources = SourceCache::new(report.source_root);
+
+    let mut groups = BTreeMap::<GroupKey, Vec<&Diagnostic>>::new();
+    for diagnostic in &diagnostics {
+        let key = GroupKey {
+            contract: ContractKey(sources.enclosing_contract(&diagnostic.primary_span)),
+            category: category_label(&diagnostic.rule_id),
+            severity: severity_order(diagnostic.severity),
+        };
+        groups.entry(key).or_default().push(diagnostic);
+    }
+
+    let mut output = String::new();
+    let _ = writeln!(output, "<!DOCTYPE html>");
+    let _ = writeln!(output, "<html lang=\"en\">");
+    let _ = writeln!(output, "<head>");
+    let _ = writeln!(output, "<meta charset=\"utf-8\">");
+    let _ = writeln!(output, "<title>aztec-lint report</title>");
+    let _ = writeln!(output, "<style>{STYLE}</style>");
+    let _ = writeln!(output, "</head>");
+    let _ = writeln!(output, "<body>");
+    render_header(&mut output, &report, &diagnostics);
+
+    let _ = writeln!(output, "<main>");
+    if diagnostics.is_empty() {
+        let _ = writeln!(output, "<p class=\"empty\">No diagnostics.</p>");
+    }
+    let mut current_contract = None::<&ContractKey>;
+    let mut current_category = None::<&str>;
+    for (key, group) in &groups {
+        if current_contract != Some(&key.contract) {
+            let _ = writeln!(output, "<h2>{}</h2>", escape_xml(&key.contract.label()));
+            current_contract = Some(&key.contract);
+            current_category = None;
+        }
+        if current_category != Some(key.category) {
+            let _ = writeln!(output, "<h3>{}</h3>", escape_xml(key.category));
+            current_category = Some(key.category);
+        }
+        let _ = writeln!(
+            output,
+            "<h4>{} ({})</h4>",
+            severity_heading(key.severity),
+            group.len()
+        );
+        for diagnostic in group {
+            render_diagnostic(&mut output, &mut sources, diagnostic);
+        }
+    }
+    render_rule_docs(&mut output, &diagnostics);
+    let _ = writeln!(output, "</main>");
+    let _ = writeln!(output, "<script>{SCRIPT}</script>");
+    let _ = writeln!(output, "</body>");
+    let _ = writeln!(output, "</html>");
+    output
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct GroupKey {
+    contract: ContractKey,
+    category: &'static str,
+    severity: u8,
+}
+
+/// Diagnostics inside a contract sort before those outside of any contract.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ContractKey(Option<String>);
+
+impl ContractKey {
+    fn label(&self) -> String {
+        match &self.0 {
+            Some(name) => format!("contract {name}"),
+            None => "Outside of contracts".to_string(),
+        }
+    }
+}
+
+impl Ord for ContractKey {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.0.is_none(), &self.0).cmp(&(other.0.is_none(), &other.0))
+    }
+}
+
+impl PartialOrd for ContractKey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn render_header(output: &mut String, report: &HtmlReport<'_>, diagnostics: &[&Diagnostic]) {
+    let suppressed = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.suppressed)
+        .count();
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| !diagnostic.suppressed && diagnostic.severity == Severity::Error)
+        .count();
+    let warnings = diagnostics.len() - suppressed - errors;
+
+    let _ = writeln!(output, "<header>");
+    let _ = writeln!(output, "<h1>aztec-lint report</h1>");
+    let _ = writeln!(
+        output,
+        "<div class=\"meta\">checked={} profile={} version={}</div>",
+        escape_xml(&report.path.display().to_string()),
+        escape_xml(report.profile),
+        crate::VERSION
+    );
+    let _ = writeln!(
+        output,
+        "<div class=\"counts\"><span>errors={errors}</span><span>warnings={warnings}</span><span>suppressed={suppressed}</span></div>"
+    );
+    let _ = writeln!(output, "<div class=\"filters\">");
+    for class in ["error", "warning", "suppressed"] {
+        let _ = writeln!(
+            output,
+            "<label><input type=\"checkbox\" value=\"{class}\" checked> {class}</label>"
+        );
+    }
+    let _ = writeln!(output, "</div>");
+    let _ = writeln!(output, "</header>");
+}
+
+fn render_diagnostic(output: &mut String, sources: &mut SourceCache<'_>, diagnostic: &Diagnostic) {
+    let diagnostic = diagnostic
+        .clone()
+        .with_legacy_fields_from_suggestion_groups();
+    let severity = severity_label(diagnostic.severity);
+    // Suppressed findings only carry the `suppressed` filter class, so the
+    // severity checkboxes don't hide them.
+    let filter_class = if diagnostic.suppressed {
+        "suppressed"
+    } else {
+        severity
+    };
+    let span = &diagnostic.primary_span;
+
+    let _ = writeln!(output, "<section class=\"diagnostic {filter_class}\">");
+    let _ = write!(
+        output,
+        "<div><span class=\"badge {severity}\">{severity}</span> <a href=\"#rule-{id}\">{id}</a>: {}",
+        escape_xml(&diagnostic.message),
+        id = escape_xml(&diagnostic.rule_id),
+    );
+    if diagnostic.suppressed {
+        let reason = diagnostic
+            .suppression_reason
+            .as_deref()
+            .unwrap_or("suppressed");
+        let _ = write!(
+            output,
+            " <span class=\"badge suppressed\" title=\"{}\">suppressed</span>",
+            escape_xml(reason)
+        );
+    }
+    let _ = writeln!(output, "</div>");
+    let _ = writeln!(
+        output,
+        "<div class=\"location\">{}:{}:{} &middot; confidence={} &middot; policy={}</div>",
+        escape_xml(&normalize_file_path(&span.file)),
+        span.line,
+        span.col,
+        confidence_label(diagnostic.confidence),
+        escape_xml(&diagnostic.policy)
+    );
+    render_snippet(output, sources, span, &diagnostic.secondary_spans);
+    for secondary in &diagnostic.secondary_spans {
+        if !sources.snippet_contains(span, secondary) {
+            let _ = writeln!(
+                output,
+                "<div class=\"location\">related: {}:{}:{}</div>",
+                escape_xml(&normalize_file_path(&secondary.file)),
+                secondary.line,
+                secondary.col
+            );
+            render_snippet(output, sources, secondary, &[]);
+        }
+    }
+
+    let mut annotations = Vec::<String>::new();
+    for note in &diagnostic.notes {
+        annotations.push(format!("<b>note:</b> {}", escape_xml(&note.message)));
+    }
+    for help in &diagnostic.helps {
+        annotations.push(format!("<b>help:</b> {}", escape_xml(&help.message)));
+    }
+    for suggestion in &diagnostic.suggestions {
+        annotations.push(format!("<b>help:</b> {}", escape_xml(suggestion)));
+    }
+    if !annotations.is_empty() {
+        let _ = writeln!(output, "<ul class=\"annotations\">");
+        for annotation in annotations {
+            let _ = writeln!(output, "<li>{annotation}</li>");
+        }
+        let _ = writeln!(output, "</ul>");
+    }
+    let _ = writeln!(output, "</section>");
+}
+
+/// Renders the lines around `primary`, highlighting it and any `secondary`
+/// spans that fall inside the same window.
+fn render_snippet(
+    output: &mut String,
+    sources: &mut SourceCache<'_>,
+    primary: &Span,
+    secondary: &[Span],
+) {
+    let Some(lines) = sources.lines(&primary.file) else {
+        return;
+    };
+    let Some(primary_index) = line_index(primary.line).filter(|index| *index < lines.len()) else {
+        return;
+    };
+    let first = primary_index.saturating_sub(SNIPPET_CONTEXT_LINES);
+    let last = (primary_index + SNIPPET_CONTEXT_LINES).min(lines.len() - 1);
+    let gutter_width = (last + 1).to_string().len();
+
+    let _ = writeln!(output, "<pre>");
+    for (index, text) in lines.iter().enumerate().take(last + 1).skip(first) {
+        let mut highlights = Vec::<(usize, usize, &str)>::new();
+        if index == primary_index {
+            highlights.push((primary.col as usize, span_width(primary), "primary"));
+        }
+        for span in secondary {
+            if same_file(span, primary) && line_index(span.line) == Some(index) {
+                highlights.push((span.col as usize, span_width(span), "secondary"));
+            }
+        }
+        let _ = writeln!(
+            output,
+            "<span class=\"gutter\">{:>gutter_width$} | </span>{}",
+            index + 1,
+            highlight_line(text, &highlights)
+        );
+    }
+    let _ = writeln!(output, "</pre>");
+}
+
+/// Wraps the 1-based column ranges in `highlights` in `<mark>` elements.
+/// Overlapping ranges keep the first highlight that claims a character.
+fn highlight_line(text: &str, highlights: &[(usize, usize, &str)]) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut classes = vec![None::<&str>; chars.len()];
+    for (col, width, class) in highlights {
+        let start = col.saturating_sub(1).min(chars.len());
+        let end = (start + (*width).max(1)).min(chars.len());
+        for slot in &mut classes[start..end] {
+            slot.get_or_insert(class);
+        }
+    }
+
+    let mut rendered = String::new();
+    let mut open = None::<&str>;
+    for (ch, class) in chars.iter().zip(&classes) {
+        if *class != open {
+            if open.is_some() {
+                rendered.push_str("</mark>");
+            }
+            if let Some(class) = class {
+                let _ = write!(rendered, "<mark class=\"{class}\">");
+            }
+            open = *class;
+        }
+        rendered.push_str(&escape_xml(&ch.to_string()));
+    }
+    if open.is_some() {
+        rendered.push_str("</mark>");
+    }
+    rendered
+}
+
+fn render_rule_docs(output: &mut String, diagnostics: &[&Diagnostic]) {
+    let rule_ids = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.rule_id.as_str())
+        .collect::<BTreeSet<_>>();
+    if rule_ids.is_empty() {
+        return;
+    }
+
+    let _ = writeln!(output, "<h2>Rules</h2>");
+    for rule_id in rule_ids {
+        let _ = writeln!(
+            output,
+            "<section class=\"rule-docs\" id=\"rule-{id}\"><h3>{id}</h3>",
+            id = escape_xml(rule_id)
+        );
+        match find_lint(rule_id) {
+            Some(lint) => render_lint_docs(output, lint),
+            None => {
+                let _ = writeln!(output, "<p class=\"empty\">No documentation available.</p>");
+            }
+        }
+        let _ = writeln!(output, "</section>");
+    }
+}
+
+fn render_lint_docs(output: &mut String, lint: &LintSpec) {
+    let docs = lint.docs;
+    let _ = writeln!(
+        output,
+        "<p>{} <span class=\"meta\">(pack={} category={} maturity={})</span></p>",
+        escape_xml(docs.summary),
+        escape_xml(lint.pack),
+        lint.category.as_str(),
+        lint.maturity.as_str()
+    );
+    let _ = writeln!(output, "<dl>");
+    for (title, body) in [
+        ("What it does", docs.what_it_does),
+        ("Why this matters", docs.why_this_matters),
+        ("How to fix", docs.how_to_fix),
+        ("Known limitations", docs.known_limitations),
+    ] {
+        let _ = writeln!(output, "<dt>{title}</dt><dd>{}</dd>", escape_xml(body));
+    }
+    if !docs.references.is_empty() {
+        let references = docs
+            .references
+            .iter()
+            .map(|reference| escape_xml(reference))
+            .collect::<Vec<_>>();
+        let _ = writeln!(
+            output,
+            "<dt>References</dt><dd>{}</dd>",
+            references.join(", ")
+        );
+    }
+    let _ = writeln!(output, "</dl>");
+}
+
+struct SourceCache<'a> {
+    source_root: &'a Path,
+    files: HashMap<String, Option<Vec<String>>>,
+}
+
+impl<'a> SourceCache<'a> {
+    fn new(source_root: &'a Path) -> Self {
+        Self {
+            source_root,
+            files: HashMap::new(),
+        }
+    }
+
+    fn lines(&mut self, file: &str) -> Option<&Vec<String>> {
+        let source_root = self.source_root;
+        self.files
+            .entry(file.to_string())
+            .or_insert_with(|| {
+                let contents = fs::read_to_string(source_root.join(file)).ok()?;
+                Some(contents.lines().map(str::to_string).collect())
+            })
+            .as_ref()
+    }
+
+    /// Name of the last `contract` declaration at or before `span` in its file.
+    fn enclosing_contract(&mut self, span: &Span) -> Option<String> {
+        let lines = self.lines(&span.file)?;
+        let end = line_index(span.line)?.min(lines.len().saturating_sub(1));
+        lines
+            .iter()
+            .take(end + 1)
+            .rev()
+            .find_map(|line| contract_name(line))
+    }
+
+    fn snippet_contains(&mut self, primary: &Span, span: &Span) -> bool {
+        let (Some(primary_index), Some(index)) = (line_index(primary.line), line_index(span.line))
+        else {
+            return false;
+        };
+        same_file(primary, span)
+            && self.lines(&primary.file).is_some()
+            && primary_index.abs_diff(index) <= SNIPPET_CONTEXT_LINES
+    }
+}
+
+fn contract_name(line: &str) -> Option<String> {
+    let mut code = line.trim_start();
+    while let Some(attribute) = code.strip_prefix("#[") {
+        code = attribute.split_once(']')?.1.trim_start();
+    }
+    let code = code.strip_prefix("pub ").unwrap_or(code).trim_start();
+    let rest = code.strip_prefix("contract")?;
+    if !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let name = rest
+        .trim_start()
+        .split(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+        .next()?;
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+fn line_index(line: u32) -> Option<usize> {
+    usize::try_from(line.checked_sub(1)?).ok()
+}
+
+fn span_width(span: &Span) -> usize {
+    span.end.saturating_sub(span.start) as usize
+}
+
+fn same_file(left: &Span, right: &Span) -> bool {
+    normalize_file_path(&left.file) == normalize_file_path(&right.file)
+}
+
+fn category_label(rule_id: &str) -> &'static str {
+    find_lint(rule_id)
+        .map(|lint| lint.category.as_str())
+        .unwrap_or(UNCATEGORIZED)
+}
+
+fn severity_order(severity: Severity) -> u8 {
+    match severity {
+        Severity::Error => 0,
+        Severity::Warning => 1,
+    }
+}
+
+fn severity_heading(order: u8) -> &'static str {
+    if order == severity_order(Severity::Error) {
+        "Errors"
+    } else {
+        "Warnings"
+    }
+}
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+fn confidence_label(confidence: Confidence) -> &'static str {
+    match confidence {
+        Confidence::Low => "low",
+        Confidence::Medium => "medium",
+        Confidence::High => "high",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use tempfile::tempdir;
+
+    use super::{HtmlReport, contract_name, highlight_line, render_html_report};
+    use crate::diagnostics::{Confidence, Diagnostic, Severity, StructuredMessage};
+    use crate::model::Span;
+
+    fn diagnostic(rule_id: &str, file: &str, span: Span, message: &str) -> Diagnostic {
+        Diagnostic {
+            rule_id: rule_id.to_string(),
+            severity: Severity::Warning,
+            confidence: Confidence::Medium,
+            policy: "privacy".to_string(),
+            message: message.to_string(),
+            primary_span: Span {
+                file: file.to_string(),
+                ..span
+            },
+            secondary_spans: Vec::new(),
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
+            structured_suggestions: Vec::new(),
+            suggestion_groups: Vec::new(),
+            fixes: Vec::new(),
+            suppressed: false,
+            suppression_reason: None,
+        }
+    }
+
+    #[test]
+    fn html_report_groups_by_contract_category_and_severity() {
+        let tmp = tempdir().expect("temp dir should be created");
+        fs::create_dir_all(tmp.path().join("src")).expect("src dir should be created");
+        fs::write(
+            tmp.path().join("src/main.nr"),
+            "use dep::aztec::macros::aztec;\n\n#[aztec]\npub contract Token {\n    fn leak(secret: Field) {\n        emit(secret);\n    }\n}\n",
+        )
+        .expect("source should be written");
+        fs::write(
+            tmp.path().join("src/lib.nr"),
+            "fn helper() { let x = 42; }\n",
+        )
+        .expect("source should be written");
+
+        let mut leak = diagnostic(
+            "AZTEC001",
+            "src/main.nr",
+            Span::new("", 80, 92, 6, 9),
+            "secret <value> reaches a public sink",
+        );
+        leak.severity = Severity::Error;
+        leak.secondary_spans = vec![Span::new("src/main.nr", 54, 60, 5, 13)];
+        leak.helps = vec![StructuredMessage {
+            message: "hash the value first".to_string(),
+            span: None,
+        }];
+        let magic = diagnostic(
+            "NOIR100",
+            "src/lib.nr",
+            Span::new("", 22, 24, 1, 23),
+            "magic number",
+        );
+
+        let mut allowed = diagnostic(
+            "NOIR100",
+            "src/lib.nr",
+            Span::new("", 22, 24, 1, 23),
+            "allowed magic number",
+        );
+        allowed.suppressed = true;
+        allowed.suppression_reason = Some("allow(NOIR100) at 'src/lib.nr'".to_string());
+
+        let rendered = render_html_report(HtmlReport {
+            path: tmp.path(),
+            source_root: tmp.path(),
+            profile: "aztec",
+            diagnostics: &[&magic, &leak, &allowed],
+        });
+
+        assert!(rendered.starts_with("<!DOCTYPE html>"));
+        assert!(rendered.contains("<style>") && rendered.contains("<script>"));
+        assert!(!rendered.contains("<link") && !rendered.contains("src=\"http"));
+        let token = rendered
+            .find("<h2>contract Token</h2>")
+            .expect("contract section");
+        let outside = rendered
+            .find("<h2>Outside of contracts</h2>")
+            .expect("outside section");
+        assert!(token < outside);
+        assert!(rendered.contains("<h3>privacy</h3>"));
+        assert!(rendered.contains("<h4>Errors (1)</h4>"));
+        assert!(rendered.contains("secret &lt;value&gt; reaches a public sink"));
+        assert!(rendered.contains("        <mark class=\"primary\">emit(secret)</mark>;"));
+        assert!(rendered.contains("<mark class=\"secondary\">secret</mark>"));
+        assert!(rendered.contains("<b>help:</b> hash the value first"));
+        assert!(rendered.contains("id=\"rule-AZTEC001\""));
+        assert!(rendered.contains("Why this matters"));
+        assert_eq!(
+            rendered
+                .matches("<section class=\"diagnostic suppressed\">")
+                .count(),
+            1
+        );
+        assert_eq!(
+            rendered
+                .matches("<section class=\"diagnostic warning\">")
+                .count(),
+            1
+        );
+        assert!(rendered.contains("title=\"allow(NOIR100) at &apos;src/lib.nr&apos;\""));
+    }
+
+    #[test]
+    fn html_report_is_deterministic_across_reordered_input() {
+        let first = diagnostic("AZTEC001", "src/a.nr", Span::new("", 10, 11, 1, 2), "first");
+        let second = diagnostic("NOIR100", "src/b.nr", Span::new("", 11, 12, 2, 2), "second");
+        let render = |diagnostics: &[&Diagnostic]| {
+            render_html_report(HtmlReport {
+                path: Path::new("/repo"),
+                source_root: Path::new("/repo"),
+                profile: "default",
+                diagnostics,
+            })
+        };
+
+        assert_eq!(render(&[&second, &first]), render(&[&first, &second]));
+    }
+
+    #[test]
+    fn detects_contract_declarations_and_highlights_columns() {
+        assert_eq!(
+            contract_name("pub contract Token {"),
+            Some("Token".to_string())
+        );
+        assert_eq!(
+            contract_name("#[aztec] contract Vault{"),
+            Some("Vault".to_string())
+        );
+        assert_eq!(contract_name("let contract_id = 1;"), None);
+        assert_eq!(
+            highlight_line("a <b> c", &[(3, 3, "primary")]),
+            "a <mark class=\"primary\">&lt;b&gt;</mark> c"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::diagnostics::{Diagnostic, SuggestionGroup, diagnostic_sort_key};
+use crate::timings::Timings;
 
 type SuggestionGroupEditSortKey = (String, u32, u32, u32, u32, String);
 type SuggestionGroupSortKey = (
@@ -10,15 +11,31 @@ type SuggestionGroupSortKey = (
 );
 
 pub fn render_diagnostics(diagnostics: &[&Diagnostic]) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(&sorted_for_json(diagnostics))
+}
+
+/// Renders `{"diagnostics": [...], "timings": {...}}`, used when timing output
+/// is requested. The diagnostics array matches [`render_diagnostics`].
+pub fn render_diagnostics_with_timings(
+    diagnostics: &[&Diagnostic],
+    timings: &Timings,
+) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(&serde_json::json!({
+        "diagnostics": sorted_for_json(diagnostics),
+        "timings": timings.report(),
+    }))
+}
+
+fn sorted_for_json(diagnostics: &[&Diagnostic]) -> Vec<Diagnostic> {
     let mut sorted = diagnostics
         .iter()
         .map(|diagnostic| normalize_for_json((**diagnostic).clone()))
         .collect::<Vec<_>>();
     sorted.sort_by_key(diagnostic_sort_key);
-    serde_json::to_string_pretty(&sorted)
+    sorted
 }
 
-fn normalize_for_json(mut diagnostic: Diagnostic) -> Diagnostic {
+pub(crate) fn normalize_for_json(mut diagnostic: Diagnostic) -> Diagnostic {
     diagnostic.merge_legacy_fields_from_suggestion_groups();
     if diagnostic.fixes.is_empty() {
         diagnostic.fixes = diagnostic.fixes_from_suggestion_groups();
@@ -145,7 +162,7 @@ fn suggestion_group_edits_sort_key(group: &SuggestionGroup) -> Vec<SuggestionGro
 mod tests {
     use serde_json::Value;
 
-    use super::render_diagnostics;
+    use super::{render_diagnostics, render_diagnostics_with_timings};
     use crate::diagnostics::{
         Applicability, Confidence, Diagnostic, Severity, StructuredMessage, SuggestionGroup,
         TextEdit,
@@ -187,6 +204,25 @@ mod tests {
         assert!(earlier_idx < later_idx);
     }
 
+    #[test]
+    fn json_output_with_timings_wraps_diagnostics() {
+        let item = diagnostic("NOIR100", 1, "message");
+        let mut timings = crate::timings::Timings::default();
+        timings.record_rule("NOIR100", std::time::Duration::from_millis(2));
+
+        let rendered = render_diagnostics_with_timings(&[&item], &timings)
+            .expect("json rendering should pass");
+        let value: Value = serde_json::from_str(&rendered).expect("json output should parse");
+
+        assert_eq!(value["diagnostics"][0]["rule_id"].as_str(), Some("NOIR100"));
+        assert_eq!(
+            value["timings"]["rules"][0]["name"].as_str(),
+            Some("NOIR100")
+        );
+        assert_eq!(value["timings"]["rules"][0]["ms"].as_f64(), Some(2.0));
+        assert_eq!(value["timings"]["phases"].as_array().map(Vec::len), Some(0));
+    }
+
     #[test]
     fn json_output_includes_structured_suggestions_with_applicability() {
         let mut item = diagnostic("NOIR100", 2, "message");
@@ -0,0 +1,241 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use crate::diagnostics::{Confidence, Diagnostic, Severity, diagnostic_sort_key};
+use crate::output::sarif::repository_relative_uri;
+
+/// Renders diagnostics as JUnit XML with one `<testsuite>` per rule.
+///
+/// Every unsuppressed diagnostic becomes a failing `<testcase>`; suppressed
+/// diagnostics are reported as skipped test cases. Suites are ordered by rule
+/// id and test cases by file, span, and message.
+pub fn render_diagnostics(repo_root: &Path, diagnostics: &[&Diagnostic]) -> String {
+    let mut suites = BTreeMap::<&str, Vec<&Diagnostic>>::new();
+    for diagnostic in diagnostics {
+        suites
+            .entry(diagnostic.rule_id.as_str())
+            .or_default()
+            .push(diagnostic);
+    }
+    for cases in suites.values_mut() {
+        cases.sort_by_key(|diagnostic| diagnostic_sort_key(diagnostic));
+    }
+
+    let skipped_total = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.suppressed)
+        .count();
+    let mut output = String::new();
+    let _ = writeln!(output, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        output,
+        r#"<testsuites name="aztec-lint" tests="{}" failures="{}" skipped="{skipped_total}">"#,
+        diagnostics.len(),
+        diagnostics.len() - skipped_total,
+    );
+    for (rule_id, cases) in &suites {
+        let skipped = cases
+            .iter()
+            .filter(|diagnostic| diagnostic.suppressed)
+            .count();
+        let _ = writeln!(
+            output,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" skipped="{skipped}">"#,
+            escape_xml(rule_id),
+            cases.len(),
+            cases.len() - skipped,
+        );
+        for diagnostic in cases {
+            render_testcase(&mut output, repo_root, diagnostic);
+        }
+        let _ = writeln!(output, "  </testsuite>");
+    }
+    let _ = writeln!(output, "</testsuites>");
+    output
+}
+
+fn render_testcase(output: &mut String, repo_root: &Path, diagnostic: &Diagnostic) {
+    let span = &diagnostic.primary_span;
+    let file = repository_relative_uri(repo_root, &span.file);
+    let location = format!("{file}:{}:{}", span.line, span.col);
+    let _ = writeln!(
+        output,
+        r#"    <testcase name="{}" classname="{}" file="{}" line="{}">"#,
+        escape_xml(&location),
+        escape_xml(&diagnostic.rule_id),
+        escape_xml(&file),
+        span.line,
+    );
+
+    if diagnostic.suppressed {
+        let reason = diagnostic
+            .suppression_reason
+            .as_deref()
+            .unwrap_or("suppressed");
+        let _ = writeln!(
+            output,
+            r#"      <skipped message="{}"/>"#,
+            escape_xml(&format!("suppressed: {reason}"))
+        );
+    } else {
+        let _ = writeln!(
+            output,
+            r#"      <failure type="{}" message="{}">{}</failure>"#,
+            severity_label(diagnostic.severity),
+            escape_xml(&diagnostic.message),
+            escape_xml(&failure_details(&location, diagnostic)),
+        );
+    }
+    let _ = writeln!(output, "    </testcase>");
+}
+
+fn failure_details(location: &str, diagnostic: &Diagnostic) -> String {
+    let span = &diagnostic.primary_span;
+    let mut details = String::new();
+    let _ = writeln!(
+        details,
+        "{location}: {}[{}] {}",
+        severity_label(diagnostic.severity),
+        diagnostic.rule_id,
+        diagnostic.message
+    );
+    let _ = writeln!(
+        details,
+        "span: bytes {}..{} policy={} confidence={}",
+        span.start,
+        span.end,
+        diagnostic.policy,
+        confidence_label(diagnostic.confidence)
+    );
+    for note in &diagnostic.notes {
+        let _ = writeln!(details, "note: {}", note.message);
+    }
+    for help in &diagnostic.helps {
+        let _ = writeln!(details, "help: {}", help.message);
+    }
+    for suggestion in &diagnostic.suggestions {
+        let _ = writeln!(details, "help: {suggestion}");
+    }
+    details
+}
+
+/// Escapes text for use in XML attribute values and character data.
+/// Other control characters are not valid XML 1.0 and are dropped.
+pub(crate) fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(ch),
+            ch if ch.is_control() => {}
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+fn confidence_label(confidence: Confidence) -> &'static str {
+    match confidence {
+        Confidence::Low => "low",
+        Confidence::Medium => "medium",
+        Confidence::High => "high",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::render_diagnostics;
+    use crate::diagnostics::{Confidence, Diagnostic, Severity, StructuredMessage};
+    use crate::model::Span;
+
+    fn diagnostic(rule_id: &str, file: &str, start: u32, line: u32, message: &str) -> Diagnostic {
+        Diagnostic {
+            rule_id: rule_id.to_string(),
+            severity: Severity::Warning,
+            confidence: Confidence::Medium,
+            policy: "privacy".to_string(),
+            message: message.to_string(),
+            primary_span: Span::new(file, start, start + 1, line, 2),
+            secondary_spans: Vec::new(),
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
+            structured_suggestions: Vec::new(),
+            suggestion_groups: Vec::new(),
+            fixes: Vec::new(),
+            suppressed: false,
+            suppression_reason: None,
+        }
+    }
+
+    #[test]
+    fn junit_output_groups_failures_by_rule_with_details() {
+        let root = Path::new("/repo");
+        let mut leak = diagnostic(
+            "AZTEC001",
+            "/repo/src/main.nr",
+            10,
+            3,
+            "value <leaks> & more",
+        );
+        leak.severity = Severity::Error;
+        leak.helps = vec![StructuredMessage {
+            message: "hash the value first".to_string(),
+            span: None,
+        }];
+        let mut suppressed = diagnostic("NOIR100", "src/lib.nr", 4, 1, "magic number");
+        suppressed.suppressed = true;
+        suppressed.suppression_reason = Some("allow(noir_core::noir100)".to_string());
+
+        let rendered = render_diagnostics(root, &[&suppressed, &leak]);
+
+        assert!(rendered.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+        assert!(
+            rendered
+                .contains(r#"<testsuites name="aztec-lint" tests="2" failures="1" skipped="1">"#)
+        );
+        assert!(
+            rendered.contains(r#"<testsuite name="AZTEC001" tests="1" failures="1" skipped="0">"#)
+        );
+        assert!(rendered.contains(
+            r#"<testcase name="src/main.nr:3:2" classname="AZTEC001" file="src/main.nr" line="3">"#
+        ));
+        assert!(
+            rendered.contains(r#"<failure type="error" message="value &lt;leaks&gt; &amp; more">"#)
+        );
+        assert!(rendered.contains("help: hash the value first"));
+        assert!(rendered.contains(r#"<skipped message="suppressed: allow(noir_core::noir100)"/>"#));
+        assert!(
+            rendered.find("AZTEC001").expect("AZTEC001 suite")
+                < rendered.find("NOIR100").expect("NOIR100 suite")
+        );
+    }
+
+    #[test]
+    fn junit_output_is_deterministic_across_reordered_input() {
+        let root = Path::new("/repo");
+        let first = diagnostic("AZTEC001", "src/a.nr", 10, 1, "first");
+        let second = diagnostic("AZTEC001", "src/b.nr", 11, 2, "second");
+        let third = diagnostic("NOIR100", "src/a.nr", 3, 1, "third");
+
+        let left = render_diagnostics(root, &[&third, &second, &first]);
+        let right = render_diagnostics(root, &[&first, &second, &third]);
+
+        assert_eq!(left, right);
+        assert!(left.find("src/a.nr:1:2").expect("first") < left.find("src/b.nr").expect("second"));
+    }
+}
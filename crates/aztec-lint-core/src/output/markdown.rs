@@ -0,0 +1,376 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use crate::diagnostics::{
+    Confidence, Diagnostic, Severity, diagnostic_sort_key, normalize_file_path,
+};
+use crate::model::Span;
+
+const SNIPPET_CONTEXT_LINES: usize = 1;
+
+pub struct MarkdownReport<'a> {
+    pub path: &'a Path,
+    pub source_root: &'a Path,
+    pub profile: &'a str,
+    pub changed_only: bool,
+    pub active_rules: usize,
+    pub diagnostics: &'a [&'a Diagnostic],
+}
+
+/// Renders a compact Markdown summary suitable for `$GITHUB_STEP_SUMMARY` or a
+/// pull request comment: run metadata, finding counts per rule and per file,
+/// and one collapsible `<details>` block with a snippet per diagnostic.
+pub fn render_markdown_report(report: MarkdownReport<'_>) -> String {
+    let mut diagnostics = report.diagnostics.to_vec();
+    diagnostics.sort_by_key(|diagnostic| diagnostic_sort_key(diagnostic));
+
+    let mut output = String::new();
+    let _ = writeln!(output, "## aztec-lint");
+    let _ = writeln!(output);
+    let _ = writeln!(
+        output,
+        "checked `{}` · profile `{}` · changed_only `{}` · active_rules `{}`",
+        report.path.display(),
+        report.profile,
+        report.changed_only,
+        report.active_rules
+    );
+    let _ = writeln!(output);
+
+    if diagnostics.is_empty() {
+        let _ = writeln!(output, "No diagnostics.");
+        return output;
+    }
+
+    let suppressed = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.suppressed)
+        .count();
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| !diagnostic.suppressed && diagnostic.severity == Severity::Error)
+        .count();
+    let _ = writeln!(
+        output,
+        "**diagnostics={} errors={errors} warnings={} suppressed={suppressed}**",
+        diagnostics.len(),
+        diagnostics.len() - errors - suppressed
+    );
+    let _ = writeln!(output);
+
+    let mut by_rule = BTreeMap::<&str, Counts>::new();
+    let mut by_file = BTreeMap::<String, Counts>::new();
+    for diagnostic in &diagnostics {
+        by_rule
+            .entry(diagnostic.rule_id.as_str())
+            .or_default()
+            .add(diagnostic);
+        by_file
+            .entry(normalize_file_path(&diagnostic.primary_span.file))
+            .or_default()
+            .add(diagnostic);
+    }
+
+    let _ = writeln!(output, "### Findings by rule");
+    let _ = writeln!(output);
+    render_counts_table(
+        &mut output,
+        "Rule",
+        by_rule
+            .iter()
+            .map(|(rule_id, counts)| (rule_link(rule_id), counts)),
+    );
+    let _ = writeln!(output, "### Findings by file");
+    let _ = writeln!(output);
+    render_counts_table(
+        &mut output,
+        "File",
+        by_file
+            .iter()
+            .map(|(file, counts)| (format!("`{}`", table_cell(file)), counts)),
+    );
+
+    let _ = writeln!(output, "### Details");
+    let _ = writeln!(output);
+    let mut source_cache = HashMap::<String, Option<Vec<String>>>::new();
+    for diagnostic in &diagnostics {
+        render_details(
+            &mut output,
+            report.source_root,
+            diagnostic,
+            &mut source_cache,
+        );
+    }
+    output
+}
+
+/// Link to the rule's page in the docs portal, pinned to this release.
+pub fn rule_docs_url(rule_id: &str) -> String {
+    format!(
+        "https://github.com/NethermindEth/aztec-lint/blob/v{}/docs/portal/lints/{}.md",
+        crate::VERSION,
+        rule_id.to_ascii_lowercase()
+    )
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Counts {
+    errors: usize,
+    warnings: usize,
+    suppressed: usize,
+}
+
+impl Counts {
+    /// Suppressed diagnostics are counted apart from errors and warnings, so
+    /// they are not part of the total either.
+    fn add(&mut self, diagnostic: &Diagnostic) {
+        if diagnostic.suppressed {
+            self.suppressed += 1;
+            return;
+        }
+        match diagnostic.severity {
+            Severity::Error => self.errors += 1,
+            Severity::Warning => self.warnings += 1,
+        }
+    }
+}
+
+fn render_counts_table<'a>(
+    output: &mut String,
+    label: &str,
+    rows: impl Iterator<Item = (String, &'a Counts)>,
+) {
+    let _ = writeln!(
+        output,
+        "| {label} | Errors | Warnings | Suppressed | Total |"
+    );
+    let _ = writeln!(output, "| --- | ---: | ---: | ---: | ---: |");
+    for (name, counts) in rows {
+        let _ = writeln!(
+            output,
+            "| {name} | {} | {} | {} | {} |",
+            counts.errors,
+            counts.warnings,
+            counts.suppressed,
+            counts.errors + counts.warnings
+        );
+    }
+    let _ = writeln!(output);
+}
+
+fn render_details(
+    output: &mut String,
+    source_root: &Path,
+    diagnostic: &Diagnostic,
+    source_cache: &mut HashMap<String, Option<Vec<String>>>,
+) {
+    let diagnostic = diagnostic
+        .clone()
+        .with_legacy_fields_from_suggestion_groups();
+    let span = &diagnostic.primary_span;
+    let suppressed = if diagnostic.suppressed {
+        " (suppressed)"
+    } else {
+        ""
+    };
+
+    let _ = writeln!(output, "<details>");
+    let _ = writeln!(
+        output,
+        "<summary>{}[<code>{}</code>] <code>{}:{}:{}</code>: {}{suppressed}</summary>",
+        severity_label(diagnostic.severity),
+        escape_html(&diagnostic.rule_id),
+        escape_html(&normalize_file_path(&span.file)),
+        span.line,
+        span.col,
+        escape_html(&diagnostic.message),
+    );
+    let _ = writeln!(output);
+
+    if let Some(snippet) = snippet(source_root, span, source_cache) {
+        let _ = writeln!(output, "```noir");
+        let _ = write!(output, "{snippet}");
+        let _ = writeln!(output, "```");
+        let _ = writeln!(output);
+    }
+
+    let _ = writeln!(
+        output,
+        "- confidence: `{}`, policy: `{}`, docs: {}",
+        confidence_label(diagnostic.confidence),
+        diagnostic.policy,
+        rule_link(&diagnostic.rule_id)
+    );
+    if let Some(reason) = diagnostic
+        .suppression_reason
+        .as_deref()
+        .filter(|_| diagnostic.suppressed)
+    {
+        let _ = writeln!(output, "- suppressed: {}", escape_html(reason));
+    }
+    for note in &diagnostic.notes {
+        let _ = writeln!(output, "- note: {}", escape_html(&note.message));
+    }
+    for help in &diagnostic.helps {
+        let _ = writeln!(output, "- help: {}", escape_html(&help.message));
+    }
+    for suggestion in &diagnostic.suggestions {
+        let _ = writeln!(output, "- help: {}", escape_html(suggestion));
+    }
+    let _ = writeln!(output);
+    let _ = writeln!(output, "</details>");
+    let _ = writeln!(output);
+}
+
+/// Lines around the primary span with a `^` marker under its first column.
+fn snippet(
+    source_root: &Path,
+    span: &Span,
+    source_cache: &mut HashMap<String, Option<Vec<String>>>,
+) -> Option<String> {
+    let lines = source_cache
+        .entry(span.file.clone())
+        .or_insert_with(|| {
+            let contents = fs::read_to_string(source_root.join(&span.file)).ok()?;
+            Some(contents.lines().map(str::to_string).collect())
+        })
+        .as_ref()?;
+    let index = usize::try_from(span.line.checked_sub(1)?).ok()?;
+    if index >= lines.len() {
+        return None;
+    }
+    let first = index.saturating_sub(SNIPPET_CONTEXT_LINES);
+    let last = (index + SNIPPET_CONTEXT_LINES).min(lines.len() - 1);
+    let gutter_width = (last + 1).to_string().len();
+
+    let mut snippet = String::new();
+    for (line_index, text) in lines.iter().enumerate().take(last + 1).skip(first) {
+        let _ = writeln!(snippet, "{:>gutter_width$} | {text}", line_index + 1);
+        if line_index == index {
+            let padding = " ".repeat(span.col.saturating_sub(1) as usize);
+            let _ = writeln!(snippet, "{:>gutter_width$} | {padding}^", "");
+        }
+    }
+    Some(snippet)
+}
+
+fn rule_link(rule_id: &str) -> String {
+    format!("[`{rule_id}`]({})", rule_docs_url(rule_id))
+}
+
+fn table_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
+fn confidence_label(confidence: Confidence) -> &'static str {
+    match confidence {
+        Confidence::Low => "low",
+        Confidence::Medium => "medium",
+        Confidence::High => "high",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use tempfile::tempdir;
+
+    use super::{MarkdownReport, render_markdown_report, rule_docs_url};
+    use crate::diagnostics::{Confidence, Diagnostic, Severity};
+    use crate::model::Span;
+
+    fn diagnostic(rule_id: &str, file: &str, start: u32, line: u32, message: &str) -> Diagnostic {
+        Diagnostic {
+            rule_id: rule_id.to_string(),
+            severity: Severity::Warning,
+            confidence: Confidence::Medium,
+            policy: "maintainability".to_string(),
+            message: message.to_string(),
+            primary_span: Span::new(file, start, start + 1, line, 5),
+            secondary_spans: Vec::new(),
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
+            structured_suggestions: Vec::new(),
+            suggestion_groups: Vec::new(),
+            fixes: Vec::new(),
+            suppressed: false,
+            suppression_reason: None,
+        }
+    }
+
+    fn render(root: &Path, diagnostics: &[&Diagnostic]) -> String {
+        render_markdown_report(MarkdownReport {
+            path: Path::new("."),
+            source_root: root,
+            profile: "default",
+            changed_only: false,
+            active_rules: 12,
+            diagnostics,
+        })
+    }
+
+    #[test]
+    fn markdown_report_summarizes_rules_files_and_details() {
+        let tmp = tempdir().expect("temp dir should be created");
+        fs::create_dir_all(tmp.path().join("src")).expect("src dir should be created");
+        fs::write(
+            tmp.path().join("src/main.nr"),
+            "fn main() {\n    let x = 42;\n}\n",
+        )
+        .expect("source should be written");
+        let mut error = diagnostic("AZTEC001", "src/main.nr", 20, 2, "value <leaks>");
+        error.severity = Severity::Error;
+        let warning = diagnostic("NOIR100", "src/main.nr", 24, 2, "magic number `42`");
+        let mut suppressed = diagnostic("NOIR100", "src/main.nr", 28, 2, "magic number `42`");
+        suppressed.suppressed = true;
+
+        let rendered = render(tmp.path(), &[&warning, &error, &suppressed]);
+
+        assert!(rendered.contains(
+            "checked `.` · profile `default` · changed_only `false` · active_rules `12`"
+        ));
+        assert!(rendered.contains("**diagnostics=3 errors=1 warnings=1 suppressed=1**"));
+        assert!(rendered.contains(&format!(
+            "| [`AZTEC001`]({}) | 1 | 0 | 0 | 1 |",
+            rule_docs_url("AZTEC001")
+        )));
+        assert!(rendered.contains(&format!(
+            "| [`NOIR100`]({}) | 0 | 1 | 1 | 1 |",
+            rule_docs_url("NOIR100")
+        )));
+        assert!(rendered.contains("| `src/main.nr` | 1 | 1 | 1 | 2 |"));
+        assert!(rendered.contains(
+            "<summary>error[<code>AZTEC001</code>] <code>src/main.nr:2:5</code>: value &lt;leaks&gt;</summary>"
+        ));
+        assert!(
+            rendered
+                .contains("```noir\n1 | fn main() {\n2 |     let x = 42;\n  |     ^\n3 | }\n```")
+        );
+    }
+
+    #[test]
+    fn markdown_report_handles_empty_runs_and_links_portal_pages() {
+        let rendered = render(Path::new("/repo"), &[]);
+        assert!(rendered.trim_end().ends_with("No diagnostics."));
+        assert!(rule_docs_url("NOIR100").ends_with("/docs/portal/lints/noir100.md"));
+    }
+}
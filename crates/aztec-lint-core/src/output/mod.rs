@@ -1,4 +1,13 @@
 pub mod ansi;
+pub mod checkstyle;
+pub mod github;
+pub mod gitlab;
+pub mod html;
 pub mod json;
+pub mod junit;
+pub mod markdown;
+pub mod ndjson;
+pub mod rdjson;
 pub mod sarif;
+pub mod sonarqube;
 pub mod text;
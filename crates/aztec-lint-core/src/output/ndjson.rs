@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use serde_json::json;
+
+use crate::config::ConfigSource;
+use crate::diagnostics::{Diagnostic, Severity, diagnostic_sort_key};
+use crate::output::json::normalize_for_json;
+
+/// Run-level facts written in the final NDJSON record.
+#[derive(Clone, Copy, Debug)]
+pub struct NdjsonSummary<'a> {
+    pub exit_code: u8,
+    pub config_source: &'a ConfigSource,
+}
+
+/// Writes one compact JSON object per diagnostic, using the same schema and
+/// ordering as [`crate::output::json::render_diagnostics`], followed by a
+/// summary record tagged `"kind": "summary"`.
+///
+/// Diagnostics are normalized and written one at a time, so consumers can
+/// process results as they arrive and memory stays flat for large workspaces.
+pub fn write_diagnostics<W: Write + ?Sized>(
+    writer: &mut W,
+    diagnostics: &[&Diagnostic],
+    summary: NdjsonSummary<'_>,
+) -> Result<(), serde_json::Error> {
+    let mut sorted = diagnostics.to_vec();
+    sorted.sort_by_key(|diagnostic| diagnostic_sort_key(diagnostic));
+
+    let mut rules = BTreeMap::<&str, usize>::new();
+    let mut errors = 0usize;
+    let mut suppressed = 0usize;
+    for diagnostic in &sorted {
+        serde_json::to_writer(&mut *writer, &normalize_for_json((*diagnostic).clone()))?;
+        writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+
+        *rules.entry(diagnostic.rule_id.as_str()).or_default() += 1;
+        if diagnostic.suppressed {
+            suppressed += 1;
+        } else if diagnostic.severity == Severity::Error {
+            errors += 1;
+        }
+    }
+
+    let config_source = match summary.config_source {
+        ConfigSource::File(path) => path.display().to_string(),
+        ConfigSource::Default => "default".to_string(),
+    };
+    serde_json::to_writer(
+        &mut *writer,
+        &json!({
+            "kind": "summary",
+            "tool": "aztec-lint",
+            "version": crate::VERSION,
+            "diagnostics": sorted.len(),
+            "errors": errors,
+            "warnings": sorted.len() - errors - suppressed,
+            "suppressed": suppressed,
+            "rules": rules,
+            "exit_code": summary.exit_code,
+            "config_source": config_source,
+        }),
+    )?;
+    writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+    writer.flush().map_err(serde_json::Error::io)
+}
+
+pub fn render_diagnostics(
+    diagnostics: &[&Diagnostic],
+    summary: NdjsonSummary<'_>,
+) -> Result<String, serde_json::Error> {
+    let mut output = Vec::<u8>::new();
+    write_diagnostics(&mut output, diagnostics, summary)?;
+    Ok(String::from_utf8_lossy(&output).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use serde_json::{Value, json};
+
+    use super::{NdjsonSummary, render_diagnostics};
+    use crate::config::ConfigSource;
+    use crate::diagnostics::{Confidence, Diagnostic, Severity};
+    use crate::model::Span;
+    use crate::output::json;
+
+    fn diagnostic(rule_id: &str, file: &str, start: u32, line: u32, message: &str) -> Diagnostic {
+        Diagnostic {
+            rule_id: rule_id.to_string(),
+            severity: Severity::Warning,
+            confidence: Confidence::Medium,
+            policy: "privacy".to_string(),
+            message: message.to_string(),
+            primary_span: Span::new(file, start, start + 1, line, 2),
+            secondary_spans: Vec::new(),
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
+            structured_suggestions: Vec::new(),
+            suggestion_groups: Vec::new(),
+            fixes: Vec::new(),
+            suppressed: false,
+            suppression_reason: None,
+        }
+    }
+
+    #[test]
+    fn ndjson_lines_match_json_schema_and_end_with_summary() {
+        let mut error = diagnostic("AZTEC001", "src/b.nr", 10, 2, "leak");
+        error.severity = Severity::Error;
+        let warning = diagnostic("NOIR100", "src/a.nr", 4, 1, "magic");
+        let mut suppressed = diagnostic("NOIR100", "src/a.nr", 8, 1, "magic");
+        suppressed.suppressed = true;
+        let diagnostics = [&error, &suppressed, &warning];
+        let source = ConfigSource::File(PathBuf::from("project/aztec-lint.toml"));
+
+        let rendered = render_diagnostics(
+            &diagnostics,
+            NdjsonSummary {
+                exit_code: 1,
+                config_source: &source,
+            },
+        )
+        .expect("ndjson render should pass");
+        let lines = rendered
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).expect("line should parse"))
+            .collect::<Vec<_>>();
+
+        let array: Value = serde_json::from_str(
+            &json::render_diagnostics(&diagnostics).expect("json render should pass"),
+        )
+        .expect("json should parse");
+        assert_eq!(Value::Array(lines[..3].to_vec()), array);
+        assert_eq!(
+            lines[3],
+            json!({
+                "kind": "summary",
+                "tool": "aztec-lint",
+                "version": crate::VERSION,
+                "diagnostics": 3,
+                "errors": 1,
+                "warnings": 1,
+                "suppressed": 1,
+                "rules": { "AZTEC001": 1, "NOIR100": 2 },
+                "exit_code": 1,
+                "config_source": "project/aztec-lint.toml",
+            })
+        );
+        assert!(
+            rendered.lines().all(|line| !line.starts_with(' ')),
+            "records should be compact"
+        );
+    }
+
+    #[test]
+    fn ndjson_summary_reports_default_config_source() {
+        let rendered = render_diagnostics(
+            &[],
+            NdjsonSummary {
+                exit_code: 0,
+                config_source: &ConfigSource::Default,
+            },
+        )
+        .expect("ndjson render should pass");
+        let summary: Value = serde_json::from_str(rendered.trim_end()).expect("summary parses");
+
+        assert_eq!(summary["config_source"], "default");
+        assert_eq!(summary["diagnostics"], 0);
+    }
+}
@@ -0,0 +1,312 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde_json::{Value, json};
+
+use crate::diagnostics::{Applicability, Diagnostic, Severity, diagnostic_sort_key};
+use crate::model::Span;
+use crate::output::markdown::rule_docs_url;
+use crate::output::sarif::repository_relative_uri;
+
+const TOOL_URL: &str = "https://github.com/NethermindEth/aztec-lint";
+
+/// Renders diagnostics in reviewdog's Diagnostic Format (`rdjson`).
+///
+/// The first machine-applicable suggestion group of each diagnostic becomes
+/// its reviewdog `suggestions`, one entry per edit, so the fix can be applied
+/// as a single GitHub suggestion. Suppressed diagnostics are omitted.
+pub fn render_diagnostics(
+    repo_root: &Path,
+    diagnostics: &[&Diagnostic],
+) -> Result<String, serde_json::Error> {
+    let results = render_results(repo_root, diagnostics);
+    serde_json::to_string_pretty(&json!({
+        "source": source(),
+        "diagnostics": results,
+    }))
+}
+
+/// Renders the `rdjsonl` variant: one compact reviewdog diagnostic per line.
+pub fn render_diagnostics_lines(
+    repo_root: &Path,
+    diagnostics: &[&Diagnostic],
+) -> Result<String, serde_json::Error> {
+    let mut output = String::new();
+    for result in render_results(repo_root, diagnostics) {
+        output.push_str(&serde_json::to_string(&result)?);
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+fn render_results(repo_root: &Path, diagnostics: &[&Diagnostic]) -> Vec<Value> {
+    let mut sorted = diagnostics
+        .iter()
+        .filter(|diagnostic| !diagnostic.suppressed)
+        .copied()
+        .collect::<Vec<_>>();
+    sorted.sort_by_key(|diagnostic| diagnostic_sort_key(diagnostic));
+
+    let mut sources = SourceTexts::new(repo_root);
+    sorted
+        .into_iter()
+        .map(|diagnostic| render_result(repo_root, diagnostic, &mut sources))
+        .collect()
+}
+
+fn render_result(repo_root: &Path, diagnostic: &Diagnostic, sources: &mut SourceTexts) -> Value {
+    let mut result = json!({
+        "message": diagnostic.message,
+        "location": {
+            "path": repository_relative_uri(repo_root, &diagnostic.primary_span.file),
+            "range": sources.range(&diagnostic.primary_span),
+        },
+        "severity": rdjson_severity(diagnostic.severity),
+        "source": source(),
+        "code": {
+            "value": diagnostic.rule_id,
+            "url": rule_docs_url(&diagnostic.rule_id),
+        },
+    });
+
+    let mut groups = diagnostic
+        .suggestion_groups
+        .iter()
+        .filter(|group| group.applicability == Applicability::MachineApplicable)
+        .collect::<Vec<_>>();
+    groups.sort_by(|left, right| left.id.cmp(&right.id));
+    if let Some(group) = groups.first() {
+        let mut edits = group.edits.iter().collect::<Vec<_>>();
+        edits.sort_by_key(|edit| (edit.span.start, edit.span.end));
+        let suggestions = edits
+            .into_iter()
+            .map(|edit| {
+                json!({
+                    "range": sources.range(&edit.span),
+                    "text": edit.replacement,
+                })
+            })
+            .collect::<Vec<_>>();
+        result["suggestions"] = Value::Array(suggestions);
+    }
+    result
+}
+
+fn source() -> Value {
+    json!({ "name": "aztec-lint", "url": TOOL_URL })
+}
+
+fn rdjson_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Warning => "WARNING",
+        Severity::Error => "ERROR",
+    }
+}
+
+/// Source files read on demand to turn span byte offsets into reviewdog
+/// positions (1-based lines, 1-based UTF-8 byte columns).
+struct SourceTexts<'a> {
+    repo_root: &'a Path,
+    files: HashMap<String, Option<String>>,
+}
+
+impl<'a> SourceTexts<'a> {
+    fn new(repo_root: &'a Path) -> Self {
+        Self {
+            repo_root,
+            files: HashMap::new(),
+        }
+    }
+
+    fn range(&mut self, span: &Span) -> Value {
+        let repo_root = self.repo_root;
+        let text = self
+            .files
+            .entry(span.file.clone())
+            .or_insert_with(|| fs::read_to_string(repo_root.join(&span.file)).ok());
+        let (start, end) = match text {
+            Some(text) => (
+                position_for_offset(text, span.start as usize),
+                position_for_offset(text, span.end as usize),
+            ),
+            // Without the source, assume the span does not cross a line break.
+            None => {
+                let width = span.end.saturating_sub(span.start);
+                (
+                    (span.line, span.col),
+                    (span.line, span.col.saturating_add(width)),
+                )
+            }
+        };
+        json!({
+            "start": { "line": start.0, "column": start.1 },
+            "end": { "line": end.0, "column": end.1 },
+        })
+    }
+}
+
+fn position_for_offset(text: &str, offset: usize) -> (u32, u32) {
+    let offset = offset.min(text.len());
+    let line_start = text.as_bytes()[..offset]
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |index| index + 1);
+    let line = text.as_bytes()[..line_start]
+        .iter()
+        .filter(|byte| **byte == b'\n')
+        .count();
+    (
+        u32::try_from(line + 1).unwrap_or(u32::MAX),
+        u32::try_from(offset - line_start + 1).unwrap_or(u32::MAX),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use serde_json::{Value, json};
+    use tempfile::tempdir;
+
+    use super::{render_diagnostics, render_diagnostics_lines};
+    use crate::diagnostics::{
+        Applicability, Confidence, Diagnostic, Severity, SuggestionGroup, TextEdit,
+    };
+    use crate::model::Span;
+
+    fn diagnostic(rule_id: &str, file: &str, span: Span, message: &str) -> Diagnostic {
+        Diagnostic {
+            rule_id: rule_id.to_string(),
+            severity: Severity::Warning,
+            confidence: Confidence::Medium,
+            policy: "maintainability".to_string(),
+            message: message.to_string(),
+            primary_span: Span {
+                file: file.to_string(),
+                ..span
+            },
+            secondary_spans: Vec::new(),
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
+            structured_suggestions: Vec::new(),
+            suggestion_groups: Vec::new(),
+            fixes: Vec::new(),
+            suppressed: false,
+            suppression_reason: None,
+        }
+    }
+
+    fn group(id: &str, applicability: Applicability, edits: Vec<TextEdit>) -> SuggestionGroup {
+        SuggestionGroup {
+            id: id.to_string(),
+            message: "apply fix".to_string(),
+            applicability,
+            edits,
+            provenance: None,
+        }
+    }
+
+    #[test]
+    fn rdjson_output_includes_multi_edit_machine_applicable_suggestions() {
+        let tmp = tempdir().expect("temp dir should be created");
+        fs::create_dir_all(tmp.path().join("src")).expect("src dir should be created");
+        fs::write(
+            tmp.path().join("src/main.nr"),
+            "fn main() {\n    let x = 1;\n    assert(x == 1);\n}\n",
+        )
+        .expect("source should be written");
+
+        let mut issue = diagnostic(
+            "NOIR001",
+            "src/main.nr",
+            Span::new("", 20, 21, 2, 9),
+            "unused variable",
+        );
+        issue.suggestion_groups = vec![
+            group(
+                "sg0002",
+                Applicability::MaybeIncorrect,
+                vec![TextEdit {
+                    span: Span::new("src/main.nr", 20, 21, 2, 9),
+                    replacement: "_".to_string(),
+                }],
+            ),
+            group(
+                "sg0001",
+                Applicability::MachineApplicable,
+                vec![
+                    TextEdit {
+                        span: Span::new("src/main.nr", 38, 39, 3, 12),
+                        replacement: "_x".to_string(),
+                    },
+                    TextEdit {
+                        span: Span::new("src/main.nr", 20, 21, 2, 9),
+                        replacement: "_x".to_string(),
+                    },
+                ],
+            ),
+        ];
+        let mut suppressed = issue.clone();
+        suppressed.rule_id = "NOIR100".to_string();
+        suppressed.suppressed = true;
+
+        let rendered = render_diagnostics(tmp.path(), &[&suppressed, &issue])
+            .expect("rdjson render should pass");
+        let value: Value = serde_json::from_str(&rendered).expect("rdjson should parse");
+
+        assert_eq!(value["source"]["name"], "aztec-lint");
+        let diagnostics = value["diagnostics"].as_array().expect("diagnostics array");
+        assert_eq!(diagnostics.len(), 1);
+        let result = &diagnostics[0];
+        assert_eq!(result["severity"], "WARNING");
+        assert_eq!(result["code"]["value"], "NOIR001");
+        assert_eq!(
+            result["location"],
+            json!({
+                "path": "src/main.nr",
+                "range": {
+                    "start": { "line": 2, "column": 9 },
+                    "end": { "line": 2, "column": 10 },
+                }
+            })
+        );
+        assert_eq!(
+            result["suggestions"],
+            json!([
+                {
+                    "range": { "start": { "line": 2, "column": 9 }, "end": { "line": 2, "column": 10 } },
+                    "text": "_x",
+                },
+                {
+                    "range": { "start": { "line": 3, "column": 12 }, "end": { "line": 3, "column": 13 } },
+                    "text": "_x",
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn rdjsonl_output_is_one_compact_diagnostic_per_line() {
+        let root = Path::new("/repo");
+        let first = diagnostic("AZTEC001", "src/a.nr", Span::new("", 10, 12, 1, 2), "first");
+        let second = diagnostic(
+            "AZTEC020",
+            "src/b.nr",
+            Span::new("", 11, 12, 2, 2),
+            "second",
+        );
+
+        let rendered =
+            render_diagnostics_lines(root, &[&second, &first]).expect("rdjsonl render should pass");
+        let lines = rendered.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 2);
+        let first_line: Value = serde_json::from_str(lines[0]).expect("line should parse");
+        assert_eq!(first_line["code"]["value"], "AZTEC001");
+        assert_eq!(first_line["location"]["range"]["end"]["column"], 4);
+        assert!(first_line.get("suggestions").is_none());
+    }
+}
@@ -469,7 +469,7 @@ fn region_for_span(span: &Span) -> Value {
     })
 }
 
-fn repository_relative_uri(repo_root: &Path, file: &str) -> String {
+pub(crate) fn repository_relative_uri(repo_root: &Path, file: &str) -> String {
     let repo_root = to_absolute_path(repo_root);
     let file_path = Path::new(file);
     let absolute_file = if file_path.is_absolute() {
@@ -0,0 +1,194 @@
+use std::path::Path;
+
+use serde_json::{Value, json};
+
+use crate::diagnostics::{Confidence, Diagnostic, Severity, diagnostic_sort_key};
+use crate::lints::find_lint;
+use crate::model::Span;
+use crate::output::sarif::repository_relative_uri;
+
+const ENGINE_ID: &str = "aztec-lint";
+
+/// Renders diagnostics in SonarQube's Generic Issue Import format.
+///
+/// Issue types follow the lint category (falling back to the diagnostic
+/// policy for unknown rules) and severities combine severity and confidence.
+/// Ranges are line based because Sonar rejects columns past the end of a line.
+/// Suppressed diagnostics are omitted.
+pub fn render_diagnostics(
+    repo_root: &Path,
+    diagnostics: &[&Diagnostic],
+) -> Result<String, serde_json::Error> {
+    let mut sorted = diagnostics
+        .iter()
+        .filter(|diagnostic| !diagnostic.suppressed)
+        .copied()
+        .collect::<Vec<_>>();
+    sorted.sort_by_key(|diagnostic| diagnostic_sort_key(diagnostic));
+
+    let issues = sorted
+        .into_iter()
+        .map(|diagnostic| render_issue(repo_root, diagnostic))
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&json!({ "issues": issues }))
+}
+
+fn render_issue(repo_root: &Path, diagnostic: &Diagnostic) -> Value {
+    let secondary_locations = diagnostic
+        .secondary_spans
+        .iter()
+        .map(|span| location(repo_root, span, "related location"))
+        .collect::<Vec<_>>();
+    json!({
+        "engineId": ENGINE_ID,
+        "ruleId": diagnostic.rule_id,
+        "type": sonar_type(diagnostic),
+        "severity": sonar_severity(diagnostic.severity, diagnostic.confidence),
+        "primaryLocation": location(repo_root, &diagnostic.primary_span, &diagnostic.message),
+        "secondaryLocations": secondary_locations,
+    })
+}
+
+fn location(repo_root: &Path, span: &Span, message: &str) -> Value {
+    json!({
+        "message": message,
+        "filePath": repository_relative_uri(repo_root, &span.file),
+        "textRange": {
+            "startLine": span.line,
+            "endLine": span.line,
+        }
+    })
+}
+
+fn sonar_type(diagnostic: &Diagnostic) -> &'static str {
+    let category = find_lint(&diagnostic.rule_id)
+        .map(|lint| lint.category.as_str())
+        .unwrap_or(diagnostic.policy.as_str());
+    match category {
+        "privacy" | "soundness" => "VULNERABILITY",
+        "correctness" | "protocol" => "BUG",
+        _ => "CODE_SMELL",
+    }
+}
+
+/// Maps severity and confidence onto Sonar's scale. `BLOCKER` is left to
+/// quality gates; confident errors are `CRITICAL`.
+fn sonar_severity(severity: Severity, confidence: Confidence) -> &'static str {
+    match (severity, confidence) {
+        (Severity::Error, Confidence::High) => "CRITICAL",
+        (Severity::Error, Confidence::Medium) | (Severity::Warning, Confidence::High) => "MAJOR",
+        (Severity::Error, Confidence::Low) | (Severity::Warning, Confidence::Medium) => "MINOR",
+        (Severity::Warning, Confidence::Low) => "INFO",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use serde_json::{Value, json};
+
+    use super::{render_diagnostics, sonar_severity};
+    use crate::diagnostics::{Confidence, Diagnostic, Severity};
+    use crate::model::Span;
+
+    fn diagnostic(rule_id: &str, policy: &str, file: &str, line: u32) -> Diagnostic {
+        Diagnostic {
+            rule_id: rule_id.to_string(),
+            severity: Severity::Warning,
+            confidence: Confidence::Medium,
+            policy: policy.to_string(),
+            message: format!("{rule_id} finding"),
+            primary_span: Span::new(file, line * 10, line * 10 + 1, line, 2),
+            secondary_spans: Vec::new(),
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            helps: Vec::new(),
+            structured_suggestions: Vec::new(),
+            suggestion_groups: Vec::new(),
+            fixes: Vec::new(),
+            suppressed: false,
+            suppression_reason: None,
+        }
+    }
+
+    #[test]
+    fn sonarqube_output_maps_categories_to_issue_types() {
+        let root = Path::new("/repo");
+        let mut privacy = diagnostic("AZTEC001", "privacy", "/repo/src/main.nr", 1);
+        privacy.severity = Severity::Error;
+        privacy.confidence = Confidence::High;
+        privacy.secondary_spans = vec![Span::new("src/main.nr", 40, 45, 4, 1)];
+        let soundness = diagnostic("AZTEC020", "soundness", "src/main.nr", 2);
+        let correctness = diagnostic("NOIR001", "correctness", "src/main.nr", 3);
+        let maintainability = diagnostic("NOIR100", "maintainability", "src/main.nr", 5);
+        let custom = diagnostic("CUSTOM001", "privacy", "src/main.nr", 6);
+
+        let rendered = render_diagnostics(
+            root,
+            &[
+                &maintainability,
+                &custom,
+                &correctness,
+                &soundness,
+                &privacy,
+            ],
+        )
+        .expect("sonarqube render should pass");
+        let value: Value = serde_json::from_str(&rendered).expect("report should parse");
+        let issues = value["issues"].as_array().expect("issues array");
+
+        let types = issues
+            .iter()
+            .map(|issue| {
+                (
+                    issue["ruleId"].as_str().unwrap(),
+                    issue["type"].as_str().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            vec![
+                ("AZTEC001", "VULNERABILITY"),
+                ("AZTEC020", "VULNERABILITY"),
+                ("NOIR001", "BUG"),
+                ("NOIR100", "CODE_SMELL"),
+                ("CUSTOM001", "VULNERABILITY"),
+            ]
+        );
+        assert_eq!(
+            issues[0],
+            json!({
+                "engineId": "aztec-lint",
+                "ruleId": "AZTEC001",
+                "type": "VULNERABILITY",
+                "severity": "CRITICAL",
+                "primaryLocation": {
+                    "message": "AZTEC001 finding",
+                    "filePath": "src/main.nr",
+                    "textRange": { "startLine": 1, "endLine": 1 }
+                },
+                "secondaryLocations": [{
+                    "message": "related location",
+                    "filePath": "src/main.nr",
+                    "textRange": { "startLine": 4, "endLine": 4 }
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn sonarqube_severity_scales_with_confidence() {
+        assert_eq!(
+            sonar_severity(Severity::Error, Confidence::High),
+            "CRITICAL"
+        );
+        assert_eq!(sonar_severity(Severity::Error, Confidence::Medium), "MAJOR");
+        assert_eq!(
+            sonar_severity(Severity::Warning, Confidence::Medium),
+            "MINOR"
+        );
+        assert_eq!(sonar_severity(Severity::Warning, Confidence::Low), "INFO");
+    }
+}
@@ -9,6 +9,7 @@ use crate::diagnostics::{
 };
 use crate::model::Span;
 use crate::output::ansi::{Colorizer, Stream};
+use crate::timings::Timings;
 
 pub struct CheckTextReport<'a> {
     pub path: &'a Path,
@@ -21,10 +22,13 @@ pub struct CheckTextReport<'a> {
 }
 
 pub fn render_check_report(report: CheckTextReport<'_>) -> String {
+    render_check_report_with_colors(report, Colorizer::for_stream(Stream::Stdout))
+}
+
+pub fn render_check_report_with_colors(report: CheckTextReport<'_>, colors: Colorizer) -> String {
     let mut output = String::new();
     let mut diagnostics = report.diagnostics.to_vec();
     let mut source_cache = HashMap::<String, Option<Vec<String>>>::new();
-    let colors = Colorizer::for_stream(Stream::Stdout);
     diagnostics.sort_by_key(|diagnostic| diagnostic_sort_key(diagnostic));
 
     if report.show_run_header {
@@ -68,6 +72,38 @@ pub fn render_check_report(report: CheckTextReport<'_>) -> String {
     output
 }
 
+/// Renders phase and per-rule wall times as a table, slowest first within each
+/// section.
+pub fn render_timings_table(timings: &Timings) -> String {
+    let mut output = String::new();
+    let report = timings.report();
+    let width = report
+        .phases
+        .iter()
+        .chain(&report.rules)
+        .map(|entry| entry.name.len())
+        .max()
+        .unwrap_or(0);
+
+    let _ = writeln!(output, "timings (wall time):");
+    for (kind, entries) in [("phase", &report.phases), ("rule", &report.rules)] {
+        for entry in entries {
+            let _ = writeln!(
+                output,
+                "  {kind:<5}  {:<width$}  {:>10.3} ms",
+                entry.name, entry.ms
+            );
+        }
+    }
+    let rule_total = report.rules.iter().map(|entry| entry.ms).sum::<f64>();
+    let _ = writeln!(
+        output,
+        "rules={} total_rule_ms={rule_total:.3}",
+        report.rules.len()
+    );
+    output
+}
+
 fn render_diagnostic(
     output: &mut String,
     source_root: &Path,
@@ -480,7 +516,7 @@ mod tests {
 
     use tempfile::tempdir;
 
-    use super::{CheckTextReport, render_check_report};
+    use super::{CheckTextReport, render_check_report, render_timings_table};
     use crate::diagnostics::{
         Applicability, Confidence, Diagnostic, Severity, StructuredMessage, StructuredSuggestion,
         SuggestionGroup, TextEdit,
@@ -767,4 +803,19 @@ mod tests {
             .expect("B edit should be rendered");
         assert!(a_idx < b_idx);
     }
+
+    #[test]
+    fn timings_table_lists_phases_and_rules_slowest_first() {
+        let mut timings = crate::timings::Timings::default();
+        timings.record_phase("frontend", std::time::Duration::from_millis(4));
+        timings.record_rule("NOIR100", std::time::Duration::from_millis(1));
+        timings.record_rule("AZTEC001", std::time::Duration::from_millis(2));
+
+        let rendered = render_timings_table(&timings);
+
+        assert_eq!(
+            rendered,
+            "timings (wall time):\n  phase  frontend       4.000 ms\n  rule   AZTEC001       2.000 ms\n  rule   NOIR100        1.000 ms\nrules=2 total_rule_ms=3.000\n"
+        );
+    }
 }
@@ -0,0 +1,99 @@
+use std::num::NonZeroUsize;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Worker count used when no explicit job limit is given.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Applies `f` to every item on up to `jobs` scoped worker threads and returns
+/// the results in input order, so callers observe the same sequence as a
+/// sequential loop regardless of scheduling. Panics in `f` are propagated.
+pub fn map_ordered<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = jobs.min(items.len());
+    if workers <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut slots = items.iter().map(|_| None).collect::<Vec<Option<R>>>();
+    thread::scope(|scope| {
+        let handles = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut results = Vec::<(usize, R)>::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            break;
+                        };
+                        results.push((index, f(item)));
+                    }
+                    results
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            let results = handle
+                .join()
+                .unwrap_or_else(|payload| panic::resume_unwind(payload));
+            for (index, result) in results {
+                slots[index] = Some(result);
+            }
+        }
+    });
+
+    slots
+        .into_iter()
+        .map(|slot| slot.expect("every item should be processed by a worker"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+    use std::thread;
+
+    use super::map_ordered;
+
+    #[test]
+    fn preserves_input_order_for_any_job_count() {
+        let items = (0..64u32).collect::<Vec<_>>();
+        let expected = items.iter().map(|item| item * 3).collect::<Vec<_>>();
+
+        for jobs in [0, 1, 2, 7, 128] {
+            assert_eq!(map_ordered(&items, jobs, |item| item * 3), expected);
+        }
+    }
+
+    #[test]
+    fn distributes_work_across_threads() {
+        let items = (0..32u32).collect::<Vec<_>>();
+        let threads = Mutex::new(HashSet::new());
+
+        map_ordered(&items, 4, |_| {
+            threads
+                .lock()
+                .expect("thread set should not be poisoned")
+                .insert(thread::current().id());
+            thread::sleep(std::time::Duration::from_millis(2));
+        });
+
+        assert!(
+            threads
+                .lock()
+                .expect("thread set should not be poisoned")
+                .len()
+                > 1
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+pub const FRONTEND_PHASE: &str = "frontend";
+pub const SEMANTIC_EXTRACTION_PHASE: &str = "semantic_extraction";
+pub const AZTEC_MODEL_PHASE: &str = "aztec_model";
+pub const TAINT_GRAPH_PHASE: &str = "taint_graph";
+
+/// Accumulated wall time per analysis phase and per rule. Repeated
+/// recordings under the same name are summed.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Timings {
+    phases: BTreeMap<String, Duration>,
+    rules: BTreeMap<String, Duration>,
+}
+
+/// Serializable view of [`Timings`], with entries sorted slowest first.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TimingsReport {
+    pub phases: Vec<TimingEntry>,
+    pub rules: Vec<TimingEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TimingEntry {
+    pub name: String,
+    pub ms: f64,
+}
+
+impl Timings {
+    pub fn time<T>(&mut self, phase: &str, f: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let result = f();
+        self.record_phase(phase, started.elapsed());
+        result
+    }
+
+    pub fn record_phase(&mut self, phase: &str, elapsed: Duration) {
+        *self.phases.entry(phase.to_string()).or_default() += elapsed;
+    }
+
+    pub fn record_rule(&mut self, rule_id: &str, elapsed: Duration) {
+        *self.rules.entry(rule_id.to_string()).or_default() += elapsed;
+    }
+
+    pub fn merge(&mut self, other: &Timings) {
+        for (phase, elapsed) in &other.phases {
+            self.record_phase(phase, *elapsed);
+        }
+        for (rule_id, elapsed) in &other.rules {
+            self.record_rule(rule_id, *elapsed);
+        }
+    }
+
+    pub fn phases(&self) -> &BTreeMap<String, Duration> {
+        &self.phases
+    }
+
+    pub fn rules(&self) -> &BTreeMap<String, Duration> {
+        &self.rules
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.phases.is_empty() && self.rules.is_empty()
+    }
+
+    pub fn report(&self) -> TimingsReport {
+        TimingsReport {
+            phases: sorted_entries(&self.phases),
+            rules: sorted_entries(&self.rules),
+        }
+    }
+}
+
+fn sorted_entries(durations: &BTreeMap<String, Duration>) -> Vec<TimingEntry> {
+    let mut entries = durations.iter().collect::<Vec<_>>();
+    entries.sort_by(|(left_name, left), (right_name, right)| {
+        right.cmp(left).then_with(|| left_name.cmp(right_name))
+    });
+    entries
+        .into_iter()
+        .map(|(name, elapsed)| TimingEntry {
+            name: name.clone(),
+            ms: elapsed.as_secs_f64() * 1_000.0,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{FRONTEND_PHASE, TAINT_GRAPH_PHASE, Timings};
+
+    #[test]
+    fn accumulates_and_sorts_slowest_first() {
+        let mut timings = Timings::default();
+        timings.record_phase(FRONTEND_PHASE, Duration::from_millis(3));
+        timings.record_phase(TAINT_GRAPH_PHASE, Duration::from_millis(2));
+        timings.record_phase(TAINT_GRAPH_PHASE, Duration::from_millis(2));
+        timings.record_rule("NOIR100", Duration::from_millis(1));
+        timings.record_rule("AZTEC001", Duration::from_millis(1));
+
+        let mut merged = Timings::default();
+        merged.record_rule("NOIR100", Duration::from_millis(5));
+        merged.merge(&timings);
+        let report = merged.report();
+
+        let phases = report
+            .phases
+            .iter()
+            .map(|entry| (entry.name.as_str(), entry.ms))
+            .collect::<Vec<_>>();
+        assert_eq!(phases, vec![("taint_graph", 4.0), ("frontend", 3.0)]);
+        let rules = report
+            .rules
+            .iter()
+            .map(|entry| (entry.name.as_str(), entry.ms))
+            .collect::<Vec<_>>();
+        assert_eq!(rules, vec![("NOIR100", 6.0), ("AZTEC001", 1.0)]);
+    }
+}
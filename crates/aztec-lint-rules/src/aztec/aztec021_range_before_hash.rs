@@ -1,7 +1,4 @@
-use aztec_lint_aztec::SourceUnit;
-use aztec_lint_aztec::taint::{
-    TaintSinkKind, TaintSourceKind, analyze_intra_procedural, build_def_use_graph_with_semantic,
-};
+use aztec_lint_aztec::taint::{TaintSinkKind, TaintSourceKind, analyze_intra_procedural};
 use aztec_lint_core::diagnostics::{Applicability, Diagnostic, SuggestionGroup, TextEdit};
 use aztec_lint_core::policy::SOUNDNESS;
 
@@ -16,19 +13,10 @@ impl Rule for Aztec021RangeBeforeHashRule {
     }
 
     fn run(&self, ctx: &RuleContext<'_>, out: &mut Vec<Diagnostic>) {
-        let Some(model) = ctx.aztec_model() else {
+        let Some(graph) = ctx.taint_graph() else {
             return;
         };
-
-        let config = ctx.aztec_config();
-        let sources = ctx
-            .files()
-            .iter()
-            .map(|file| SourceUnit::new(file.path().to_string(), file.text().to_string()))
-            .collect::<Vec<_>>();
-        let graph =
-            build_def_use_graph_with_semantic(&sources, model, Some(ctx.semantic_model()), &config);
-        let analysis = analyze_intra_procedural(&graph);
+        let analysis = analyze_intra_procedural(graph);
 
         for flow in analysis.flows {
             if flow.sink_kind != TaintSinkKind::HashOrSerialize {
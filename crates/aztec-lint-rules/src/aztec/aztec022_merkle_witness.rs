@@ -1,9 +1,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use aztec_lint_aztec::SourceUnit;
 use aztec_lint_aztec::taint::{
     DefUseGraph, FunctionGraph, TaintSinkKind, analyze_intra_procedural,
-    build_def_use_graph_with_semantic,
 };
 use aztec_lint_core::diagnostics::Diagnostic;
 use aztec_lint_core::model::{SemanticModel, StatementCategory};
@@ -20,19 +18,10 @@ impl Rule for Aztec022MerkleWitnessRule {
     }
 
     fn run(&self, ctx: &RuleContext<'_>, out: &mut Vec<Diagnostic>) {
-        let Some(model) = ctx.aztec_model() else {
+        let Some(graph) = ctx.taint_graph() else {
             return;
         };
-
-        let config = ctx.aztec_config();
-        let sources = ctx
-            .files()
-            .iter()
-            .map(|file| SourceUnit::new(file.path().to_string(), file.text().to_string()))
-            .collect::<Vec<_>>();
-        let graph =
-            build_def_use_graph_with_semantic(&sources, model, Some(ctx.semantic_model()), &config);
-        let analysis = analyze_intra_procedural(&graph);
+        let analysis = analyze_intra_procedural(graph);
 
         let tainted_merkle_functions = analysis
             .flows
@@ -50,7 +39,7 @@ impl Rule for Aztec022MerkleWitnessRule {
                 continue;
             }
 
-            let has_verification = has_semantic_merkle_verification(function, &graph, ctx);
+            let has_verification = has_semantic_merkle_verification(function, graph, ctx);
             if has_verification {
                 continue;
             }
@@ -1,9 +1,6 @@
 use std::collections::{BTreeMap, BTreeSet};
 
-use aztec_lint_aztec::SourceUnit;
-use aztec_lint_aztec::taint::{
-    TaintSinkKind, TaintSourceKind, analyze_intra_procedural, build_def_use_graph_with_semantic,
-};
+use aztec_lint_aztec::taint::{TaintSinkKind, TaintSourceKind, analyze_intra_procedural};
 use aztec_lint_core::diagnostics::Diagnostic;
 use aztec_lint_core::model::Span;
 use aztec_lint_core::policy::PRIVACY;
@@ -19,19 +16,10 @@ impl Rule for Aztec036SecretBranchAffectsEnqueueRule {
     }
 
     fn run(&self, ctx: &RuleContext<'_>, out: &mut Vec<Diagnostic>) {
-        let Some(model) = ctx.aztec_model() else {
+        let Some(graph) = ctx.taint_graph() else {
             return;
         };
-
-        let config = ctx.aztec_config();
-        let sources = ctx
-            .files()
-            .iter()
-            .map(|file| SourceUnit::new(file.path().to_string(), file.text().to_string()))
-            .collect::<Vec<_>>();
-        let graph =
-            build_def_use_graph_with_semantic(&sources, model, Some(ctx.semantic_model()), &config);
-        let analysis = analyze_intra_procedural(&graph);
+        let analysis = analyze_intra_procedural(graph);
         let file_texts = ctx
             .files()
             .iter()
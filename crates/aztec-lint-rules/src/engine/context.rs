@@ -1,12 +1,21 @@
 use std::cmp::min;
+use std::collections::{BTreeMap, BTreeSet};
 use std::io;
 use std::path::Path;
+use std::sync::{Mutex, MutexGuard, OnceLock, PoisonError};
+use std::time::{Duration, Instant};
 
+use aztec_lint_aztec::SourceUnit;
+use aztec_lint_aztec::taint::{DefUseGraph, build_def_use_graph_with_semantic};
 use aztec_lint_core::config::AztecConfig;
-use aztec_lint_core::config::RuleLevel;
+use aztec_lint_core::config::{ResolvedPathOverride, RuleLevel};
 use aztec_lint_core::diagnostics::{Confidence, Diagnostic, Severity, normalize_file_path};
+use aztec_lint_core::lints::{
+    LINT_GROUP_TOOL_PREFIX, LintGroup, LintOptionTable, LintOptionValue, find_lint,
+};
 use aztec_lint_core::model::AztecModel;
 use aztec_lint_core::model::{ProjectModel, SemanticModel, Span};
+use aztec_lint_core::timings::{TAINT_GRAPH_PHASE, Timings};
 
 use super::query::RuleQuery;
 
@@ -100,15 +109,19 @@ struct DirectiveScope {
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct PendingDirective {
     rule_id: String,
+    /// Name as written in the attribute: the rule id, or the group it came from.
+    label: String,
     level: RuleLevel,
     order: u32,
     root_scope_candidate: bool,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct ResolvedRuleLevel {
     pub level: RuleLevel,
     pub from_scoped_directive: bool,
+    /// Describes the `[[overrides]]` block that set the level, if one did.
+    pub path_override: Option<String>,
 }
 
 #[derive(Debug)]
@@ -119,6 +132,14 @@ pub struct RuleContext<'a> {
     semantic_model: Option<SemanticModel>,
     aztec_model: Option<AztecModel>,
     aztec_config: Option<AztecConfig>,
+    lint_options: BTreeMap<String, LintOptionTable>,
+    path_overrides: Vec<ResolvedPathOverride>,
+    /// Project root relative to the config directory, `/`-separated and empty
+    /// when they coincide; prefixed to file paths before matching overrides.
+    override_root: String,
+    /// Def-use graph shared by the taint rules, built on first use.
+    taint_graph: OnceLock<DefUseGraph>,
+    timings: Mutex<Timings>,
 }
 
 impl<'a> RuleContext<'a> {
@@ -169,6 +190,11 @@ impl<'a> RuleContext<'a> {
             semantic_model: None,
             aztec_model: None,
             aztec_config: None,
+            lint_options: BTreeMap::new(),
+            path_overrides: Vec::new(),
+            override_root: String::new(),
+            taint_graph: OnceLock::new(),
+            timings: Mutex::new(Timings::default()),
         }
     }
 
@@ -180,6 +206,20 @@ impl<'a> RuleContext<'a> {
         &self.files
     }
 
+    /// Drops the source files `keep` rejects, along with their directives, so
+    /// no rule reads them. Used for paths excluded by configuration.
+    pub fn retain_files(&mut self, mut keep: impl FnMut(&str) -> bool) {
+        self.files.retain(|file| keep(file.path()));
+        let kept = self
+            .files
+            .iter()
+            .map(|file| file.path.as_str())
+            .collect::<BTreeSet<_>>();
+        self.directives
+            .retain(|directive| kept.contains(directive.file.as_str()));
+        self.taint_graph = OnceLock::new();
+    }
+
     pub fn semantic_model(&self) -> &SemanticModel {
         self.semantic_model
             .as_ref()
@@ -189,6 +229,7 @@ impl<'a> RuleContext<'a> {
     pub fn set_semantic_model(&mut self, mut model: SemanticModel) {
         model.normalize();
         self.semantic_model = Some(model);
+        self.taint_graph = OnceLock::new();
     }
 
     pub fn query(&self) -> RuleQuery<'_> {
@@ -201,6 +242,7 @@ impl<'a> RuleContext<'a> {
 
     pub fn set_aztec_model(&mut self, model: AztecModel) {
         self.aztec_model = Some(model);
+        self.taint_graph = OnceLock::new();
     }
 
     pub fn aztec_config(&self) -> AztecConfig {
@@ -209,6 +251,110 @@ impl<'a> RuleContext<'a> {
 
     pub fn set_aztec_config(&mut self, config: AztecConfig) {
         self.aztec_config = Some(config);
+        self.taint_graph = OnceLock::new();
+    }
+
+    /// Def-use graph of the project's sources, or `None` without an Aztec
+    /// model. Built once per context and timed as the taint graph phase; call
+    /// it before running rules to keep its cost out of per-rule timings.
+    pub fn taint_graph(&self) -> Option<&DefUseGraph> {
+        let model = self.aztec_model.as_ref()?;
+        Some(self.taint_graph.get_or_init(|| {
+            let sources = self
+                .files
+                .iter()
+                .map(|file| SourceUnit::new(file.path().to_string(), file.text().to_string()))
+                .collect::<Vec<_>>();
+            self.time_phase(TAINT_GRAPH_PHASE, || {
+                build_def_use_graph_with_semantic(
+                    &sources,
+                    model,
+                    Some(self.semantic_model()),
+                    &self.aztec_config(),
+                )
+            })
+        }))
+    }
+
+    /// Installs the validated `[lints.<RULE_ID>]` tables from the loaded config.
+    pub fn set_lint_options(&mut self, options: BTreeMap<String, LintOptionTable>) {
+        self.lint_options = options;
+    }
+
+    /// Installs the resolved `[[overrides]]` blocks. Their globs are relative to
+    /// the config directory, so `project_root` locates this project's files
+    /// from there (`""` when the config sits at the project root).
+    pub fn set_path_overrides(
+        &mut self,
+        overrides: Vec<ResolvedPathOverride>,
+        project_root: impl Into<String>,
+    ) {
+        self.path_overrides = overrides;
+        self.override_root = normalize_file_path(&project_root.into())
+            .trim_end_matches('/')
+            .to_string();
+    }
+
+    /// Configured value of `option` for `rule_id` in `file`. The last matching
+    /// `[[overrides]]` block that sets it wins over `[lints.<RULE_ID>]`, which
+    /// falls back to the default declared in the lint catalog.
+    ///
+    /// Panics if the catalog does not declare `option` for `rule_id`; that is a
+    /// rule implementation bug rather than a configuration error.
+    pub fn lint_option(&self, rule_id: &str, option: &str, file: &str) -> LintOptionValue {
+        let (lint, spec) = find_lint(rule_id)
+            .and_then(|lint| {
+                lint.options
+                    .iter()
+                    .find(|spec| spec.name == option)
+                    .map(|spec| (lint, spec))
+            })
+            .unwrap_or_else(|| panic!("{rule_id} does not declare option '{option}'"));
+        let path = self.override_path(file);
+        self.path_overrides
+            .iter()
+            .rev()
+            .filter(|path_override| path_override.matching_pattern(&path).is_some())
+            .find_map(|path_override| path_override.lints.get(lint.id)?.get(option))
+            .or_else(|| self.lint_options.get(lint.id)?.get(option))
+            .filter(|value| value.matches(spec.default))
+            .cloned()
+            .unwrap_or_else(|| spec.default.value())
+    }
+
+    pub fn lint_option_usize(&self, rule_id: &str, option: &str, file: &str) -> usize {
+        self.lint_option(rule_id, option, file)
+            .as_usize()
+            .unwrap_or(usize::MAX)
+    }
+
+    pub fn lint_option_strings(&self, rule_id: &str, option: &str, file: &str) -> Vec<String> {
+        self.lint_option(rule_id, option, file)
+            .as_string_list()
+            .map(<[String]>::to_vec)
+            .unwrap_or_default()
+    }
+
+    /// Runs `f` and adds its wall time to the named analysis phase. Safe to call
+    /// from rules executing concurrently.
+    pub fn time_phase<T>(&self, phase: &str, f: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let result = f();
+        self.lock_timings().record_phase(phase, started.elapsed());
+        result
+    }
+
+    pub(crate) fn record_rule_time(&self, rule_id: &str, elapsed: Duration) {
+        self.lock_timings().record_rule(rule_id, elapsed);
+    }
+
+    /// Phase and rule wall times recorded while rules ran against this context.
+    pub fn timings(&self) -> Timings {
+        self.lock_timings().clone()
+    }
+
+    fn lock_timings(&self) -> MutexGuard<'_, Timings> {
+        self.timings.lock().unwrap_or_else(PoisonError::into_inner)
     }
 
     pub fn suppression_reason(&self, rule_id: &str, span: &Span) -> Option<&str> {
@@ -227,6 +373,27 @@ impl<'a> RuleContext<'a> {
         })
     }
 
+    /// Whether an `[[overrides]]` block matching one of the files raises
+    /// `rule_id` above `allow`.
+    pub(crate) fn has_non_allow_path_override(&self, rule_id: &str) -> bool {
+        let normalized_rule = normalize_rule_id(rule_id);
+        self.path_overrides
+            .iter()
+            .filter(|path_override| {
+                path_override
+                    .levels
+                    .get(&normalized_rule)
+                    .is_some_and(|level| *level != RuleLevel::Allow)
+            })
+            .any(|path_override| {
+                self.files.iter().any(|file| {
+                    path_override
+                        .matching_pattern(&self.override_path(file.path()))
+                        .is_some()
+                })
+            })
+    }
+
     pub(crate) fn resolve_rule_level(
         &self,
         rule_id: &str,
@@ -239,18 +406,47 @@ impl<'a> RuleContext<'a> {
 
         let best = self.best_directive(&normalized_rule, &normalized_file, start);
 
-        match best {
-            Some(directive) => ResolvedRuleLevel {
+        if let Some(directive) = best {
+            return ResolvedRuleLevel {
                 level: directive.level,
                 from_scoped_directive: true,
+                path_override: None,
+            };
+        }
+
+        // Later blocks win, like later keys in a profile.
+        let path = self.override_path(&normalized_file);
+        let path_override = self.path_overrides.iter().rev().find_map(|path_override| {
+            let level = path_override.levels.get(&normalized_rule)?;
+            let pattern = path_override.matching_pattern(&path)?;
+            Some((path_override.index, pattern, *level))
+        });
+        match path_override {
+            Some((index, pattern, level)) => ResolvedRuleLevel {
+                level,
+                from_scoped_directive: false,
+                path_override: Some(format!(
+                    "[[overrides]] entry {index} (paths: \"{pattern}\")"
+                )),
             },
             None => ResolvedRuleLevel {
                 level: baseline,
                 from_scoped_directive: false,
+                path_override: None,
             },
         }
     }
 
+    /// `file` relative to the config directory, for matching override globs.
+    fn override_path(&self, file: &str) -> String {
+        let file = normalize_file_path(file);
+        if self.override_root.is_empty() {
+            file
+        } else {
+            format!("{}/{file}", self.override_root)
+        }
+    }
+
     fn best_directive(
         &self,
         normalized_rule: &str,
@@ -330,16 +526,15 @@ fn parse_file_directives(source: &SourceFile) -> Vec<DirectiveScope> {
         if let Some(kind) = line_item_kind(trimmed) {
             let scope_end = find_item_scope_end(source.text(), offset, offset + line.len());
             for directive in pending.drain(..) {
-                let rule_id = normalize_rule_id(&directive.rule_id);
                 scopes.push(DirectiveScope {
-                    rule_id: rule_id.clone(),
+                    rule_id: normalize_rule_id(&directive.rule_id),
                     file: source.path().to_string(),
                     start: u32::try_from(offset).unwrap_or(u32::MAX),
                     end: u32::try_from(scope_end).unwrap_or(u32::MAX),
                     level: directive.level,
                     kind,
                     order: directive.order,
-                    reason: format!("{}({rule_id})", directive.level),
+                    reason: format!("{}({})", directive.level, directive.label),
                 });
             }
         } else if !trimmed.is_empty() && !trimmed.starts_with("#[") {
@@ -369,16 +564,15 @@ fn flush_file_level_pending(
         if !directive.root_scope_candidate {
             continue;
         }
-        let rule_id = normalize_rule_id(&directive.rule_id);
         scopes.push(DirectiveScope {
-            rule_id: rule_id.clone(),
+            rule_id: normalize_rule_id(&directive.rule_id),
             file: file.to_string(),
             start: 0,
             end: file_end,
             level: directive.level,
             kind: DirectiveScopeKind::File,
             order: directive.order,
-            reason: format!("{}({rule_id})", directive.level),
+            reason: format!("{}({})", directive.level, directive.label),
         });
     }
 }
@@ -412,10 +606,11 @@ fn extract_directives(
         let content_end = content_start + close_rel;
         let content = &input[content_start..content_end];
 
-        for rule_id in extract_rule_ids(content) {
+        for (rule_id, label) in extract_rule_ids(content) {
             *order = order.saturating_add(1);
             matched.push(PendingDirective {
                 rule_id,
+                label,
                 level,
                 order: *order,
                 root_scope_candidate,
@@ -428,13 +623,28 @@ fn extract_directives(
     matched
 }
 
-fn extract_rule_ids(input: &str) -> Vec<String> {
-    let mut matched = Vec::<String>::new();
+/// Returns `(rule_id, label)` pairs; `aztec::<group>` expands to one pair per
+/// group member, all labelled with the group.
+fn extract_rule_ids(input: &str) -> Vec<(String, String)> {
+    let mut matched = Vec::<(String, String)>::new();
     for raw_token in input.split(',') {
         let token = raw_token.trim();
         if token.is_empty() {
             continue;
         }
+        if let Some(group) = token
+            .strip_prefix(LINT_GROUP_TOOL_PREFIX)
+            .and_then(LintGroup::parse)
+        {
+            let label = format!("{LINT_GROUP_TOOL_PREFIX}{}", group.as_str());
+            matched.extend(
+                group
+                    .members()
+                    .into_iter()
+                    .map(|rule_id| (rule_id.to_string(), label.clone())),
+            );
+            continue;
+        }
         let candidate = token
             .split("::")
             .last()
@@ -452,7 +662,7 @@ fn extract_rule_ids(input: &str) -> Vec<String> {
             && normalized.chars().any(|ch| ch.is_ascii_alphabetic())
             && normalized.chars().any(|ch| ch.is_ascii_digit());
         if looks_like_rule {
-            matched.push(normalized);
+            matched.push((normalized.clone(), normalized));
         }
     }
     matched
@@ -539,12 +749,17 @@ fn find_item_scope_end(source: &str, item_start: usize, line_end: usize) -> usiz
 
 #[cfg(test)]
 mod tests {
-    use aztec_lint_core::config::RuleLevel;
+    use std::collections::BTreeMap;
+
+    use aztec_lint_aztec::{SourceUnit, build_aztec_model};
+    use aztec_lint_core::config::{AztecConfig, ResolvedPathOverride, RuleLevel};
+    use aztec_lint_core::lints::{LintOptionTable, LintOptionValue};
     use aztec_lint_core::model::{
         CfgBlock, CfgEdge, CfgEdgeKind, DfgEdge, DfgEdgeKind, ExpressionCategory, ProjectModel,
         SemanticExpression, SemanticFunction, SemanticModel, SemanticStatement, Span,
         StatementCategory, TypeCategory,
     };
+    use aztec_lint_core::timings::TAINT_GRAPH_PHASE;
 
     use super::RuleContext;
 
@@ -712,6 +927,32 @@ fn main() {
         );
     }
 
+    #[test]
+    fn lint_group_directives_apply_to_every_group_member() {
+        let project = ProjectModel::default();
+        let source = r#"
+#[allow(aztec::privacy)]
+#[deny(AZTEC003)]
+fn main() {
+    let secret = 7;
+}
+"#;
+        let context = RuleContext::from_sources(
+            &project,
+            vec![("src/main.nr".to_string(), source.to_string())],
+        );
+
+        let marker = source.find("secret").expect("secret marker should exist");
+        let span = context.files()[0].span_for_range(marker, marker + 6);
+
+        assert_eq!(
+            context.suppression_reason("AZTEC001", &span),
+            Some("allow(aztec::privacy)")
+        );
+        assert_eq!(context.suppression_reason("AZTEC003", &span), None);
+        assert_eq!(context.suppression_reason("NOIR001", &span), None);
+    }
+
     #[test]
     fn supports_same_line_allow_and_item() {
         let project = ProjectModel::default();
@@ -932,4 +1173,101 @@ fn main() {
         assert_eq!(dfg.edges[0].to_node_id, "def::1");
         assert_eq!(dfg.edges[1].to_node_id, "def::2");
     }
+
+    #[test]
+    fn path_override_lint_options_take_precedence_for_matching_files() {
+        let project = ProjectModel::default();
+        let mut context = RuleContext::from_sources(
+            &project,
+            vec![
+                ("src/main.nr".to_string(), "fn main() {}".to_string()),
+                (
+                    "src/generated/out.nr".to_string(),
+                    "fn out() {}".to_string(),
+                ),
+            ],
+        );
+        let complexity_limit = |limit| {
+            BTreeMap::from([(
+                "NOIR110".to_string(),
+                LintOptionTable::from([(
+                    "complexity_limit".to_string(),
+                    LintOptionValue::Integer(limit),
+                )]),
+            )])
+        };
+        context.set_lint_options(complexity_limit(10));
+        context.set_path_overrides(
+            vec![ResolvedPathOverride {
+                index: 1,
+                paths: vec!["app/src/generated/**".to_string()],
+                levels: BTreeMap::new(),
+                lints: complexity_limit(40),
+            }],
+            "./app/",
+        );
+
+        assert_eq!(
+            context.lint_option_usize("NOIR110", "complexity_limit", "src/main.nr"),
+            10
+        );
+        assert_eq!(
+            context.lint_option_usize("NOIR110", "complexity_limit", "src/generated/out.nr"),
+            40
+        );
+        assert_eq!(
+            context.lint_option_usize("NOIR120", "nesting_limit", "src/generated/out.nr"),
+            3
+        );
+    }
+
+    #[test]
+    fn retain_files_drops_files_and_their_directives() {
+        let project = ProjectModel::default();
+        let mut context = RuleContext::from_sources(
+            &project,
+            vec![
+                ("src/main.nr".to_string(), "fn main() {}".to_string()),
+                (
+                    "src/generated/out.nr".to_string(),
+                    "#[deny(NOIR100)]\nfn out() {}".to_string(),
+                ),
+            ],
+        );
+
+        context.retain_files(|path| !path.starts_with("src/generated/"));
+
+        let paths = context
+            .files()
+            .iter()
+            .map(|file| file.path())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, vec!["src/main.nr"]);
+        assert!(!context.has_non_allow_scoped_directive("NOIR100"));
+    }
+
+    #[test]
+    fn taint_graph_is_built_once_and_timed_as_a_phase() {
+        let project = ProjectModel::default();
+        let source = "#[aztec]\npub contract C {\n    #[external(\"private\")]\n    fn f(secret: Field) {\n        debug_log(secret);\n    }\n}\n";
+        let mut context = RuleContext::from_sources(
+            &project,
+            vec![("src/main.nr".to_string(), source.to_string())],
+        );
+        assert!(context.taint_graph().is_none());
+
+        let config = AztecConfig::default();
+        context.set_aztec_config(config.clone());
+        context.set_aztec_model(build_aztec_model(
+            &[SourceUnit::new("src/main.nr", source)],
+            &config,
+        ));
+
+        let first = context
+            .taint_graph()
+            .expect("graph should build with a model");
+        let second = context.taint_graph().expect("graph should be cached");
+        assert!(std::ptr::eq(first, second));
+        assert!(context.timings().phases().contains_key(TAINT_GRAPH_PHASE));
+    }
 }
@@ -5,17 +5,21 @@ pub mod registry;
 use std::collections::{BTreeMap, BTreeSet};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::time::Instant;
 
 use aztec_lint_core::config::RuleLevel;
 use aztec_lint_core::diagnostics::{
     Diagnostic, DiagnosticViolation, Severity, sort_diagnostics, validate_diagnostics,
 };
 use aztec_lint_core::lints::{all_lints, find_lint};
+use aztec_lint_core::parallel::map_ordered;
 
 use self::context::RuleContext;
 use self::registry::{RuleRegistration, full_registry};
 
-pub trait Rule {
+/// A lint implementation. Rules may run concurrently on worker threads, so
+/// implementations must be shareable across threads.
+pub trait Rule: Send + Sync {
     fn id(&self) -> &'static str;
     fn run(&self, ctx: &RuleContext<'_>, out: &mut Vec<Diagnostic>);
 }
@@ -23,6 +27,8 @@ pub trait Rule {
 #[derive(Clone, Debug, Default)]
 pub struct RuleRunSettings {
     pub effective_levels: BTreeMap<String, RuleLevel>,
+    /// Maximum number of rules executed concurrently; `0` and `1` run sequentially.
+    pub jobs: usize,
 }
 
 pub struct RuleEngine {
@@ -81,7 +87,7 @@ impl RuleEngine {
         ctx: &RuleContext<'_>,
         settings: &RuleRunSettings,
     ) -> Result<Vec<Diagnostic>, RuleEngineError> {
-        self.run(ctx, &settings.effective_levels)
+        self.run_rules(ctx, &settings.effective_levels, settings.jobs)
     }
 
     pub fn run(
@@ -89,53 +95,35 @@ impl RuleEngine {
         ctx: &RuleContext<'_>,
         effective_levels: &BTreeMap<String, RuleLevel>,
     ) -> Result<Vec<Diagnostic>, RuleEngineError> {
-        let mut diagnostics = Vec::<Diagnostic>::new();
-
-        for registration in &self.registry {
-            let Some(level) = effective_levels.get(registration.lint.id).copied() else {
-                continue;
-            };
-            if level == RuleLevel::Allow
-                && !ctx.has_non_allow_scoped_directive(registration.lint.id)
-            {
-                continue;
-            }
-
-            // Run each rule against an isolated output buffer so a rule cannot
-            // mutate diagnostics emitted by previously executed rules.
-            let mut rule_diagnostics = Vec::<Diagnostic>::new();
-            registration.rule.run(ctx, &mut rule_diagnostics);
-
-            for diagnostic in &mut rule_diagnostics {
-                diagnostic.rule_id = registration.lint.id.to_string();
-                diagnostic.confidence = registration.lint.confidence;
-                diagnostic.policy = registration.lint.policy.to_string();
-            }
+        self.run_rules(ctx, effective_levels, 1)
+    }
 
-            let mut resolved_diagnostics = Vec::<Diagnostic>::new();
-            for mut diagnostic in rule_diagnostics {
-                let resolved_level =
-                    ctx.resolve_rule_level(registration.lint.id, &diagnostic.primary_span, level);
-
-                if resolved_level.level == RuleLevel::Allow {
-                    if !resolved_level.from_scoped_directive {
-                        continue;
-                    }
-                    let reason = ctx
-                        .suppression_reason(registration.lint.id, &diagnostic.primary_span)
-                        .map(str::to_string)
-                        .unwrap_or_else(|| format!("allow({})", registration.lint.id));
-                    diagnostic.suppressed = true;
-                    diagnostic.suppression_reason = Some(reason);
-                    diagnostic.severity = Severity::Warning;
-                } else {
-                    diagnostic.severity = level_to_severity(resolved_level.level);
-                }
-                resolved_diagnostics.push(diagnostic);
-            }
+    fn run_rules(
+        &self,
+        ctx: &RuleContext<'_>,
+        effective_levels: &BTreeMap<String, RuleLevel>,
+        jobs: usize,
+    ) -> Result<Vec<Diagnostic>, RuleEngineError> {
+        let selected = self
+            .registry
+            .iter()
+            .filter_map(|registration| {
+                let level = effective_levels.get(registration.lint.id).copied()?;
+                (level != RuleLevel::Allow
+                    || ctx.has_non_allow_scoped_directive(registration.lint.id)
+                    || ctx.has_non_allow_path_override(registration.lint.id))
+                .then_some((registration, level))
+            })
+            .collect::<Vec<_>>();
 
-            diagnostics.extend(resolved_diagnostics);
-        }
+        // Per-rule results are concatenated in registry order, so the output
+        // is identical to a sequential run regardless of scheduling.
+        let mut diagnostics = map_ordered(&selected, jobs, |(registration, level)| {
+            run_registered_rule(registration, *level, ctx)
+        })
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
 
         sort_diagnostics(&mut diagnostics);
 
@@ -148,6 +136,50 @@ impl RuleEngine {
     }
 }
 
+fn run_registered_rule(
+    registration: &RuleRegistration,
+    level: RuleLevel,
+    ctx: &RuleContext<'_>,
+) -> Vec<Diagnostic> {
+    // Run each rule against an isolated output buffer so a rule cannot
+    // mutate diagnostics emitted by other rules.
+    let mut rule_diagnostics = Vec::<Diagnostic>::new();
+    let started = Instant::now();
+    registration.rule.run(ctx, &mut rule_diagnostics);
+    ctx.record_rule_time(registration.lint.id, started.elapsed());
+
+    for diagnostic in &mut rule_diagnostics {
+        diagnostic.rule_id = registration.lint.id.to_string();
+        diagnostic.confidence = registration.lint.confidence;
+        diagnostic.policy = registration.lint.policy.to_string();
+    }
+
+    let mut resolved_diagnostics = Vec::<Diagnostic>::new();
+    for mut diagnostic in rule_diagnostics {
+        let resolved_level =
+            ctx.resolve_rule_level(registration.lint.id, &diagnostic.primary_span, level);
+
+        if resolved_level.level == RuleLevel::Allow {
+            let reason = if resolved_level.from_scoped_directive {
+                ctx.suppression_reason(registration.lint.id, &diagnostic.primary_span)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("allow({})", registration.lint.id))
+            } else if let Some(path_override) = resolved_level.path_override {
+                format!("allow({}) by {path_override}", registration.lint.id)
+            } else {
+                continue;
+            };
+            diagnostic.suppressed = true;
+            diagnostic.suppression_reason = Some(reason);
+            diagnostic.severity = Severity::Warning;
+        } else {
+            diagnostic.severity = level_to_severity(resolved_level.level);
+        }
+        resolved_diagnostics.push(diagnostic);
+    }
+    resolved_diagnostics
+}
+
 fn validate_registry_metadata(registry: &[RuleRegistration]) {
     let mut seen_rule_ids = BTreeSet::<&'static str>::new();
 
@@ -250,7 +282,7 @@ mod tests {
     use std::collections::BTreeMap;
     use std::panic::{AssertUnwindSafe, catch_unwind};
 
-    use aztec_lint_core::config::RuleLevel;
+    use aztec_lint_core::config::{ResolvedPathOverride, RuleLevel};
     use aztec_lint_core::diagnostics::{Confidence, Diagnostic, DiagnosticViolationKind, Severity};
     use aztec_lint_core::lints::{
         LintCategory, LintDocs, LintLifecycleState, LintMaturityTier, LintSpec, find_lint,
@@ -262,7 +294,9 @@ mod tests {
     use crate::engine::context::RuleContext;
     use crate::engine::registry::{RuleRegistration, full_registry};
 
-    use super::{RuleEngine, RuleEngineError, validate_registry_integrity_with_catalog};
+    use super::{
+        RuleEngine, RuleEngineError, RuleRunSettings, validate_registry_integrity_with_catalog,
+    };
 
     struct TestRule;
 
@@ -350,6 +384,72 @@ fn main() {
         );
     }
 
+    struct FixedSpanRule(&'static str);
+
+    impl Rule for FixedSpanRule {
+        fn id(&self) -> &'static str {
+            self.0
+        }
+
+        fn run(
+            &self,
+            ctx: &RuleContext<'_>,
+            out: &mut Vec<aztec_lint_core::diagnostics::Diagnostic>,
+        ) {
+            let file = &ctx.files()[0];
+            for (offset, _) in file.text().match_indices("value") {
+                out.push(ctx.diagnostic(
+                    self.id(),
+                    aztec_lint_core::policy::MAINTAINABILITY,
+                    "fixed span",
+                    file.span_for_range(offset, offset + "value".len()),
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn parallel_rule_execution_matches_sequential_output() {
+        let project = ProjectModel::default();
+        let context = RuleContext::from_sources(
+            &project,
+            vec![(
+                "src/main.nr".to_string(),
+                "fn main() {\n    let value = 42;\n    assert(value == 42);\n}\n".to_string(),
+            )],
+        );
+        let rule_ids = ["NOIR001", "NOIR002", "NOIR100", "NOIR110", "NOIR120"];
+        let engine = RuleEngine::with_registry(
+            rule_ids
+                .iter()
+                .map(|rule_id| RuleRegistration {
+                    lint: find_lint(rule_id).expect("rule should be in canonical catalog"),
+                    rule: Box::new(FixedSpanRule(rule_id)),
+                })
+                .collect(),
+        );
+        let effective_levels = rule_ids
+            .iter()
+            .map(|rule_id| (rule_id.to_string(), RuleLevel::Warn))
+            .collect::<BTreeMap<_, _>>();
+
+        let sequential = engine
+            .run(&context, &effective_levels)
+            .expect("sequential run should succeed");
+        let parallel = engine
+            .run_with_settings(
+                &context,
+                &RuleRunSettings {
+                    effective_levels,
+                    jobs: 4,
+                },
+            )
+            .expect("parallel run should succeed");
+
+        assert_eq!(sequential.len(), rule_ids.len() * 2);
+        assert_eq!(parallel, sequential);
+    }
+
     #[test]
     fn engine_applies_scoped_allow_warn_and_deny_levels() {
         let project = ProjectModel::default();
@@ -448,6 +548,117 @@ fn baseline_scope() {
         assert_eq!(diagnostics[0].severity, Severity::Error);
     }
 
+    struct EveryFileRule;
+
+    impl Rule for EveryFileRule {
+        fn id(&self) -> &'static str {
+            "NOIR100"
+        }
+
+        fn run(
+            &self,
+            ctx: &RuleContext<'_>,
+            out: &mut Vec<aztec_lint_core::diagnostics::Diagnostic>,
+        ) {
+            for file in ctx.files() {
+                let offset = file.text().find("value").unwrap_or_default();
+                out.push(ctx.diagnostic(
+                    self.id(),
+                    aztec_lint_core::policy::MAINTAINABILITY,
+                    file.path(),
+                    file.span_for_range(offset, offset + "value".len()),
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn path_overrides_apply_after_scoped_directives() {
+        let project = ProjectModel::default();
+        let mut context = RuleContext::from_sources(
+            &project,
+            vec![
+                (
+                    "src/main.nr".to_string(),
+                    "fn main() { let value = 1; }\n".to_string(),
+                ),
+                (
+                    "examples/demo.nr".to_string(),
+                    "fn demo() { let value = 1; }\n".to_string(),
+                ),
+                (
+                    "examples/strict.nr".to_string(),
+                    "#[warn(NOIR100)]\nfn strict() { let value = 1; }\n".to_string(),
+                ),
+                (
+                    "lib/other.nr".to_string(),
+                    "fn other() { let value = 1; }\n".to_string(),
+                ),
+            ],
+        );
+        context.set_path_overrides(
+            vec![
+                ResolvedPathOverride {
+                    index: 1,
+                    paths: vec!["contracts/*/src/".to_string()],
+                    levels: BTreeMap::from([("NOIR100".to_string(), RuleLevel::Deny)]),
+                    lints: BTreeMap::new(),
+                },
+                ResolvedPathOverride {
+                    index: 2,
+                    paths: vec!["contracts/*/examples/".to_string()],
+                    levels: BTreeMap::from([("NOIR100".to_string(), RuleLevel::Allow)]),
+                    lints: BTreeMap::new(),
+                },
+                ResolvedPathOverride {
+                    index: 3,
+                    paths: vec!["lib/".to_string()],
+                    levels: BTreeMap::from([("NOIR100".to_string(), RuleLevel::Deny)]),
+                    lints: BTreeMap::new(),
+                },
+            ],
+            "contracts/token",
+        );
+        let lint = find_lint("NOIR100").expect("NOIR100 should be in canonical catalog");
+        let engine = RuleEngine::with_registry(vec![RuleRegistration {
+            lint,
+            rule: Box::new(EveryFileRule),
+        }]);
+
+        let diagnostics = engine
+            .run(
+                &context,
+                &BTreeMap::from([("NOIR100".to_string(), RuleLevel::Allow)]),
+            )
+            .expect("engine run should succeed");
+        let by_file = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                (
+                    diagnostic.message.as_str(),
+                    diagnostic.severity,
+                    diagnostic.suppression_reason.as_deref(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            by_file,
+            vec![
+                (
+                    "examples/demo.nr",
+                    Severity::Warning,
+                    Some(
+                        "allow(NOIR100) by [[overrides]] entry 2 (paths: \"contracts/*/examples/\")"
+                    ),
+                ),
+                ("examples/strict.nr", Severity::Warning, None),
+                ("src/main.nr", Severity::Error, None),
+            ]
+        );
+        assert!(diagnostics[0].suppressed);
+    }
+
     struct StaticRule {
         id: &'static str,
     }
@@ -480,6 +691,8 @@ fn baseline_scope() {
             default_level: RuleLevel::Warn,
             confidence: Confidence::Medium,
             lifecycle: LintLifecycleState::Active,
+            fix_applicability: None,
+            options: &[],
             docs: test_docs(),
         }))
     }
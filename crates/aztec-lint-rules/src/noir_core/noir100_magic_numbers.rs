@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use aztec_lint_core::diagnostics::normalize_file_path;
 use aztec_lint_core::diagnostics::{Applicability, Diagnostic, SuggestionGroup, TextEdit};
@@ -15,6 +15,12 @@ use crate::noir_core::util::{
 pub struct Noir100MagicNumbersRule;
 pub struct Noir101RepeatedLocalInitMagicNumbersRule;
 
+/// Options are declared on NOIR100 and shared with NOIR101 so both rules agree
+/// on which literals are magic.
+const OPTIONS_RULE_ID: &str = "NOIR100";
+const ALLOWED_LITERALS_OPTION: &str = "allowed_literals";
+const HASH_DOMAIN_TAGS_OPTION: &str = "hash_domain_tags";
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum MagicLiteralSignal {
     High,
@@ -22,6 +28,30 @@ enum MagicLiteralSignal {
     Ignore,
 }
 
+/// Literal sets from `[lints.NOIR100]` for one file, normalized with
+/// [`normalize_literal`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct MagicLiteralSettings {
+    allowed_literals: BTreeSet<String>,
+    hash_domain_tags: BTreeSet<String>,
+}
+
+impl MagicLiteralSettings {
+    fn from_context(ctx: &RuleContext<'_>, file: &str) -> Self {
+        Self {
+            allowed_literals: normalized_literal_option(ctx, ALLOWED_LITERALS_OPTION, file),
+            hash_domain_tags: normalized_literal_option(ctx, HASH_DOMAIN_TAGS_OPTION, file),
+        }
+    }
+}
+
+fn normalized_literal_option(ctx: &RuleContext<'_>, option: &str, file: &str) -> BTreeSet<String> {
+    ctx.lint_option_strings(OPTIONS_RULE_ID, option, file)
+        .iter()
+        .map(|literal| normalize_literal(literal))
+        .collect()
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct LocalInitCandidate {
     file: String,
@@ -50,6 +80,7 @@ impl Noir100MagicNumbersRule {
         let semantic = ctx.semantic_model();
         let files = file_map(ctx.files());
         let include_test_paths = include_test_path_magic_number_checks();
+        let mut settings_by_file = BTreeMap::<String, MagicLiteralSettings>::new();
 
         for expression in semantic.expressions.iter().filter(|expression| {
             expression.category == ExpressionCategory::Literal
@@ -76,10 +107,13 @@ impl Noir100MagicNumbersRule {
             if is_named_constant_declaration_context(file.text(), expression_start) {
                 continue;
             }
+            let settings = settings_by_file
+                .entry(file.path().to_string())
+                .or_insert_with(|| MagicLiteralSettings::from_context(ctx, file.path()));
 
             for (literal, relative_offset) in extract_numeric_literals(source) {
                 let start = expression_start.saturating_add(relative_offset);
-                if magic_literal_signal(file.text(), start, literal.len(), &literal)
+                if magic_literal_signal(file.text(), start, literal.len(), &literal, settings)
                     != MagicLiteralSignal::High
                 {
                     continue;
@@ -95,6 +129,7 @@ impl Noir100MagicNumbersRule {
             if !include_test_paths && is_test_path(file.path()) {
                 continue;
             }
+            let settings = &MagicLiteralSettings::from_context(ctx, file.path());
             let mut offset = 0usize;
 
             for line in file.text().lines() {
@@ -102,7 +137,7 @@ impl Noir100MagicNumbersRule {
 
                 for (literal, column) in extract_numeric_literals(code) {
                     let start = offset + column;
-                    if magic_literal_signal(file.text(), start, literal.len(), &literal)
+                    if magic_literal_signal(file.text(), start, literal.len(), &literal, settings)
                         != MagicLiteralSignal::High
                     {
                         continue;
@@ -135,6 +170,7 @@ impl Noir101RepeatedLocalInitMagicNumbersRule {
         let semantic = ctx.semantic_model();
         let files = file_map(ctx.files());
         let include_test_paths = include_test_path_magic_number_checks();
+        let mut settings_by_file = BTreeMap::<String, MagicLiteralSettings>::new();
         let mut candidates = Vec::<LocalInitCandidate>::new();
 
         for expression in semantic.expressions.iter().filter(|expression| {
@@ -162,11 +198,14 @@ impl Noir101RepeatedLocalInitMagicNumbersRule {
             if is_named_constant_declaration_context(file.text(), expression_start) {
                 continue;
             }
+            let settings = settings_by_file
+                .entry(file.path().to_string())
+                .or_insert_with(|| MagicLiteralSettings::from_context(ctx, file.path()));
 
             for (literal, relative_offset) in extract_numeric_literals(source) {
                 let start = expression_start.saturating_add(relative_offset);
                 let literal_len = literal.len();
-                if magic_literal_signal(file.text(), start, literal_len, &literal)
+                if magic_literal_signal(file.text(), start, literal_len, &literal, settings)
                     != MagicLiteralSignal::LocalInit
                 {
                     continue;
@@ -192,6 +231,7 @@ impl Noir101RepeatedLocalInitMagicNumbersRule {
             if !include_test_paths && is_test_path(file.path()) {
                 continue;
             }
+            let settings = &MagicLiteralSettings::from_context(ctx, file.path());
             let mut offset = 0usize;
             let scopes = text_fallback_function_scopes(file.text());
 
@@ -201,7 +241,7 @@ impl Noir101RepeatedLocalInitMagicNumbersRule {
                 for (literal, column) in extract_numeric_literals(code) {
                     let start = offset + column;
                     let literal_len = literal.len();
-                    if magic_literal_signal(file.text(), start, literal_len, &literal)
+                    if magic_literal_signal(file.text(), start, literal_len, &literal, settings)
                         != MagicLiteralSignal::LocalInit
                     {
                         continue;
@@ -238,11 +278,12 @@ fn magic_literal_signal(
     offset: usize,
     literal_len: usize,
     literal: &str,
+    settings: &MagicLiteralSettings,
 ) -> MagicLiteralSignal {
     if is_fixture_context(source, offset) {
         return MagicLiteralSignal::Ignore;
     }
-    if is_poseidon2_domain_tag_context(source, offset, literal_len, literal) {
+    if is_poseidon2_domain_tag_context(source, offset, literal_len, literal, settings) {
         return MagicLiteralSignal::Ignore;
     }
     if is_named_constant_declaration_context(source, offset) {
@@ -254,7 +295,10 @@ fn magic_literal_signal(
     if is_array_type_length_annotation_context(source, offset, literal_len) {
         return MagicLiteralSignal::Ignore;
     }
-    if is_zero_or_one_literal(literal) {
+    if settings
+        .allowed_literals
+        .contains(&normalize_literal(literal))
+    {
         return MagicLiteralSignal::Ignore;
     }
     if is_sensitive_magic_context(source, offset, literal_len) {
@@ -505,6 +549,7 @@ fn is_poseidon2_domain_tag_context(
     offset: usize,
     literal_len: usize,
     literal: &str,
+    settings: &MagicLiteralSettings,
 ) -> bool {
     if offset + literal_len > source.len() {
         return false;
@@ -551,19 +596,20 @@ fn is_poseidon2_domain_tag_context(
         return false;
     }
 
-    matches_known_hash_domain_tag(first, literal)
+    matches_known_hash_domain_tag(first, literal, settings)
 }
 
-fn matches_known_hash_domain_tag(first_item: &str, literal: &str) -> bool {
-    const KNOWN_TAGS: &[&str] = &[
-        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16",
-        "32", "64", "128", "160", "192", "256", "512", "1024",
-    ];
+fn matches_known_hash_domain_tag(
+    first_item: &str,
+    literal: &str,
+    settings: &MagicLiteralSettings,
+) -> bool {
     if first_item != literal {
         return false;
     }
-    let compact = literal.chars().filter(|ch| *ch != '_').collect::<String>();
-    KNOWN_TAGS.contains(&compact.as_str())
+    settings
+        .hash_domain_tags
+        .contains(&normalize_literal(literal))
 }
 
 fn first_top_level_item(input: &str) -> &str {
@@ -866,15 +912,20 @@ fn line_bounds(source: &str, offset: usize) -> (usize, usize) {
     (start, end)
 }
 
-fn is_zero_or_one_literal(literal: &str) -> bool {
-    let mut value = 0u8;
-    for byte in literal.bytes() {
-        if !byte.is_ascii_digit() {
-            return false;
-        }
-        value = (value.saturating_mul(10).saturating_add(byte - b'0')).min(2);
+/// Drops `_` separators, lowercases, and strips leading zeros from decimal
+/// literals so `1_000`, `01000` and `1000` compare equal.
+fn normalize_literal(literal: &str) -> String {
+    let compact = literal
+        .trim()
+        .chars()
+        .filter(|ch| *ch != '_')
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if !compact.is_empty() && compact.bytes().all(|byte| byte.is_ascii_digit()) {
+        let trimmed = compact.trim_start_matches('0');
+        return if trimmed.is_empty() { "0" } else { trimmed }.to_string();
     }
-    value <= 1
+    compact
 }
 
 fn strip_line_comment(line: &str) -> &str {
@@ -883,6 +934,9 @@ fn strip_line_comment(line: &str) -> &str {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
+    use aztec_lint_core::lints::{LintOptionTable, LintOptionValue};
     use aztec_lint_core::model::{
         ExpressionCategory, ProjectModel, SemanticExpression, SemanticFunction, Span, TypeCategory,
     };
@@ -890,7 +944,19 @@ mod tests {
     use crate::Rule;
     use crate::engine::context::RuleContext;
 
-    use super::{Noir100MagicNumbersRule, Noir101RepeatedLocalInitMagicNumbersRule};
+    use super::{
+        Noir100MagicNumbersRule, Noir101RepeatedLocalInitMagicNumbersRule, normalize_literal,
+    };
+
+    fn noir100_options(option: &str, literals: &[&str]) -> BTreeMap<String, LintOptionTable> {
+        BTreeMap::from([(
+            "NOIR100".to_string(),
+            LintOptionTable::from([(
+                option.to_string(),
+                LintOptionValue::StringList(literals.iter().map(ToString::to_string).collect()),
+            )]),
+        )])
+    }
 
     #[test]
     fn reports_magic_numbers() {
@@ -1370,4 +1436,50 @@ mod tests {
                 .contains("repeated local initializer magic number")
         }));
     }
+
+    #[test]
+    fn configured_allowed_literals_apply_to_both_rules() {
+        let project = ProjectModel::default();
+        let mut context = RuleContext::from_sources(
+            &project,
+            vec![(
+                "src/main.nr".to_string(),
+                "fn main(limit: u32) { let fee = 42; let cap = 42; if limit > 042 { assert(cap > fee); } }"
+                    .to_string(),
+            )],
+        );
+        context.set_lint_options(noir100_options("allowed_literals", &["0", "1", "42"]));
+
+        let mut diagnostics = Vec::new();
+        Noir100MagicNumbersRule.run(&context, &mut diagnostics);
+        Noir101RepeatedLocalInitMagicNumbersRule.run(&context, &mut diagnostics);
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn configured_hash_domain_tags_replace_defaults() {
+        let project = ProjectModel::default();
+        let mut context = RuleContext::from_sources(
+            &project,
+            vec![(
+                "src/main.nr".to_string(),
+                "fn main(x: Field) { let h = poseidon2_hash([42, x]); }".to_string(),
+            )],
+        );
+        context.set_lint_options(noir100_options("hash_domain_tags", &["42"]));
+
+        let mut diagnostics = Vec::new();
+        Noir100MagicNumbersRule.run(&context, &mut diagnostics);
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn literal_normalization_ignores_separators_and_leading_zeros() {
+        assert_eq!(normalize_literal("1_000"), "1000");
+        assert_eq!(normalize_literal("0001"), "1");
+        assert_eq!(normalize_literal("00"), "0");
+        assert_eq!(normalize_literal("0xFF"), "0xff");
+    }
 }
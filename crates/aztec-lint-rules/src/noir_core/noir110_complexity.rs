@@ -11,7 +11,7 @@ use crate::noir_core::util::text_fallback_function_scopes;
 
 pub struct Noir110ComplexityRule;
 
-const COMPLEXITY_LIMIT: usize = 6;
+const COMPLEXITY_LIMIT_OPTION: &str = "complexity_limit";
 
 impl Rule for Noir110ComplexityRule {
     fn id(&self) -> &'static str {
@@ -49,14 +49,15 @@ impl Noir110ComplexityRule {
             let complexity = decision_blocks_by_function
                 .get(&function.symbol_id)
                 .map_or(0usize, BTreeSet::len);
-            if complexity <= COMPLEXITY_LIMIT {
-                continue;
-            }
-
             let normalized_file = normalize_file_path(&function.span.file);
             let Some(file) = files.get(&normalized_file).copied() else {
                 continue;
             };
+            let limit = ctx.lint_option_usize(self.id(), COMPLEXITY_LIMIT_OPTION, file.path());
+            if complexity <= limit {
+                continue;
+            }
+
             let Some(name_start) = usize::try_from(function.span.start).ok() else {
                 continue;
             };
@@ -68,7 +69,7 @@ impl Noir110ComplexityRule {
                 self.id(),
                 MAINTAINABILITY,
                 format!(
-                    "function `{}` complexity is {complexity} (limit: {COMPLEXITY_LIMIT})",
+                    "function `{}` complexity is {complexity} (limit: {limit})",
                     function.name
                 ),
                 file.span_for_range(name_start, name_end),
@@ -78,11 +79,12 @@ impl Noir110ComplexityRule {
 
     fn run_text_fallback(&self, ctx: &RuleContext<'_>, out: &mut Vec<Diagnostic>) {
         for file in ctx.files() {
+            let limit = ctx.lint_option_usize(self.id(), COMPLEXITY_LIMIT_OPTION, file.path());
             let source = file.text();
             for function in text_fallback_function_scopes(source) {
                 let body = &source[function.body_start..function.body_end];
                 let complexity = compute_complexity_score(body);
-                if complexity <= COMPLEXITY_LIMIT {
+                if complexity <= limit {
                     continue;
                 }
 
@@ -90,7 +92,7 @@ impl Noir110ComplexityRule {
                     self.id(),
                     MAINTAINABILITY,
                     format!(
-                        "function `{}` complexity is {complexity} (limit: {COMPLEXITY_LIMIT})",
+                        "function `{}` complexity is {complexity} (limit: {limit})",
                         function.name
                     ),
                     file.span_for_range(
@@ -129,6 +131,10 @@ mod tests {
         CfgEdge, CfgEdgeKind, ProjectModel, SemanticFunction, Span, TypeCategory,
     };
 
+    use std::collections::BTreeMap;
+
+    use aztec_lint_core::lints::{LintOptionTable, LintOptionValue};
+
     use crate::Rule;
     use crate::engine::context::RuleContext;
 
@@ -293,4 +299,30 @@ fn main(x: Field) {
 
         assert_eq!(diagnostics.len(), 1);
     }
+
+    #[test]
+    fn configured_limit_replaces_default() {
+        let project = ProjectModel::default();
+        let source = r#"
+fn main(x: Field) {
+    if x > 1 { }
+    if x > 2 { }
+    if x > 3 { }
+}
+"#;
+        let mut context = RuleContext::from_sources(
+            &project,
+            vec![("src/main.nr".to_string(), source.to_string())],
+        );
+        context.set_lint_options(BTreeMap::from([(
+            "NOIR110".to_string(),
+            LintOptionTable::from([("complexity_limit".to_string(), LintOptionValue::Integer(2))]),
+        )]));
+
+        let mut diagnostics = Vec::new();
+        Noir110ComplexityRule.run(&context, &mut diagnostics);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("(limit: 2)"));
+    }
 }
@@ -11,7 +11,7 @@ use crate::noir_core::util::text_fallback_function_scopes;
 
 pub struct Noir120NestingRule;
 
-const NESTING_LIMIT: usize = 3;
+const NESTING_LIMIT_OPTION: &str = "nesting_limit";
 
 impl Rule for Noir120NestingRule {
     fn id(&self) -> &'static str {
@@ -37,6 +37,7 @@ impl Noir120NestingRule {
             let Some(file) = files.get(&normalized_file).copied() else {
                 continue;
             };
+            let limit = ctx.lint_option_usize(self.id(), NESTING_LIMIT_OPTION, file.path());
             let mut blocks = semantic
                 .expressions
                 .iter()
@@ -56,7 +57,7 @@ impl Noir120NestingRule {
             blocks.sort_by_key(|(start, end)| (*start, std::cmp::Reverse(*end)));
             let max_depth = max_nested_block_depth(&blocks);
             let logical_depth = max_depth.saturating_sub(1);
-            if logical_depth <= NESTING_LIMIT {
+            if logical_depth <= limit {
                 continue;
             }
 
@@ -70,7 +71,7 @@ impl Noir120NestingRule {
                 self.id(),
                 MAINTAINABILITY,
                 format!(
-                    "function `{}` nesting depth is {logical_depth} (limit: {NESTING_LIMIT})",
+                    "function `{}` nesting depth is {logical_depth} (limit: {limit})",
                     function.name
                 ),
                 file.span_for_range(name_start, name_end),
@@ -80,12 +81,13 @@ impl Noir120NestingRule {
 
     fn run_text_fallback(&self, ctx: &RuleContext<'_>, out: &mut Vec<Diagnostic>) {
         for file in ctx.files() {
+            let limit = ctx.lint_option_usize(self.id(), NESTING_LIMIT_OPTION, file.path());
             let source = file.text();
             for function in text_fallback_function_scopes(source) {
                 let body = &source[function.body_start..function.body_end];
                 let max_depth = max_brace_depth(body);
                 let logical_depth = max_depth.saturating_sub(1);
-                if logical_depth <= NESTING_LIMIT {
+                if logical_depth <= limit {
                     continue;
                 }
 
@@ -93,7 +95,7 @@ impl Noir120NestingRule {
                     self.id(),
                     MAINTAINABILITY,
                     format!(
-                        "function `{}` nesting depth is {logical_depth} (limit: {NESTING_LIMIT})",
+                        "function `{}` nesting depth is {logical_depth} (limit: {limit})",
                         function.name
                     ),
                     file.span_for_range(
@@ -165,6 +167,9 @@ fn max_brace_depth(body: &str) -> usize {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
+    use aztec_lint_core::lints::{LintOptionTable, LintOptionValue};
     use aztec_lint_core::model::{
         ExpressionCategory, ProjectModel, SemanticExpression, SemanticFunction, Span, TypeCategory,
     };
@@ -341,4 +346,26 @@ mod tests {
 
         blocks
     }
+
+    #[test]
+    fn configured_limit_allows_deeper_nesting() {
+        let project = ProjectModel::default();
+        let mut context = RuleContext::from_sources(
+            &project,
+            vec![(
+                "src/main.nr".to_string(),
+                "fn main() { if true { if true { if true { if true { let x = 1; } } } } }"
+                    .to_string(),
+            )],
+        );
+        context.set_lint_options(BTreeMap::from([(
+            "NOIR120".to_string(),
+            LintOptionTable::from([("nesting_limit".to_string(), LintOptionValue::Integer(4))]),
+        )]));
+
+        let mut diagnostics = Vec::new();
+        Noir120NestingRule.run(&context, &mut diagnostics);
+
+        assert!(diagnostics.is_empty());
+    }
 }
@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use aztec_lint_aztec::{SourceUnit, build_aztec_model};
+use aztec_lint_core::config::{AztecConfig, RuleLevel};
+use aztec_lint_core::diagnostics::Applicability;
+use aztec_lint_core::lints::all_lints;
+use aztec_lint_core::model::ProjectModel;
+use aztec_lint_rules::RuleEngine;
+use aztec_lint_rules::engine::context::RuleContext;
+
+const FIXTURE_DIRS: &[&str] = &["fixtures/noir_core/rule_cases", "fixtures/aztec/rule_cases"];
+
+/// Rule fixtures whose file name starts with `<rule id>_`, lowercased.
+fn rule_fixtures(rule_id: &str) -> Vec<PathBuf> {
+    let prefix = format!("{}_", rule_id.to_ascii_lowercase());
+    let mut fixtures = FIXTURE_DIRS
+        .iter()
+        .map(|dir| {
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("../..")
+                .join(dir)
+        })
+        .flat_map(|dir| fs::read_dir(dir).expect("fixture dir must be readable"))
+        .map(|entry| entry.expect("fixture entry must be readable").path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".nr"))
+        })
+        .collect::<Vec<_>>();
+    fixtures.sort();
+    fixtures
+}
+
+/// Applicabilities of every suggestion group `rule_id` emits on `source`.
+fn emitted_applicabilities(rule_id: &str, source: &str) -> Vec<Applicability> {
+    let project = ProjectModel::default();
+    let config = AztecConfig::default();
+    let mut context = RuleContext::from_sources(
+        &project,
+        vec![("src/main.nr".to_string(), source.to_string())],
+    );
+    context.set_aztec_config(config.clone());
+    let sources = vec![SourceUnit::new("src/main.nr", source)];
+    context.set_aztec_model(build_aztec_model(&sources, &config));
+
+    RuleEngine::new()
+        .run(
+            &context,
+            &BTreeMap::from([(rule_id.to_string(), RuleLevel::Deny)]),
+        )
+        .expect("engine run should succeed")
+        .into_iter()
+        .filter(|diagnostic| diagnostic.rule_id == rule_id)
+        .flat_map(|diagnostic| diagnostic.suggestion_groups)
+        .map(|group| group.applicability)
+        .collect()
+}
+
+#[test]
+fn declared_fix_applicability_matches_rule_fixtures() {
+    let mut mismatches = Vec::<String>::new();
+    for lint in all_lints() {
+        let fixtures = rule_fixtures(lint.id);
+        if fixtures.is_empty() {
+            assert!(
+                lint.fix_applicability.is_none(),
+                "{} declares {:?} but has no rule fixtures to check it against",
+                lint.id,
+                lint.fix_applicability
+            );
+            continue;
+        }
+
+        // Applicability is ordered strongest first, so the minimum is the
+        // strongest suggestion the lint emits.
+        let emitted = fixtures
+            .iter()
+            .flat_map(|fixture| {
+                let source = fs::read_to_string(fixture).expect("fixture source must load");
+                emitted_applicabilities(lint.id, &source)
+            })
+            .min();
+        if emitted != lint.fix_applicability {
+            mismatches.push(format!(
+                "{}: declared {:?}, fixtures emit {emitted:?}",
+                lint.id, lint.fix_applicability
+            ));
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "fix_applicability does not match emitted suggestion groups:\n{}",
+        mismatches.join("\n")
+    );
+}
@@ -197,6 +197,8 @@ LintSpec {{\n\
     default_level: RuleLevel::Warn,\n\
     confidence: Confidence::Medium,\n\
     lifecycle: LintLifecycleState::Active,\n\
+    fix_applicability: None,\n\
+    options: &[],\n\
     docs: LintDocs {{\n\
         summary: \"TODO\",\n\
         what_it_does: \"TODO\",\n\
@@ -1,11 +1,18 @@
 use std::collections::{BTreeMap, BTreeSet};
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::time::Instant;
 
 use aztec_lint_aztec::taint::{analyze_intra_procedural, build_def_use_graph};
-use aztec_lint_aztec::{SourceUnit, build_aztec_model};
-use aztec_lint_core::config::AztecConfig;
+use aztec_lint_aztec::{SourceUnit, build_aztec_model, build_aztec_model_with_semantic};
+use aztec_lint_core::config::{AztecConfig, RuleLevel};
+use aztec_lint_core::lints::all_lints;
+use aztec_lint_core::model::{AztecModel, ProjectModel};
+use aztec_lint_core::noir::build_project_semantic_bundle;
+use aztec_lint_core::timings::Timings;
+use aztec_lint_rules::RuleEngine;
+use aztec_lint_rules::engine::context::RuleContext;
 use toml::Value;
 
 use crate::common::{
@@ -15,6 +22,9 @@ use crate::common::{
 
 const SCENARIOS_FILE: &str = "benchmarks/scenarios.toml";
 const BUDGETS_FILE: &str = "benchmarks/budgets.toml";
+/// Scratch directory, under the workspace root, holding one Nargo project per
+/// scenario fixture for rule timings.
+const RULE_PROJECTS_DIR: &str = "target/perf-gate";
 
 #[derive(Clone, Debug)]
 struct PerfScenario {
@@ -34,11 +44,14 @@ struct PerfBudget {
 struct PerfConfig {
     warmup_runs: usize,
     sample_runs: usize,
+    rule_iterations: usize,
     noise_percent: f64,
     hard_fail_percent: f64,
     scenarios: Vec<PerfScenario>,
     budgets: BTreeMap<String, PerfBudget>,
+    rule_budgets: BTreeMap<String, PerfBudget>,
     allowlist: BTreeSet<String>,
+    rule_allowlist: BTreeSet<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -51,6 +64,22 @@ struct ScenarioMeasurement {
     max_flows: usize,
 }
 
+/// A scenario fixture compiled as a Nargo project, so rules run against the
+/// same project and semantic model as in a real lint run.
+struct RuleFixture {
+    root: PathBuf,
+    project: ProjectModel,
+    aztec_model: AztecModel,
+}
+
+/// Per-rule `Rule::run` wall time summed over every scenario fixture in a sample.
+#[derive(Clone, Debug)]
+struct RuleMeasurement {
+    rule_id: String,
+    median_ms: f64,
+    p95_ms: f64,
+}
+
 pub fn run(args: &[String]) -> Result<(), DynError> {
     let (mut flags, options) = parse_flags_and_options(args)?;
     let check = flags.remove("check");
@@ -63,10 +92,12 @@ pub fn run(args: &[String]) -> Result<(), DynError> {
     let config = load_config(scenarios_path.as_path(), budgets_path.as_path())?;
 
     let results = execute_scenarios(&root, &config)?;
+    let rule_results = execute_rule_samples(&root, &config)?;
     print_report(&results, &config);
+    print_rule_report(&rule_results, &config);
 
     if check {
-        enforce_budgets(&results, &config)?;
+        enforce_budgets(&results, &rule_results, &config)?;
         run_smoke_test(&root, locked)?;
         println!("perf-gate check: scenario budgets and smoke gate passed");
     } else {
@@ -107,6 +138,11 @@ fn load_config(scenarios_path: &Path, budgets_path: &Path) -> Result<PerfConfig,
 
     let warmup_runs = parse_positive_usize(runner, "warmup_runs", "runner")?;
     let sample_runs = parse_positive_usize(runner, "sample_runs", "runner")?;
+    let rule_iterations =
+        parse_non_negative_usize(runner, "rule_iterations", "runner")?.unwrap_or(1);
+    if rule_iterations == 0 {
+        return Err("runner.rule_iterations must be greater than zero".into());
+    }
 
     let raw_scenarios = scenarios_table
         .get("scenario")
@@ -161,6 +197,25 @@ fn load_config(scenarios_path: &Path, budgets_path: &Path) -> Result<PerfConfig,
         budgets.insert(scenario_id.clone(), PerfBudget { median_ms, p95_ms });
     }
 
+    let mut rule_budgets = BTreeMap::<String, PerfBudget>::new();
+    if let Some(rule_sections) = budgets_table.get("rule_budget") {
+        let rule_sections = rule_sections
+            .as_table()
+            .ok_or("benchmarks/budgets.toml [rule_budget] must be a table of rule IDs")?;
+        for (rule_id, raw_budget) in rule_sections {
+            let section = format!("rule_budget.{rule_id}");
+            if !all_lints().iter().any(|lint| lint.id == rule_id) {
+                return Err(format!("{section} references unknown rule ID '{rule_id}'").into());
+            }
+            let table = raw_budget
+                .as_table()
+                .ok_or_else(|| format!("{section} must be a table"))?;
+            let median_ms = parse_positive_f64(table, "median_ms", &section)?;
+            let p95_ms = parse_positive_f64(table, "p95_ms", &section)?;
+            rule_budgets.insert(rule_id.clone(), PerfBudget { median_ms, p95_ms });
+        }
+    }
+
     let policy_table = budgets_table
         .get("policy")
         .and_then(Value::as_table)
@@ -184,6 +239,27 @@ fn load_config(scenarios_path: &Path, budgets_path: &Path) -> Result<PerfConfig,
     }
 
     let mut allowlist = BTreeSet::<String>::new();
+    let mut rule_allowlist = BTreeSet::<String>::new();
+    if let Some(table) = budgets_table.get("allowlist").and_then(Value::as_table)
+        && let Some(items) = table.get("rule_ids")
+    {
+        let array = items
+            .as_array()
+            .ok_or("allowlist.rule_ids must be an array of rule IDs")?;
+        for item in array {
+            let id = item
+                .as_str()
+                .ok_or("allowlist.rule_ids entries must be strings")?
+                .trim();
+            if !rule_budgets.contains_key(id) {
+                return Err(format!(
+                    "allowlist.rule_ids references rule '{id}' without a [rule_budget] entry"
+                )
+                .into());
+            }
+            rule_allowlist.insert(id.to_string());
+        }
+    }
     if let Some(table) = budgets_table.get("allowlist").and_then(Value::as_table)
         && let Some(items) = table.get("scenario_ids")
     {
@@ -247,11 +323,14 @@ fn load_config(scenarios_path: &Path, budgets_path: &Path) -> Result<PerfConfig,
     Ok(PerfConfig {
         warmup_runs,
         sample_runs,
+        rule_iterations,
         noise_percent,
         hard_fail_percent,
         scenarios,
         budgets,
+        rule_budgets,
         allowlist,
+        rule_allowlist,
     })
 }
 
@@ -340,6 +419,126 @@ fn run_once(
     Ok((elapsed_ms, max_flows))
 }
 
+fn execute_rule_samples(
+    root: &Path,
+    config: &PerfConfig,
+) -> Result<Vec<RuleMeasurement>, DynError> {
+    let aztec_config = AztecConfig::default();
+    let engine = RuleEngine::new();
+    let levels = all_lints()
+        .iter()
+        .map(|lint| (lint.id.to_string(), RuleLevel::Warn))
+        .collect::<BTreeMap<_, _>>();
+    let mut fixtures = Vec::<RuleFixture>::new();
+    for scenario in &config.scenarios {
+        if let Some(fixture) = compile_rule_fixture(root, scenario, &aztec_config)? {
+            fixtures.push(fixture);
+        }
+    }
+
+    for _ in 0..config.warmup_runs {
+        let _ = run_rules_once(&engine, &levels, &fixtures, &aztec_config, config)?;
+    }
+
+    let mut samples_ms = BTreeMap::<String, Vec<f64>>::new();
+    for _ in 0..config.sample_runs {
+        let timings = run_rules_once(&engine, &levels, &fixtures, &aztec_config, config)?;
+        for (rule_id, elapsed) in timings.rules() {
+            samples_ms
+                .entry(rule_id.clone())
+                .or_default()
+                .push(elapsed.as_secs_f64() * 1_000.0);
+        }
+    }
+
+    let mut measurements = Vec::<RuleMeasurement>::new();
+    for (rule_id, samples) in samples_ms {
+        let (median_ms, p95_ms) = summary_metrics(&samples)?;
+        measurements.push(RuleMeasurement {
+            rule_id,
+            median_ms,
+            p95_ms,
+        });
+    }
+    measurements.sort_by(|left, right| {
+        right
+            .median_ms
+            .partial_cmp(&left.median_ms)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| left.rule_id.cmp(&right.rule_id))
+    });
+    Ok(measurements)
+}
+
+/// Writes the scenario fixture into a scratch Nargo project and builds its
+/// project, semantic, and Aztec models the way `check` does. Fixtures the Noir
+/// frontend rejects are reported and left out of rule timings.
+fn compile_rule_fixture(
+    root: &Path,
+    scenario: &PerfScenario,
+    aztec_config: &AztecConfig,
+) -> Result<Option<RuleFixture>, DynError> {
+    let project_root = root.join(RULE_PROJECTS_DIR).join(&scenario.id);
+    let entry = project_root.join("src/main.nr");
+    fs::create_dir_all(project_root.join("src"))?;
+    fs::write(
+        project_root.join("Nargo.toml"),
+        format!(
+            "[package]\nname = \"{}\"\ntype = \"contract\"\nauthors = [\"\"]\n",
+            scenario.id
+        ),
+    )?;
+    fs::write(&entry, read_text_file(&root.join(&scenario.fixture))?)?;
+
+    let project = match build_project_semantic_bundle(&project_root, &entry) {
+        Ok(bundle) => bundle.into_project_model(),
+        Err(err) => {
+            println!(
+                "perf-gate rule timings: skipping scenario={} fixture={} (Noir frontend failed: {err})",
+                scenario.id, scenario.fixture
+            );
+            return Ok(None);
+        }
+    };
+    let aztec_model = {
+        let context = RuleContext::from_project_root(&project_root, &project)?;
+        let sources = context
+            .files()
+            .iter()
+            .map(|file| SourceUnit::new(file.path().to_string(), file.text().to_string()))
+            .collect::<Vec<_>>();
+        build_aztec_model_with_semantic(&sources, aztec_config, Some(context.semantic_model()))
+    };
+    Ok(Some(RuleFixture {
+        root: project_root,
+        project,
+        aztec_model,
+    }))
+}
+
+fn run_rules_once(
+    engine: &RuleEngine,
+    levels: &BTreeMap<String, RuleLevel>,
+    fixtures: &[RuleFixture],
+    aztec_config: &AztecConfig,
+    config: &PerfConfig,
+) -> Result<Timings, DynError> {
+    let mut timings = Timings::default();
+    for fixture in fixtures {
+        let mut context = RuleContext::from_project_root(&fixture.root, &fixture.project)?;
+        context.set_aztec_config(aztec_config.clone());
+        context.set_aztec_model(fixture.aztec_model.clone());
+        // The taint graph is shared by several rules; building it up front
+        // keeps its cost out of whichever rule would have asked first.
+        let _ = context.taint_graph();
+        for _ in 0..config.rule_iterations {
+            engine.run(&context, levels)?;
+        }
+        timings.merge(&context.timings());
+    }
+    Ok(timings)
+}
+
 fn summary_metrics(samples_ms: &[f64]) -> Result<(f64, f64), DynError> {
     if samples_ms.is_empty() {
         return Err("cannot compute benchmark summary for empty sample set".into());
@@ -395,10 +594,69 @@ fn print_report(results: &[ScenarioMeasurement], config: &PerfConfig) {
     }
 }
 
-fn enforce_budgets(results: &[ScenarioMeasurement], config: &PerfConfig) -> Result<(), DynError> {
+fn print_rule_report(results: &[RuleMeasurement], config: &PerfConfig) {
+    println!(
+        "perf-gate rule timings: rules={} budgeted={} rule_iterations={}",
+        results.len(),
+        config.rule_budgets.len(),
+        config.rule_iterations,
+    );
+    for result in results {
+        match config.rule_budgets.get(&result.rule_id) {
+            Some(budget) => println!(
+                "rule={} median_ms={:.3} p95_ms={:.3} budget_median_ms={:.3} budget_p95_ms={:.3} median_regression_percent={:.2} p95_regression_percent={:.2}",
+                result.rule_id,
+                result.median_ms,
+                result.p95_ms,
+                budget.median_ms,
+                budget.p95_ms,
+                regression_percent(result.median_ms, budget.median_ms),
+                regression_percent(result.p95_ms, budget.p95_ms),
+            ),
+            None => println!(
+                "rule={} median_ms={:.3} p95_ms={:.3} budget=none",
+                result.rule_id, result.median_ms, result.p95_ms,
+            ),
+        }
+    }
+}
+
+fn enforce_budgets(
+    results: &[ScenarioMeasurement],
+    rule_results: &[RuleMeasurement],
+    config: &PerfConfig,
+) -> Result<(), DynError> {
     let mut soft_violations = Vec::<String>::new();
     let mut hard_violations = Vec::<String>::new();
 
+    // Rule budgets are reported but not enforced until they are re-baselined
+    // against compiled fixtures.
+    for result in rule_results {
+        let Some(budget) = config.rule_budgets.get(&result.rule_id) else {
+            continue;
+        };
+        let median_regression = regression_percent(result.median_ms, budget.median_ms);
+        let p95_regression = regression_percent(result.p95_ms, budget.p95_ms);
+        if median_regression.max(p95_regression) <= config.noise_percent {
+            continue;
+        }
+        let status = if config.rule_allowlist.contains(&result.rule_id) {
+            "allowlisted"
+        } else {
+            "report-only"
+        };
+        println!(
+            "perf-gate rule regression ({status}): rule={} median_ms={:.3} (budget {:.3}, +{:.2}%) p95_ms={:.3} (budget {:.3}, +{:.2}%)",
+            result.rule_id,
+            result.median_ms,
+            budget.median_ms,
+            median_regression,
+            result.p95_ms,
+            budget.p95_ms,
+            p95_regression
+        );
+    }
+
     for result in results {
         let budget = config
             .budgets
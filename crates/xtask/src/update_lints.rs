@@ -2,7 +2,9 @@ use std::collections::BTreeSet;
 use std::path::PathBuf;
 use std::process::Command;
 
-use aztec_lint_core::lints::{all_lints, render_lints_reference_markdown};
+use aztec_lint_core::lints::{
+    all_lints, render_lints_reference_markdown, splice_lint_options_markdown,
+};
 
 use crate::common::{
     DynError, ensure_no_unknown_options, parse_flags_and_options, read_text_file, run_command,
@@ -32,6 +34,21 @@ pub fn run(args: &[String]) -> Result<(), DynError> {
         write_text_file(&docs_path, &expected_docs)?;
     }
 
+    let config_docs_path = root.join("docs/configuration.md");
+    let actual_config_docs = read_text_file(&config_docs_path)?;
+    let expected_config_docs = splice_lint_options_markdown(&actual_config_docs)
+        .ok_or("docs/configuration.md is missing the lint-options markers")?;
+    if check {
+        if actual_config_docs != expected_config_docs {
+            return Err(
+                "docs/configuration.md lint options are out of date; run `cargo xtask update-lints`"
+                    .into(),
+            );
+        }
+    } else {
+        write_text_file(&config_docs_path, &expected_config_docs)?;
+    }
+
     let mut registry_test = Command::new("cargo");
     registry_test
         .arg("test")
@@ -73,6 +90,7 @@ fn ensure_generated_targets_clean(root: &PathBuf) -> Result<(), DynError> {
     let generated_targets = [
         "crates/aztec-lint-core/src/lints/mod.rs",
         "crates/aztec-lint-rules/src/engine/registry.rs",
+        "docs/configuration.md",
         "docs/lints-reference.md",
     ];
 